@@ -63,6 +63,7 @@ fn default_config() -> cbindgen::Config {
                 ("VoidArg".into(), "void".into()),
                 ("KeyEventArg".into(), "KeyEvent".into()),
                 ("PointerEventArg".into(), "PointerEvent".into()),
+                ("TouchEventArg".into(), "TouchEvent".into()),
                 ("PointArg".into(), "Point".into()),
                 ("FloatArg".into(), "float".into()),
                 ("Coord".into(), "float".into()),
@@ -143,6 +144,7 @@ fn gen_corelib(
         "TextHorizontalAlignment",
         "TextVerticalAlignment",
         "TextOverflow",
+        "FontStyle",
         "TextWrap",
         "ImageFit",
         "FillRule",
@@ -153,8 +155,11 @@ fn gen_corelib(
         "PointerEventKind",
         "PointerEventButton",
         "PointerEvent",
+        "TouchPhase",
+        "TouchEvent",
         "Rect",
         "SortOrder",
+        "TabBarPosition",
     ]
     .iter()
     .chain(items.iter())
@@ -200,6 +205,7 @@ fn gen_corelib(
         "VoidArg",
         "KeyEventArg",
         "PointerEventArg",
+        "TouchEventArg",
         "PointArg",
         "Point",
         "slint_color_brighter",
@@ -209,6 +215,7 @@ fn gen_corelib(
         "slint_image_load_from_path",
         "slint_image_load_from_embedded_data",
         "slint_image_from_embedded_textures",
+        "DEFAULT_TOLERANCE",
         "Coord",
         "LogicalRect",
         "LogicalPoint",
@@ -285,7 +292,7 @@ fn gen_corelib(
             ],
             vec!["Color"],
             "slint_image_internal.h",
-            "namespace slint::cbindgen_private { struct ParsedSVG{}; struct HTMLImage{}; using namespace vtable; }",
+            "namespace slint::cbindgen_private { struct ParsedSVG{}; struct HTMLImage{}; struct RemoteImage{}; using namespace vtable; }",
         ),
         (
             vec!["Color", "slint_color_brighter", "slint_color_darker"],
@@ -327,6 +334,9 @@ fn gen_corelib(
             "slint_windowrc_set_rendering_notifier",
             "slint_windowrc_request_redraw",
             "slint_windowrc_on_close_requested",
+            "slint_windowrc_on_scale_factor_changed",
+            "slint_windowrc_set_fullscreen",
+            "slint_windowrc_set_maximized",
             "slint_windowrc_position",
             "slint_windowrc_set_logical_position",
             "slint_windowrc_set_physical_position",
@@ -345,6 +355,7 @@ fn gen_corelib(
             "slint_image_load_from_path",
             "slint_image_load_from_embedded_data",
             "slint_image_from_embedded_textures",
+            "DEFAULT_TOLERANCE",
         ]
         .iter()
         .filter(|exclusion| !rust_types.iter().any(|inclusion| inclusion == *exclusion))
@@ -496,7 +507,7 @@ fn gen_corelib(
     namespace cbindgen_private {
         using slint::private_api::WindowAdapterRc;
         using namespace vtable;
-        struct KeyEvent; struct PointerEvent;
+        struct KeyEvent; struct PointerEvent; struct TouchEvent;
         using private_api::Property;
         using private_api::PathData;
         using private_api::Point;
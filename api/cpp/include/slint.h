@@ -63,6 +63,7 @@ using ItemArray = slint::cbindgen_private::Slice<ItemArrayEntry>;
 using cbindgen_private::KeyboardModifiers;
 using cbindgen_private::KeyEvent;
 using cbindgen_private::PointerEvent;
+using cbindgen_private::TouchEvent;
 using cbindgen_private::TableColumn;
 
 /// Internal function that checks that the API that must be called from the main
@@ -188,6 +189,17 @@ public:
                 new F(std::move(callback)));
     }
 
+    template<std::invocable<float> F>
+    void on_scale_factor_changed(F callback) const
+    {
+        auto actual_cb = [](float scale_factor, void *data) {
+            (*reinterpret_cast<F *>(data))(scale_factor);
+        };
+        cbindgen_private::slint_windowrc_on_scale_factor_changed(
+                &inner, actual_cb, [](void *user_data) { delete reinterpret_cast<F *>(user_data); },
+                new F(std::move(callback)));
+    }
+
     void request_redraw() const { cbindgen_private::slint_windowrc_request_redraw(&inner); }
 
     slint::PhysicalPosition position() const
@@ -222,6 +234,16 @@ public:
         cbindgen_private::slint_windowrc_set_physical_size(&inner, &size);
     }
 
+    void set_fullscreen(bool fullscreen)
+    {
+        cbindgen_private::slint_windowrc_set_fullscreen(&inner, fullscreen);
+    }
+
+    void set_maximized(bool maximized)
+    {
+        cbindgen_private::slint_windowrc_set_maximized(&inner, maximized);
+    }
+
     void dispatch_key_event(const cbindgen_private::KeyInputEvent &event)
     {
         private_api::assert_main_thread();
@@ -452,6 +474,15 @@ public:
         return inner.on_close_requested(std::forward<F>(callback));
     }
 
+    /// This function allows registering a callback that's invoked with the window's scale factor
+    /// when the window is first shown, and whenever the scale factor changes afterwards, for example
+    /// when the window is moved to a screen with a different resolution.
+    template<std::invocable<float> F>
+    void on_scale_factor_changed(F &&callback) const
+    {
+        inner.on_scale_factor_changed(std::forward<F>(callback));
+    }
+
     /// This function issues a request to the windowing system to redraw the contents of the window.
     void request_redraw() const { inner.request_redraw(); }
 
@@ -479,6 +510,16 @@ public:
     /// a window frame (if present).
     void set_size(const slint::PhysicalSize &size) { inner.set_physical_size(size); }
 
+    /// Sets whether the window is shown in fullscreen mode, covering the whole screen that
+    /// currently contains it. When leaving the fullscreen mode, the window gets back the size
+    /// and position it had before.
+    /// Note that on some windowing systems, this functionality is not available.
+    void set_fullscreen(bool fullscreen) { inner.set_fullscreen(fullscreen); }
+    /// Sets whether the window is maximized. When the window is restored, it gets back the size
+    /// and position it had before.
+    /// Note that on some windowing systems, this functionality is not available.
+    void set_maximized(bool maximized) { inner.set_maximized(maximized); }
+
     /// Dispatch a key press event to the scene.
     ///
     /// Use this when you're implementing your own backend and want to forward user input events.
@@ -587,6 +628,15 @@ inline LayoutInfo LayoutInfo::merge(const LayoutInfo &other) const
 
 namespace private_api {
 
+/// Returns a slice of the `len` elements at `ptr`. Slices are never null in Rust, so an empty
+/// slice uses a dangling pointer instead of a null pointer.
+template<typename T>
+inline cbindgen_private::Slice<T> make_slice(const T *ptr, size_t len)
+{
+    T *data = ptr ? const_cast<T *>(ptr) : reinterpret_cast<T *>(alignof(T));
+    return cbindgen_private::Slice<T> { data, len };
+}
+
 inline SharedVector<float> solve_box_layout(const cbindgen_private::BoxLayoutData &data,
                                             cbindgen_private::Slice<int> repeater_indexes)
 {
@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-commercial
 
 #pragma once
+#include <algorithm>
 #include <string_view>
 #include "slint_color.h"
 #include "slint_brush_internal.h"
@@ -23,6 +24,7 @@ public:
     LinearGradientBrush() = default;
     /// Constructs a new linear gradient with the specified \a angle. The color stops will be
     /// constructed from the stops array pointed to be \a firstStop, with the length \a stopCount.
+    /// The positions of the stops are clamped to [0, 1] and the stops are sorted by position.
     LinearGradientBrush(float angle, const GradientStop *firstStop, int stopCount)
         : inner(make_linear_gradient(angle, firstStop, stopCount))
     {
@@ -55,7 +57,12 @@ private:
         SharedVector<private_api::GradientStop> gradient;
         gradient.push_back({ Color::from_argb_encoded(0).inner, angle });
         for (int i = 0; i < stopCount; ++i, ++firstStop)
-            gradient.push_back(*firstStop);
+            gradient.push_back({ firstStop->color, std::clamp(firstStop->position, 0.f, 1.f) });
+        // The sort is stable, so stops at the same position keep their order, for hard transitions
+        std::stable_sort(gradient.begin() + 1, gradient.end(),
+                         [](const GradientStop &a, const GradientStop &b) {
+                             return a.position < b.position;
+                         });
         return gradient;
     }
 };
@@ -192,6 +192,32 @@ TEST_CASE("Image")
     }
 }
 
+TEST_CASE("Linear gradient stops are clamped and sorted")
+{
+    using namespace slint;
+    using private_api::GradientStop;
+
+    auto red = Color::from_rgb_uint8(255, 0, 0);
+    auto green = Color::from_rgb_uint8(0, 255, 0);
+    auto blue = Color::from_rgb_uint8(0, 0, 255);
+    auto white = Color::from_rgb_uint8(255, 255, 255);
+
+    GradientStop stops[] = { { blue, 1.5 }, { red, 0.5 }, { green, -1 }, { white, 0.5 } };
+    private_api::LinearGradientBrush gradient(90, stops, 4);
+
+    REQUIRE(gradient.angle() == 90);
+    REQUIRE(gradient.stopCount() == 4);
+    std::vector<GradientStop> sorted(gradient.stopsBegin(), gradient.stopsEnd());
+    REQUIRE(sorted[0].position == 0);
+    REQUIRE(sorted[0].color == green);
+    REQUIRE(sorted[1].position == 0.5);
+    REQUIRE(sorted[1].color == red);
+    REQUIRE(sorted[2].position == 0.5);
+    REQUIRE(sorted[2].color == white);
+    REQUIRE(sorted[3].position == 1);
+    REQUIRE(sorted[3].color == blue);
+}
+
 TEST_CASE("SharedVector")
 {
     using namespace slint;
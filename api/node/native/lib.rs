@@ -255,6 +255,7 @@ fn to_js_value<'cx>(
             &ImageInner::EmbeddedImage { .. }
             | &ImageInner::StaticTextures { .. }
             | &ImageInner::Svg(..)
+            | &ImageInner::RemoteImage(..)
             | &ImageInner::BackendStorage(..) => JsNull::new().as_value(cx), // TODO: maybe pass around node buffers?
         },
         Value::Model(model) => {
@@ -244,8 +244,11 @@ struct MyStruct {
 
 pub use i_slint_core::api::*;
 pub use i_slint_core::graphics::{
-    Brush, Color, Image, LoadImageError, Rgb8Pixel, Rgba8Pixel, RgbaColor, SharedPixelBuffer,
+    Brush, Color, Image, ImageLoadState, LoadImageError, Rgb8Pixel, Rgba8Pixel, RgbaColor,
+    SharedPixelBuffer,
 };
+#[cfg(feature = "std")]
+pub use i_slint_core::graphics::{set_image_url_fetcher, ImageFetchCompletion};
 pub use i_slint_core::model::{
     FilterModel, MapModel, Model, ModelExt, ModelNotify, ModelPeer, ModelRc, ModelTracker,
     SortModel, StandardListViewItem, TableColumn, VecModel,
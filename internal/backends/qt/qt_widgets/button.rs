@@ -268,7 +268,9 @@ fn input_event(
                     InputEventResult::EventAccepted
                 }
             }
-            MouseEvent::Wheel { .. } => return InputEventResult::EventIgnored,
+            MouseEvent::Wheel { .. } | MouseEvent::Touch { .. } => {
+                return InputEventResult::EventIgnored
+            }
         });
         if let MouseEvent::Released { position, .. } = event {
             if LogicalRect::new(
@@ -218,6 +218,7 @@ fn input_event(
                     // TODO
                     InputEventResult::EventAccepted
                 }
+                MouseEvent::Touch { .. } => InputEventResult::EventIgnored,
             };
             self.data.set(data);
             result
@@ -170,7 +170,7 @@ fn input_event(
                 data.pressed = 0;
                 InputEventResult::EventAccepted
             }
-            MouseEvent::Moved { position: pos } => {
+            MouseEvent::Moved { position: pos, .. } => {
                 if data.pressed != 0 {
                     // FIXME: use QStyle::subControlRect to find out the actual size of the groove
                     let new_val = data.pressed_val
@@ -194,6 +194,7 @@ fn input_event(
                 debug_assert_ne!(button, PointerEventButton::Left);
                 InputEventResult::EventIgnored
             }
+            MouseEvent::Touch { .. } => InputEventResult::EventIgnored,
         };
         data.active_controls = new_control;
 
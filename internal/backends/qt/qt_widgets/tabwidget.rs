@@ -4,6 +4,7 @@
 // cSpell: ignore hframe qreal tabbar vframe
 
 use i_slint_core::input::FocusEventResult;
+use i_slint_core::items::TabBarPosition;
 
 use super::*;
 
@@ -22,6 +23,7 @@ pub struct NativeTabWidget {
     pub tabbar_preferred_width: Property<LogicalLength>,
     pub current_index: Property<i32>,
     pub current_focused: Property<i32>,
+    pub tab_bar_position: Property<TabBarPosition>,
 
     // outputs
     pub content_x: Property<LogicalLength>,
@@ -54,6 +56,7 @@ struct TabBarSharedData {
             height: Property<LogicalLength>,
             tabbar_preferred_height: Property<LogicalLength>,
             tabbar_preferred_width: Property<LogicalLength>,
+            tab_bar_position: Property<TabBarPosition>,
             horizontal_metrics: Property<TabWidgetMetrics>,
             vertical_metrics: Property<TabWidgetMetrics>,
         }
@@ -70,6 +73,7 @@ macro_rules! link {
         link!(height);
         link!(tabbar_preferred_width);
         link!(tabbar_preferred_height);
+        link!(tab_bar_position);
 
         let shared_data_weak = pin_weak::rc::PinWeak::downgrade(shared_data.clone());
 
@@ -116,13 +120,18 @@ macro_rules! link {
             };
 
             let horizontal: bool = matches!(orientation, Orientation::Horizontal);
+            let shape = TabBarSharedData::FIELD_OFFSETS
+                .tab_bar_position
+                .apply_pin(shared_data.as_ref())
+                .get() as i32;
 
-            cpp!(unsafe [horizontal as "bool", size as "QSizeF", tabbar_size as "QSizeF"] -> TabWidgetMetrics as "TabWidgetMetrics" {
+            cpp!(unsafe [horizontal as "bool", size as "QSizeF", tabbar_size as "QSizeF", shape as "int"] -> TabWidgetMetrics as "TabWidgetMetrics" {
                 ensure_initialized();
                 QStyleOptionTabWidgetFrame option;
                 auto style = qApp->style();
                 option.lineWidth = style->pixelMetric(QStyle::PM_DefaultFrameWidth, 0, nullptr);
-                option.shape = QTabBar::RoundedNorth;
+                // TabBarPosition has the same order as the QTabBar::Shape rounded values
+                option.shape = QTabBar::Shape(shape);
                 option.rect = QRect(QPoint(), size.toSize());
                 option.tabBarSize = tabbar_size.toSize();
                 option.tabBarRect = QRect(QPoint(), option.tabBarSize);
@@ -203,24 +212,43 @@ fn layout_info(
             ),
         };
 
-        let size = cpp!(unsafe [content_size as "QSizeF", tabbar_size as "QSizeF"] -> qttypes::QSize as "QSize" {
-            ensure_initialized();
+        let shape = self.tab_bar_position() as i32;
+        let vertical_tabbar =
+            matches!(self.tab_bar_position(), TabBarPosition::Left | TabBarPosition::Right);
+        let size_for_tabbar = |tabbar_size: qttypes::QSizeF| {
+            cpp!(unsafe [content_size as "QSizeF", tabbar_size as "QSizeF", shape as "int", vertical_tabbar as "bool"] -> qttypes::QSize as "QSize" {
+                ensure_initialized();
 
-            QStyleOptionTabWidgetFrame option;
-            auto style = qApp->style();
-            option.lineWidth = style->pixelMetric(QStyle::PM_DefaultFrameWidth, 0, nullptr);
-            option.shape = QTabBar::RoundedNorth;
-            option.tabBarSize = tabbar_size.toSize();
-            option.rightCornerWidgetSize = QSize(0, 0);
-            option.leftCornerWidgetSize = QSize(0, 0);
-            auto sz = QSize(qMax(content_size.width(), tabbar_size.width()),
-                content_size.height() + tabbar_size.height());
-            return style->sizeFromContents(QStyle::CT_TabWidget, &option, sz, nullptr);
-        });
+                QStyleOptionTabWidgetFrame option;
+                auto style = qApp->style();
+                option.lineWidth = style->pixelMetric(QStyle::PM_DefaultFrameWidth, 0, nullptr);
+                option.shape = QTabBar::Shape(shape);
+                option.tabBarSize = tabbar_size.toSize();
+                option.rightCornerWidgetSize = QSize(0, 0);
+                option.leftCornerWidgetSize = QSize(0, 0);
+                auto sz = vertical_tabbar
+                    ? QSize(content_size.width() + tabbar_size.width(),
+                        qMax(content_size.height(), tabbar_size.height()))
+                    : QSize(qMax(content_size.width(), tabbar_size.width()),
+                        content_size.height() + tabbar_size.height());
+                return style->sizeFromContents(QStyle::CT_TabWidget, &option, sz, nullptr);
+            })
+        };
+        let size = size_for_tabbar(tabbar_size);
+        // The tab bar scrolls when the tabs don't fit, so it doesn't need its preferred size along its direction
+        let min_size = match orientation {
+            Orientation::Horizontal if !vertical_tabbar => {
+                size_for_tabbar(qttypes::QSizeF { width: 0., ..tabbar_size })
+            }
+            Orientation::Vertical if vertical_tabbar => {
+                size_for_tabbar(qttypes::QSizeF { height: 0., ..tabbar_size })
+            }
+            _ => size,
+        };
         LayoutInfo {
             min: match orientation {
-                Orientation::Horizontal => size.width as f32,
-                Orientation::Vertical => size.height as f32,
+                Orientation::Horizontal => min_size.width as f32,
+                Orientation::Vertical => min_size.height as f32,
             },
             preferred: match orientation {
                 Orientation::Horizontal => size.width as f32,
@@ -272,19 +300,21 @@ fn focus_event(
             width: this.tabbar_preferred_width().get() as _,
             height: this.tabbar_preferred_height().get() as _,
         };
+        let shape = this.tab_bar_position() as i32;
         cpp!(unsafe [
             painter as "QPainterPtr*",
             widget as "QWidget*",
             size as "QSize",
             dpr as "float",
             tabbar_size as "QSizeF",
+            shape as "int",
             initial_state as "int"
         ] {
             QStyleOptionTabWidgetFrame option;
             option.state |= QStyle::State(initial_state);
             auto style = qApp->style();
             option.lineWidth = style->pixelMetric(QStyle::PM_DefaultFrameWidth, 0, widget);
-            option.shape = QTabBar::RoundedNorth;
+            option.shape = QTabBar::Shape(shape);
             if (true /*enabled*/) {
                 option.state |= QStyle::State_Enabled;
             } else {
@@ -336,14 +366,43 @@ pub struct NativeTab {
     pub title: Property<SharedString>,
     pub icon: Property<i_slint_core::graphics::Image>,
     pub enabled: Property<bool>,
+    pub closeable: Property<bool>,
     pub pressed: Property<bool>,
+    pub close_pressed: Property<bool>,
     pub current: Property<i32>,
     pub current_focused: Property<i32>,
     pub tab_index: Property<i32>,
     pub num_tabs: Property<i32>,
+    pub tab_bar_position: Property<TabBarPosition>,
+    pub close_clicked: Callback<VoidArg>,
     pub cached_rendering_data: CachedRenderingData,
 }
 
+impl NativeTab {
+    /// Returns the geometry of the close button within the tab, if the tab is closeable
+    fn close_button_rect(self: Pin<&Self>) -> Option<LogicalRect> {
+        if !self.closeable() {
+            return None;
+        }
+        let size: qttypes::QSize = get_size!(self);
+        let shape = self.tab_bar_position() as i32;
+        let rect = cpp!(unsafe [size as "QSize", shape as "int"] -> qttypes::QRectF as "QRectF" {
+            ensure_initialized();
+            auto style = qApp->style();
+            QStyleOptionTab option;
+            option.rect = QRect(QPoint(), size);
+            option.shape = QTabBar::Shape(shape);
+            option.rightButtonSize = QSize(style->pixelMetric(QStyle::PM_TabCloseIndicatorWidth, nullptr, nullptr),
+                                           style->pixelMetric(QStyle::PM_TabCloseIndicatorHeight, nullptr, nullptr));
+            return style->subElementRect(QStyle::SE_TabBarTabRightButton, &option, nullptr);
+        });
+        Some(LogicalRect::new(
+            LogicalPoint::new(rect.x as _, rect.y as _),
+            LogicalSize::new(rect.width as _, rect.height as _),
+        ))
+    }
+}
+
 impl Item for NativeTab {
     fn init(self: Pin<&Self>, _window_adapter: &Rc<dyn WindowAdapter>) {}
 
@@ -364,18 +423,25 @@ fn layout_info(
             crate::qt_window::image_to_pixmap((&self.icon()).into(), None).unwrap_or_default();
         let tab_index: i32 = self.tab_index();
         let num_tabs: i32 = self.num_tabs();
+        let closeable: bool = self.closeable();
+        let shape = self.tab_bar_position() as i32;
+        let vertical_tabbar =
+            matches!(self.tab_bar_position(), TabBarPosition::Left | TabBarPosition::Right);
         let size = cpp!(unsafe [
             text as "QString",
             icon as "QPixmap",
             tab_index as "int",
-            num_tabs as "int"
+            num_tabs as "int",
+            closeable as "bool",
+            shape as "int",
+            vertical_tabbar as "bool"
         ] -> qttypes::QSize as "QSize" {
             ensure_initialized();
             QStyleOptionTab option;
             option.rect = option.fontMetrics.boundingRect(text);
             option.text = text;
             option.icon = icon;
-            option.shape = QTabBar::RoundedNorth;
+            option.shape = QTabBar::Shape(shape);
             option.position = num_tabs == 1 ? QStyleOptionTab::OnlyOneTab
                 : tab_index == 0 ? QStyleOptionTab::Beginning
                 : tab_index == num_tabs - 1 ? QStyleOptionTab::End
@@ -386,14 +452,28 @@ fn layout_info(
             int padding = icon.isNull() ? 0 : 4;
             int textWidth = option.fontMetrics.size(Qt::TextShowMnemonic, text).width();
             auto iconSize = icon.isNull() ? 0 : style->pixelMetric(QStyle::PM_TabBarIconSize, nullptr, nullptr);
-            QSize csz = QSize(textWidth + iconSize + hframe + padding, qMax(option.fontMetrics.height(), iconSize) + vframe);
+            QSize closeSize;
+            if (closeable) {
+                // Same as QTabBar::tabSizeHint for the right button
+                closeSize = QSize(style->pixelMetric(QStyle::PM_TabCloseIndicatorWidth, nullptr, nullptr),
+                                  style->pixelMetric(QStyle::PM_TabCloseIndicatorHeight, nullptr, nullptr));
+                option.rightButtonSize = closeSize;
+                padding += 4;
+            }
+            QSize csz = QSize(textWidth + iconSize + closeSize.width() + hframe + padding,
+                              qMax(qMax(option.fontMetrics.height(), iconSize), closeSize.height()) + vframe);
+            // Same as QTabBar::tabSizeHint: the text of vertical tabs is rotated
+            if (vertical_tabbar)
+                csz.transpose();
             return style->sizeFromContents(QStyle::CT_TabBarTab, &option, csz, nullptr);
         });
         LayoutInfo {
-            min: match orientation {
-                // FIXME: the minimum width is arbitrary, Qt uses the size of two letters + ellipses
-                Orientation::Horizontal => size.width.min(size.height * 2) as f32,
-                Orientation::Vertical => size.height as f32,
+            min: match (orientation, vertical_tabbar) {
+                // FIXME: the minimum length is arbitrary, Qt uses the size of two letters + ellipses
+                (Orientation::Horizontal, false) => size.width.min(size.height * 2) as f32,
+                (Orientation::Vertical, true) => size.height.min(size.width * 2) as f32,
+                (Orientation::Horizontal, true) => size.width as f32,
+                (Orientation::Vertical, false) => size.height as f32,
             },
             preferred: match orientation {
                 Orientation::Horizontal => size.width as f32,
@@ -423,6 +503,30 @@ fn input_event(
             return InputEventResult::EventIgnored;
         }
 
+        if let Some(close_rect) = self.close_button_rect() {
+            let on_close = event.position().map_or(false, |pos| close_rect.contains(pos));
+            match event {
+                MouseEvent::Pressed { .. } if on_close => {
+                    Self::FIELD_OFFSETS.close_pressed.apply_pin(self).set(true);
+                    return InputEventResult::GrabMouse;
+                }
+                MouseEvent::Released { .. } if self.close_pressed() => {
+                    Self::FIELD_OFFSETS.close_pressed.apply_pin(self).set(false);
+                    if on_close {
+                        Self::FIELD_OFFSETS.close_clicked.apply_pin(self).call(&());
+                    }
+                    return InputEventResult::EventAccepted;
+                }
+                MouseEvent::Moved { .. } if self.close_pressed() => {
+                    return InputEventResult::GrabMouse;
+                }
+                MouseEvent::Exit => {
+                    Self::FIELD_OFFSETS.close_pressed.apply_pin(self).set(false);
+                }
+                _ => {}
+            }
+        }
+
         Self::FIELD_OFFSETS.pressed.apply_pin(self).set(match event {
             MouseEvent::Pressed { .. } => true,
             MouseEvent::Exit | MouseEvent::Released { .. } => false,
@@ -433,7 +537,9 @@ fn input_event(
                     InputEventResult::EventIgnored
                 }
             }
-            MouseEvent::Wheel { .. } => return InputEventResult::EventIgnored,
+            MouseEvent::Wheel { .. } | MouseEvent::Touch { .. } => {
+                return InputEventResult::EventIgnored
+            }
         });
         let click_on_press = cpp!(unsafe [] -> bool as "bool" {
             return qApp->style()->styleHint(QStyle::SH_TabBar_SelectMouseType, nullptr, nullptr) == QEvent::MouseButtonPress;
@@ -480,6 +586,9 @@ fn focus_event(
         let current_focused: i32 = this.current_focused();
         let tab_index: i32 = this.tab_index();
         let num_tabs: i32 = this.num_tabs();
+        let closeable: bool = this.closeable();
+        let close_pressed: bool = this.close_pressed();
+        let shape = this.tab_bar_position() as i32;
 
         cpp!(unsafe [
             painter as "QPainterPtr*",
@@ -494,6 +603,9 @@ fn focus_event(
             current as "int",
             current_focused as "int",
             num_tabs as "int",
+            closeable as "bool",
+            close_pressed as "bool",
+            shape as "int",
             initial_state as "int"
         ] {
             ensure_initialized();
@@ -502,7 +614,7 @@ fn focus_event(
             option.rect = QRect(QPoint(), size / dpr);;
             option.text = text;
             option.icon = icon;
-            option.shape = QTabBar::RoundedNorth;
+            option.shape = QTabBar::Shape(shape);
             option.position = num_tabs == 1 ? QStyleOptionTab::OnlyOneTab
                 : tab_index == 0 ? QStyleOptionTab::Beginning
                 : tab_index == num_tabs - 1 ? QStyleOptionTab::End
@@ -525,7 +637,24 @@ fn focus_event(
                 option.state |= QStyle::State_HasFocus | QStyle::State_KeyboardFocusChange | QStyle::State_Item;
             }
             option.features |= QStyleOptionTab::HasFrame;
-            qApp->style()->drawControl(QStyle::CE_TabBarTab, &option, painter->get(), widget);
+            auto style = qApp->style();
+            if (closeable) {
+                option.rightButtonSize = QSize(style->pixelMetric(QStyle::PM_TabCloseIndicatorWidth, nullptr, nullptr),
+                                               style->pixelMetric(QStyle::PM_TabCloseIndicatorHeight, nullptr, nullptr));
+            }
+            style->drawControl(QStyle::CE_TabBarTab, &option, painter->get(), widget);
+            if (closeable) {
+                QStyleOption close_option;
+                close_option.rect = style->subElementRect(QStyle::SE_TabBarTabRightButton, &option, widget);
+                close_option.state = enabled ? QStyle::State_Enabled : QStyle::State_None;
+                if (close_pressed)
+                    close_option.state |= QStyle::State_Sunken;
+                else
+                    close_option.state |= QStyle::State_Raised;
+                if (current == tab_index)
+                    close_option.state |= QStyle::State_Selected;
+                style->drawPrimitive(QStyle::PE_IndicatorTabClose, &close_option, painter->get(), widget);
+            }
         });
     }
 }
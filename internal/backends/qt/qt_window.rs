@@ -24,7 +24,7 @@
 use i_slint_core::platform::{PlatformError, WindowEvent};
 use i_slint_core::window::{WindowAdapter, WindowAdapterSealed, WindowInner};
 use i_slint_core::{ImageInner, Property, SharedString};
-use items::{ImageFit, TextHorizontalAlignment, TextVerticalAlignment};
+use items::{FontStyle, ImageFit, TextHorizontalAlignment, TextVerticalAlignment};
 
 use std::cell::RefCell;
 use std::pin::Pin;
@@ -155,20 +155,29 @@ struct SlintWidget : QWidget {
         }
         void mouseMoveEvent(QMouseEvent *event) override {
             QPoint pos = event->pos();
-            rust!(Slint_mouseMoveEvent [rust_window: &QtWindow as "void*", pos: qttypes::QPoint as "QPoint"] {
+            int buttons = event->buttons();
+            rust!(Slint_mouseMoveEvent [rust_window: &QtWindow as "void*", pos: qttypes::QPoint as "QPoint", buttons: u32 as "int"] {
                 let position = LogicalPoint::new(pos.x as _, pos.y as _);
-                rust_window.mouse_event(MouseEvent::Moved{position})
+                let buttons = (0..u32::BITS)
+                    .map(|bit| buttons & (1 << bit))
+                    .filter(|button| *button != 0)
+                    .map(from_qt_button)
+                    .collect();
+                rust_window.mouse_event(MouseEvent::Moved{position, buttons})
             });
         }
         void wheelEvent(QWheelEvent *event) override {
             QPointF pos = event->position();
             QPoint delta = event->pixelDelta();
-            if (delta.isNull()) {
+            // The angle delta is in eighths of a degree, most mouse wheels have notches of 15 degrees
+            bool is_angle = delta.isNull();
+            if (is_angle) {
                 delta = event->angleDelta();
             }
-            rust!(Slint_mouseWheelEvent [rust_window: &QtWindow as "void*", pos: qttypes::QPointF as "QPointF", delta: qttypes::QPoint as "QPoint"] {
+            rust!(Slint_mouseWheelEvent [rust_window: &QtWindow as "void*", pos: qttypes::QPointF as "QPointF", delta: qttypes::QPoint as "QPoint", is_angle: bool as "bool"] {
                 let position = LogicalPoint::new(pos.x as _, pos.y as _);
-                rust_window.mouse_event(MouseEvent::Wheel{position, delta_x: delta.x as _, delta_y: delta.y as _})
+                let scale = if is_angle { i_slint_core::input::WHEEL_PIXELS_PER_LINE / 120. } else { 1. };
+                rust_window.mouse_event(MouseEvent::Wheel{position, delta_x: delta.x as f32 * scale, delta_y: delta.y as f32 * scale})
             });
         }
         void leaveEvent(QEvent *) override {
@@ -585,7 +594,8 @@ fn draw_text(&mut self, text: std::pin::Pin<&items::Text>, _: &ItemRc, size: Log
         let rect: qttypes::QRectF = check_geometry!(size);
         let fill_brush: qttypes::QBrush = into_qbrush(text.color(), rect.width, rect.height);
         let mut string: qttypes::QString = text.text().as_str().into();
-        let font: QFont = get_font(text.font_request(WindowInner::from_pub(self.window)));
+        let font_request = text.font_request(WindowInner::from_pub(self.window));
+        let font: QFont = get_font(font_request.clone());
         let flags = match text.horizontal_alignment() {
             TextHorizontalAlignment::Left => key_generated::Qt_AlignmentFlag_AlignLeft,
             TextHorizontalAlignment::Center => key_generated::Qt_AlignmentFlag_AlignHCenter,
@@ -599,13 +609,46 @@ fn draw_text(&mut self, text: std::pin::Pin<&items::Text>, _: &ItemRc, size: Log
             TextWrap::WordWrap => key_generated::Qt_TextFlag_TextWordWrap,
         };
         let elide = text.overflow() == TextOverflow::Elide;
+        let line_height: f32 = font_request.line_height.map_or(0., |line_height| line_height.get());
         let painter: &mut QPainterPtr = &mut self.painter;
-        cpp! { unsafe [painter as "QPainterPtr*", rect as "QRectF", fill_brush as "QBrush", mut string as "QString", flags as "int", font as "QFont", elide as "bool"] {
+        cpp! { unsafe [painter as "QPainterPtr*", rect as "QRectF", fill_brush as "QBrush", mut string as "QString", flags as "int", font as "QFont", elide as "bool", line_height as "float"] {
             (*painter)->setFont(font);
             (*painter)->setPen(QPen(fill_brush, 0));
             (*painter)->setBrush(Qt::NoBrush);
+            auto draw = [&](QString to_draw) {
+                if (line_height <= 0) {
+                    (*painter)->drawText(rect, flags, to_draw);
+                    return;
+                }
+                // QPainter::drawText always uses the line spacing of the font, so place the lines
+                // line_height apart with the glyphs centered in each line
+                to_draw.replace(QChar('\n'), QChar::LineSeparator);
+                QTextLayout layout(to_draw, font);
+                QTextOption options(Qt::Alignment(flags & Qt::AlignHorizontal_Mask));
+                options.setWrapMode((flags & Qt::TextWordWrap) ? QTextOption::WordWrap : QTextOption::NoWrap);
+                layout.setTextOption(options);
+                layout.beginLayout();
+                qreal height = 0;
+                while (true) {
+                    auto line = layout.createLine();
+                    if (!line.isValid()) {
+                        break;
+                    }
+                    line.setLineWidth(rect.width());
+                    line.setPosition(QPointF(0, height + (line_height - line.height()) / 2));
+                    height += line_height;
+                }
+                layout.endLayout();
+                qreal y = rect.top();
+                if (flags & Qt::AlignBottom) {
+                    y += rect.height() - height;
+                } else if (flags & Qt::AlignVCenter) {
+                    y += (rect.height() - height) / 2;
+                }
+                layout.draw(painter->get(), QPointF(rect.left(), y));
+            };
             if (!elide) {
-                (*painter)->drawText(rect, flags, string);
+                draw(string);
             } else if (!(flags & Qt::TextWordWrap)) {
                 QString elided;
                 QFontMetrics fm(font);
@@ -620,7 +663,7 @@ fn draw_text(&mut self, text: std::pin::Pin<&items::Text>, _: &ItemRc, size: Log
                     elided += '\n';
                     string = string.mid(pos + 1);
                 }
-                (*painter)->drawText(rect, flags, elided);
+                draw(elided);
             } else {
                 // elide and word wrap: we need to add the ellipsis manually on the last line
                 string.replace(QChar('\n'), QChar::LineSeparator);
@@ -642,7 +685,7 @@ fn draw_text(&mut self, text: std::pin::Pin<&items::Text>, _: &ItemRc, size: Log
                         break;
                     }
                     line.setLineWidth(rect.width());
-                    height += leading + line.height();
+                    height += line_height > 0 ? line_height : leading + line.height();
                     if (height > rect.height()) {
                         break;
                     }
@@ -654,7 +697,7 @@ fn draw_text(&mut self, text: std::pin::Pin<&items::Text>, _: &ItemRc, size: Log
                     QString to_elide = QStringView(string).mid(last_line_begin, last_line_size).trimmed() % QStringView(QT_UNICODE_LITERAL("…"));
                     elided += fm.elidedText(to_elide, Qt::ElideRight, rect.width());
                 }
-                (*painter)->drawText(rect, flags, elided);
+                draw(elided);
             }
         }}
     }
@@ -794,6 +837,14 @@ fn draw_path(&mut self, path: Pin<&items::Path>, _: &ItemRc, size: LogicalSize)
         let fill_brush: qttypes::QBrush = into_qbrush(path.fill(), rect.width, rect.height);
         let stroke_brush: qttypes::QBrush = into_qbrush(path.stroke(), rect.width, rect.height);
         let stroke_width: f32 = path.stroke_width().get();
+        // Qt needs an even number of entries, SVG repeats odd dash arrays
+        let dash_pattern = path
+            .stroke_dash_pattern()
+            .map(|pattern| if pattern.len() % 2 == 1 { pattern.repeat(2) } else { pattern })
+            .unwrap_or_default();
+        let dash_pattern_ptr = dash_pattern.as_ptr();
+        let dash_pattern_len = dash_pattern.len();
+        let dash_offset: f32 = path.stroke_dash_offset().get();
         let pos = qttypes::QPoint { x: offset.x as _, y: offset.y as _ };
         let mut painter_path = QPainterPath::default();
 
@@ -836,11 +887,25 @@ fn to_qpointf(p: Point) -> qttypes::QPointF {
                 mut painter_path as "QPainterPath",
                 fill_brush as "QBrush",
                 stroke_brush as "QBrush",
-                stroke_width as "float"] {
+                stroke_width as "float",
+                dash_pattern_ptr as "const float*",
+                dash_pattern_len as "size_t",
+                dash_offset as "float"] {
             (*painter)->save();
             auto cleanup = qScopeGuard([&] { (*painter)->restore(); });
             (*painter)->translate(pos);
-            (*painter)->setPen(stroke_width > 0 ? QPen(stroke_brush, stroke_width) : Qt::NoPen);
+            QPen pen = stroke_width > 0 ? QPen(stroke_brush, stroke_width) : QPen(Qt::NoPen);
+            if (stroke_width > 0 && dash_pattern_len > 0) {
+                // Qt measures the dashes in units of the pen width, and extends them with square caps by default
+                QVector<qreal> pattern;
+                for (size_t i = 0; i < dash_pattern_len; ++i) {
+                    pattern.append(dash_pattern_ptr[i] / stroke_width);
+                }
+                pen.setDashPattern(pattern);
+                pen.setDashOffset(dash_offset / stroke_width);
+                pen.setCapStyle(Qt::FlatCap);
+            }
+            (*painter)->setPen(pen);
             (*painter)->setBrush(fill_brush);
             (*painter)->drawPath(painter_path);
         }}
@@ -1587,8 +1652,17 @@ fn apply_window_properties(&self, window_item: Pin<&items::WindowItem>) {
             if (size != widget_ptr->size()) {
                 widget_ptr->resize(size.expandedTo({1, 1}));
             }
-            widget_ptr->setWindowFlag(Qt::FramelessWindowHint, no_frame);
-            widget_ptr->setWindowFlag(Qt::WindowStaysOnTopHint, always_on_top);
+            auto flags = widget_ptr->windowFlags();
+            flags.setFlag(Qt::FramelessWindowHint, no_frame);
+            flags.setFlag(Qt::WindowStaysOnTopHint, always_on_top);
+            if (flags != widget_ptr->windowFlags()) {
+                // Changing the flags of a visible window hides it, so it must be shown again
+                bool visible = widget_ptr->isVisible();
+                widget_ptr->setWindowFlags(flags);
+                if (visible) {
+                    widget_ptr->show();
+                }
+            }
             widget_ptr->setWindowTitle(title);
             auto pal = widget_ptr->palette();
 
@@ -1777,6 +1851,20 @@ fn set_position(&self, position: i_slint_core::api::WindowPosition) {
         }};
     }
 
+    fn set_fullscreen(&self, fullscreen: bool) {
+        let widget_ptr = self.widget_ptr();
+        cpp! {unsafe [widget_ptr as "QWidget*", fullscreen as "bool"] {
+            widget_ptr->setWindowState(widget_ptr->windowState().setFlag(Qt::WindowFullScreen, fullscreen));
+        }};
+    }
+
+    fn set_maximized(&self, maximized: bool) {
+        let widget_ptr = self.widget_ptr();
+        cpp! {unsafe [widget_ptr as "QWidget*", maximized as "bool"] {
+            widget_ptr->setWindowState(widget_ptr->windowState().setFlag(Qt::WindowMaximized, maximized));
+        }};
+    }
+
     fn set_size(&self, size: i_slint_core::api::WindowSize) {
         let logical_size = size.to_logical(self.window().scale_factor());
         let widget_ptr = self.widget_ptr();
@@ -1815,7 +1903,12 @@ fn text_size(
         max_width: Option<LogicalLength>,
         _scale_factor: ScaleFactor,
     ) -> LogicalSize {
-        get_font(font_request).text_size(text, max_width.map(|logical_width| logical_width.get()))
+        let line_height = font_request.line_height.map(|line_height| line_height.get());
+        get_font(font_request).text_size(
+            text,
+            max_width.map(|logical_width| logical_width.get()),
+            line_height,
+        )
     }
 
     fn text_input_byte_offset_for_position(
@@ -1967,6 +2060,28 @@ fn default_font_size(&self) -> LogicalLength {
         LogicalLength::new(default_font_size as f32)
     }
 
+    fn grab_frame(
+        &self,
+    ) -> Result<
+        i_slint_core::graphics::SharedPixelBuffer<i_slint_core::graphics::Rgba8Pixel>,
+        PlatformError,
+    > {
+        let size = WindowAdapter::window(self).size();
+        let mut buffer = i_slint_core::graphics::SharedPixelBuffer::<
+            i_slint_core::graphics::Rgba8Pixel,
+        >::new(size.width, size.height);
+        let (width, height) = (size.width, size.height);
+        let data = buffer.make_mut_bytes().as_mut_ptr();
+        let widget_ptr = self.widget_ptr();
+        cpp! {unsafe [widget_ptr as "QWidget*", width as "int", height as "int", data as "unsigned char *"] {
+            QImage img(data, width, height, width * 4, QImage::Format_RGBA8888);
+            img.fill(Qt::transparent);
+            QPainter painter(&img);
+            painter.drawPixmap(QRect(0, 0, width, height), widget_ptr->grab());
+        }}
+        Ok(buffer)
+    }
+
     fn free_graphics_resources(
         &self,
         component: ComponentRef,
@@ -1994,9 +2109,15 @@ fn get_font(request: FontRequest) -> QFont {
     let family: qttypes::QString = request.family.unwrap_or_default().as_str().into();
     let pixel_size: f32 = request.pixel_size.map_or(0., |logical_size| logical_size.get());
     let weight: i32 = request.weight.unwrap_or(0);
+    // Matches the values of QFont::Style
+    let style: i32 = match request.style {
+        FontStyle::Normal => 0,
+        FontStyle::Italic => 1,
+        FontStyle::Oblique => 2,
+    };
     let letter_spacing: f32 =
         request.letter_spacing.map_or(0., |logical_spacing| logical_spacing.get());
-    cpp!(unsafe [family as "QString", pixel_size as "float", weight as "int", letter_spacing as "float"] -> QFont as "QFont" {
+    cpp!(unsafe [family as "QString", pixel_size as "float", weight as "int", style as "int", letter_spacing as "float"] -> QFont as "QFont" {
         QFont f;
         if (!family.isEmpty())
             f.setFamily(family);
@@ -2009,6 +2130,7 @@ fn get_font(request: FontRequest) -> QFont {
             f.setWeight(QFont::Weight(weight));
     #endif
         }
+        f.setStyle(QFont::Style(style));
         f.setLetterSpacing(QFont::AbsoluteSpacing, letter_spacing);
         // Mark all font properties as resolved, to avoid inheriting font properties
         // from the widget hierarchy. Later we call QPainter::setFont, which would
@@ -2026,16 +2148,45 @@ fn get_font(request: FontRequest) -> QFont {
 cpp_class! {pub unsafe struct QFont as "QFont"}
 
 impl QFont {
-    fn text_size(&self, text: &str, max_width: Option<f32>) -> LogicalSize {
+    /// Returns the size of the text. The lines are `line_height` apart, or the line spacing of
+    /// the font if it is None.
+    fn text_size(
+        &self,
+        text: &str,
+        max_width: Option<f32>,
+        line_height: Option<f32>,
+    ) -> LogicalSize {
         let string = qttypes::QString::from(text);
         let mut r = qttypes::QRectF::default();
         if let Some(max) = max_width {
             r.height = f32::MAX as _;
             r.width = max as _;
         }
-        let size = cpp! { unsafe [self as "const QFont*", string as "QString", r as "QRectF"]
+        let line_height = line_height.unwrap_or(0.);
+        let size = cpp! { unsafe [self as "const QFont*", mut string as "QString", r as "QRectF", line_height as "float"]
                 -> qttypes::QSizeF as "QSizeF"{
-            return QFontMetricsF(*self).boundingRect(r, r.isEmpty() ? 0 : Qt::TextWordWrap , string).size();
+            QSizeF size = QFontMetricsF(*self).boundingRect(r, r.isEmpty() ? 0 : Qt::TextWordWrap , string).size();
+            if (line_height > 0) {
+                // Count the lines the same way as draw_text lays them out
+                string.replace(QChar('\n'), QChar::LineSeparator);
+                QTextLayout layout(string, *self);
+                QTextOption options;
+                options.setWrapMode(r.isEmpty() ? QTextOption::NoWrap : QTextOption::WordWrap);
+                layout.setTextOption(options);
+                layout.beginLayout();
+                int lines = 0;
+                while (true) {
+                    auto line = layout.createLine();
+                    if (!line.isValid()) {
+                        break;
+                    }
+                    line.setLineWidth(r.width());
+                    ++lines;
+                }
+                layout.endLayout();
+                size.setHeight(qMax(lines, 1) * line_height);
+            }
+            return size;
         }};
         LogicalSize::new(size.width as _, size.height as _)
     }
@@ -16,7 +16,7 @@
 
 use corelib::api::EventLoopError;
 use corelib::graphics::euclid;
-use corelib::input::{KeyEventType, KeyInputEvent, MouseEvent};
+use corelib::input::{KeyEventType, KeyInputEvent, MouseEvent, PointerEventButtons, TouchPhase};
 use corelib::window::*;
 use std::cell::{RefCell, RefMut};
 use std::rc::{Rc, Weak};
@@ -260,11 +260,72 @@ pub fn winit_key_to_char(virtual_keycode: winit::event::VirtualKeyCode) -> Optio
     i_slint_common::for_each_special_keys!(winit_key_to_char_fn);
 }
 
+/// The mouse buttons that are currently held down. A touch counts as the left button.
+#[derive(Default)]
+struct PressedButtons(Vec<winit::event::MouseButton>);
+
+impl PressedButtons {
+    fn press(&mut self, button: winit::event::MouseButton) {
+        if !self.0.contains(&button) {
+            self.0.push(button);
+        }
+    }
+
+    fn release(&mut self, button: winit::event::MouseButton) {
+        self.0.retain(|b| *b != button);
+    }
+
+    fn any(&self) -> bool {
+        !self.0.is_empty()
+    }
+
+    /// Returns the held buttons, as reported with the move events
+    fn buttons(&self) -> PointerEventButtons {
+        self.0.iter().map(|b| pointer_event_button(*b)).collect()
+    }
+}
+
+fn pointer_event_button(button: winit::event::MouseButton) -> PointerEventButton {
+    match button {
+        winit::event::MouseButton::Left => PointerEventButton::Left,
+        winit::event::MouseButton::Right => PointerEventButton::Right,
+        winit::event::MouseButton::Middle => PointerEventButton::Middle,
+        winit::event::MouseButton::Other(_) => PointerEventButton::Other,
+    }
+}
+
+#[test]
+fn test_pressed_buttons() {
+    use winit::event::MouseButton;
+    let mut pressed = PressedButtons::default();
+    assert!(!pressed.any());
+    assert!(pressed.buttons().is_empty());
+    pressed.press(MouseButton::Left);
+    pressed.press(MouseButton::Right);
+    // Releasing one of the buttons keeps the other one held
+    pressed.release(MouseButton::Left);
+    assert!(pressed.any());
+    assert!(pressed.buttons().contains(PointerEventButton::Right));
+    assert!(!pressed.buttons().contains(PointerEventButton::Left));
+    pressed.release(MouseButton::Right);
+    assert!(!pressed.any());
+
+    // The extra buttons are told apart, even though they are all reported as Other
+    pressed.press(MouseButton::Other(4));
+    pressed.press(MouseButton::Other(5));
+    pressed.release(MouseButton::Other(4));
+    assert!(pressed.any());
+    assert_eq!(pressed.buttons(), PointerEventButtons::default().with(PointerEventButton::Other));
+    pressed.release(MouseButton::Other(5));
+    assert!(!pressed.any());
+}
+
 fn process_window_event(
     window: Rc<WinitWindowAdapter>,
     event: WindowEvent,
     cursor_pos: &mut LogicalPoint,
-    pressed: &mut bool,
+    pressed: &mut PressedButtons,
+    primary_touch: &mut Option<u64>,
 ) -> Result<(), i_slint_core::platform::PlatformError> {
     let runtime_window = WindowInner::from_pub(window.window());
     match event {
@@ -363,18 +424,24 @@ fn process_window_event(
         WindowEvent::CursorMoved { position, .. } => {
             let position = position.to_logical(runtime_window.scale_factor() as f64);
             *cursor_pos = euclid::point2(position.x, position.y);
-            runtime_window.process_mouse_input(MouseEvent::Moved { position: *cursor_pos });
+            runtime_window.process_mouse_input(MouseEvent::Moved {
+                position: *cursor_pos,
+                buttons: pressed.buttons(),
+            });
         }
         WindowEvent::CursorLeft { .. } => {
             // On the html canvas, we don't get the mouse move or release event when outside the canvas. So we have no choice but canceling the event
-            if cfg!(target_arch = "wasm32") || !*pressed {
-                *pressed = false;
+            if cfg!(target_arch = "wasm32") || !pressed.any() {
+                *pressed = PressedButtons::default();
                 runtime_window.process_mouse_input(MouseEvent::Exit);
             }
         }
         WindowEvent::MouseWheel { delta, .. } => {
             let (delta_x, delta_y) = match delta {
-                winit::event::MouseScrollDelta::LineDelta(lx, ly) => (lx * 60., ly * 60.),
+                winit::event::MouseScrollDelta::LineDelta(lx, ly) => (
+                    lx * i_slint_core::input::WHEEL_PIXELS_PER_LINE,
+                    ly * i_slint_core::input::WHEEL_PIXELS_PER_LINE,
+                ),
                 winit::event::MouseScrollDelta::PixelDelta(d) => {
                     let d = d.to_logical(runtime_window.scale_factor() as f64);
                     (d.x, d.y)
@@ -387,19 +454,15 @@ fn process_window_event(
             });
         }
         WindowEvent::MouseInput { state, button, .. } => {
-            let button = match button {
-                winit::event::MouseButton::Left => PointerEventButton::Left,
-                winit::event::MouseButton::Right => PointerEventButton::Right,
-                winit::event::MouseButton::Middle => PointerEventButton::Middle,
-                winit::event::MouseButton::Other(_) => PointerEventButton::Other,
-            };
             let ev = match state {
                 winit::event::ElementState::Pressed => {
-                    *pressed = true;
+                    pressed.press(button);
+                    let button = pointer_event_button(button);
                     MouseEvent::Pressed { position: *cursor_pos, button, click_count: 0 }
                 }
                 winit::event::ElementState::Released => {
-                    *pressed = false;
+                    pressed.release(button);
+                    let button = pointer_event_button(button);
                     MouseEvent::Released { position: *cursor_pos, button, click_count: 0 }
                 }
             };
@@ -418,9 +481,31 @@ fn process_window_event(
             };
             let location = location.to_logical(runtime_window.scale_factor() as f64);
             let position = euclid::point2(location.x, location.y);
+            let phase = match touch.phase {
+                winit::event::TouchPhase::Started => TouchPhase::Started,
+                winit::event::TouchPhase::Moved => TouchPhase::Moved,
+                winit::event::TouchPhase::Ended => TouchPhase::Ended,
+                winit::event::TouchPhase::Cancelled => TouchPhase::Cancelled,
+            };
+            runtime_window.process_mouse_input(MouseEvent::Touch { id: touch.id, phase, position });
+
+            // Only the first finger that touches the screen also acts as the pointer, the other
+            // ones are ignored until it is lifted.
+            match touch.phase {
+                winit::event::TouchPhase::Started if primary_touch.is_none() => {
+                    *primary_touch = Some(touch.id)
+                }
+                winit::event::TouchPhase::Ended | winit::event::TouchPhase::Cancelled
+                    if *primary_touch == Some(touch.id) =>
+                {
+                    *primary_touch = None
+                }
+                _ if *primary_touch != Some(touch.id) => return Ok(()),
+                _ => {}
+            }
             let ev = match touch.phase {
                 winit::event::TouchPhase::Started => {
-                    *pressed = true;
+                    pressed.press(winit::event::MouseButton::Left);
                     MouseEvent::Pressed {
                         position,
                         button: PointerEventButton::Left,
@@ -428,14 +513,16 @@ fn process_window_event(
                     }
                 }
                 winit::event::TouchPhase::Ended | winit::event::TouchPhase::Cancelled => {
-                    *pressed = false;
+                    pressed.release(winit::event::MouseButton::Left);
                     MouseEvent::Released {
                         position,
                         button: PointerEventButton::Left,
                         click_count: 0,
                     }
                 }
-                winit::event::TouchPhase::Moved => MouseEvent::Moved { position },
+                winit::event::TouchPhase::Moved => {
+                    MouseEvent::Moved { position, buttons: pressed.buttons() }
+                }
             };
             runtime_window.process_mouse_input(ev);
         }
@@ -496,7 +583,8 @@ pub fn run() -> Result<(), corelib::platform::PlatformError> {
 
     // last seen cursor position
     let mut cursor_pos = LogicalPoint::default();
-    let mut pressed = false;
+    let mut pressed = PressedButtons::default();
+    let mut primary_touch = None;
 
     let outer_event_loop_error = Rc::new(RefCell::new(None));
     let inner_event_loop_error = outer_event_loop_error.clone();
@@ -505,8 +593,14 @@ pub fn run() -> Result<(), corelib::platform::PlatformError> {
         match event {
             Event::WindowEvent { event, window_id } => {
                 if let Some(window) = window_by_id(window_id) {
-                    *inner_event_loop_error.borrow_mut() =
-                        process_window_event(window, event, &mut cursor_pos, &mut pressed).err();
+                    *inner_event_loop_error.borrow_mut() = process_window_event(
+                        window,
+                        event,
+                        &mut cursor_pos,
+                        &mut pressed,
+                        &mut primary_touch,
+                    )
+                    .err();
                 };
             }
 
@@ -57,10 +57,8 @@ fn physical_size_to_slint(size: &winit::dpi::PhysicalSize<u32>) -> corelib::api:
 fn icon_to_winit(icon: corelib::graphics::Image) -> Option<winit::window::Icon> {
     let image_inner: &ImageInner = (&icon).into();
 
-    let pixel_buffer = match image_inner {
-        ImageInner::EmbeddedImage { buffer, .. } => buffer.clone(),
-        _ => return None,
-    };
+    // Scalable images, such as SVG, are rendered at a size that is large enough for the task bar
+    let pixel_buffer = image_inner.render_to_buffer(Some(euclid::size2(256, 256)))?;
 
     // This could become a method in SharedPixelBuffer...
     let rgba_pixels: Vec<u8> = match &pixel_buffer {
@@ -83,7 +81,9 @@ fn icon_to_winit(icon: corelib::graphics::Image) -> Option<winit::window::Icon>
             .collect(),
     };
 
-    winit::window::Icon::from_rgba(rgba_pixels, pixel_buffer.width(), pixel_buffer.height()).ok()
+    winit::window::Icon::from_rgba(rgba_pixels, pixel_buffer.width(), pixel_buffer.height())
+        .map_err(|err| eprintln!("Error setting the window icon: {}", err))
+        .ok()
 }
 
 fn window_is_resizable(min_size: Option<LogicalSize>, max_size: Option<LogicalSize>) -> bool {
@@ -686,6 +686,18 @@ fn set_size(&self, size: corelib::api::WindowSize) {
         self.winit_window().set_inner_size(window_size_to_slint(&size))
     }
 
+    fn set_fullscreen(&self, fullscreen: bool) {
+        let winit_window = self.winit_window();
+        winit_window.set_fullscreen(
+            fullscreen
+                .then(|| winit::window::Fullscreen::Borderless(winit_window.current_monitor())),
+        )
+    }
+
+    fn set_maximized(&self, maximized: bool) {
+        self.winit_window().set_maximized(maximized)
+    }
+
     fn dark_color_scheme(&self) -> bool {
         self.dark_color_scheme
             .get_or_init(|| {
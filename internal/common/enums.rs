@@ -59,6 +59,16 @@ enum TextOverflow {
                 Elide,
             }
 
+            /// This enum describes the style of the glyphs of the font used to render text.
+            enum FontStyle {
+                /// The glyphs are upright.
+                Normal,
+                /// The glyphs of the italic variant of the font are used.
+                Italic,
+                /// The glyphs of the oblique variant of the font are used, which are slanted versions of the upright glyphs.
+                Oblique,
+            }
+
             /// This enum describes whether an event was rejected or accepted by an event handler.
             enum EventResult {
                 /// The event is rejected by this event handler and may then be handled by the parent item
@@ -149,6 +159,18 @@ enum PointerEventButton {
                 Middle,
             }
 
+            /// This enum describes what happened to a touch point in a `TouchEvent`.
+            enum TouchPhase {
+                /// The finger touched the screen.
+                Started,
+                /// The finger moved on the screen.
+                Moved,
+                /// The finger was lifted from the screen.
+                Ended,
+                /// The system cancelled the touch point, for example because the window lost the focus.
+                Cancelled,
+            }
+
             /// This enum represents different types of mouse cursors. It's a subset of the mouse cursors available in CSS.
             /// For details and pictograms see the [MDN Documentation for cursor](https://developer.mozilla.org/en-US/docs/Web/CSS/cursor#values).
             /// Depending on the backend and used OS unidirectional resize cursors may be replaced with bidirectional ones.
@@ -321,6 +343,18 @@ enum SortOrder {
                 /// The column is sorted in descending order.
                 Descending,
             }
+
+            /// This enum describes on which side of a [`TabWidget`](widgets.md#tabwidget) the tab bar is placed.
+            enum TabBarPosition {
+                /// The tab bar is placed above the content.
+                Top,
+                /// The tab bar is placed below the content.
+                Bottom,
+                /// The tab bar is placed to the left of the content, with the tabs stacked vertically.
+                Left,
+                /// The tab bar is placed to the right of the content, with the tabs stacked vertically.
+                Right,
+            }
         ];
     };
 }
@@ -431,6 +431,7 @@ pub fn for_each_const_properties(component: &Rc<Component>, mut f: impl FnMut(&E
                                     x.ty.is_property_type()
                                         && !k.starts_with("viewport-")
                                         && k.as_str() != "commands"
+                                        && k.as_str() != "stroke-dash-array"
                                 })
                                 .map(|(k, _)| k.clone()),
                         );
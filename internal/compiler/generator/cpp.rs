@@ -2345,6 +2345,9 @@ fn compile_expression(expr: &llr::Expression, ctx: &EvaluationContext) -> String
                 (Type::Brush, Type::Color) => {
                     format!("{}.color()", f)
                 }
+                (Type::Array(_), Type::LayoutCache) => {
+                    format!("[](const auto &model){{ slint::SharedVector<float> v; if (model) {{ (*model).track_row_count_changes(); for (size_t i = 0; i < (*model).row_count(); ++i) v.push_back(slint::private_api::access_array_index(model, i)); }} return v; }}({})", f)
+                }
                 (Type::Struct { .. }, Type::Struct{ fields, name: Some(_), ..}) => {
                     format!(
                         "[&](const auto &o){{ {struct_name} s; {fields} return s; }}({obj})",
@@ -2530,7 +2533,7 @@ fn compile_expression(expr: &llr::Expression, ctx: &EvaluationContext) -> String
                 )
             } else {
                 format!(
-                    "slint::cbindgen_private::Slice<{ty}>{{ std::array<{ty}, {count}>{{ {val} }}.data(), {count} }}",
+                    "slint::private_api::make_slice<{ty}>(std::array<{ty}, {count}>{{ {val} }}.data(), {count})",
                     count = values.len(),
                     ty = ty,
                     val = val.join(", ")
@@ -2864,7 +2867,7 @@ fn box_layout_function(
         format!("std::array<int, {}> {}_array;", 2 * repeater_idx, ri)
     });
     format!(
-        "[&]{{ {} {} slint::cbindgen_private::Slice<slint::cbindgen_private::BoxLayoutCellData>{}{{cells_vector.data(), cells_vector.size()}}; return {}; }}()",
+        "[&]{{ {} {} auto {} = slint::private_api::make_slice(cells_vector.data(), cells_vector.size()); return {}; }}()",
         ri,
         push_code,
         ident(cells_variable),
@@ -720,6 +720,7 @@ fn generate_sub_component(
                     if info.ty.is_property_type()
                         && !prop.starts_with("viewport")
                         && prop != "commands"
+                        && prop != "stroke-dash-array"
                     {
                         let name = format!("{}::{}.{}", component.name, item.name, prop);
                         let prop = ident(&prop);
@@ -1837,6 +1838,12 @@ fn compile_expression(expr: &Expression, ctx: &EvaluationContext) -> TokenStream
                 (Type::Brush, Type::Color) => {
                     quote!(#f.color())
                 }
+                (Type::Array(_), Type::LayoutCache) => {
+                    quote!(match &#f { x => {
+                        x.model_tracker().track_row_count_changes();
+                        (0..x.row_count()).map(|i| x.row_data_tracked(i).unwrap_or_default()).collect::<slint::private_unstable_api::re_exports::SharedVector<_>>()
+                    }})
+                }
                 (Type::Struct { ref fields, .. }, Type::Struct { name: Some(n), .. }) => {
                     let fields = fields.iter().enumerate().map(|(index, (name, _))| {
                         let index = proc_macro2::Literal::usize_unsuffixed(index);
@@ -494,6 +494,10 @@ fn property_ty(&self, prop: &PropertyReference) -> &Type {
                     // The `Path::elements` property is not in the NativeClass
                     return &Type::PathData;
                 }
+                if prop_name == "dash-array" {
+                    // Neither is the `Path::dash-array` property
+                    return &Type::LayoutCache;
+                }
 
                 let mut sub_component = self.current_sub_component.unwrap();
                 for i in sub_component_path {
@@ -83,8 +83,14 @@ pub async fn run_passes(
     for component in (root_component.used_types.borrow().sub_components.iter())
         .chain(std::iter::once(root_component))
     {
-        compile_paths::compile_paths(component, &doc.local_registry, diag);
         lower_tabwidget::lower_tabwidget(component, type_loader, diag).await;
+    }
+    // The TabWidget lowering may have introduced new sub-components
+    collect_subcomponents::collect_subcomponents(root_component);
+    for component in (root_component.used_types.borrow().sub_components.iter())
+        .chain(std::iter::once(root_component))
+    {
+        compile_paths::compile_paths(component, &doc.local_registry, diag);
         apply_default_properties_from_style::apply_default_properties_from_style(
             component,
             &style_metrics,
@@ -116,6 +122,7 @@ pub async fn run_passes(
         repeater_component::process_repeater_components(component);
         lower_popups::lower_popups(component, &doc.local_registry, diag);
         lower_layout::lower_layouts(component, type_loader, diag).await;
+        lower_tabwidget::lower_repeated_tab_geometry(component);
         default_geometry::default_geometry(component, diag);
         z_order::reorder_by_z_order(component, diag);
         lower_property_to_element::lower_property_to_element(
@@ -443,6 +443,9 @@ fn visit_implicit_layout_info_dependencies(
             vis(&NamedReference::new(item, "font-family").into());
             vis(&NamedReference::new(item, "font-size").into());
             vis(&NamedReference::new(item, "font-weight").into());
+            vis(&NamedReference::new(item, "font-style").into());
+            vis(&NamedReference::new(item, "font-features").into());
+            vis(&NamedReference::new(item, "font-variations").into());
             vis(&NamedReference::new(item, "letter-spacing").into());
             vis(&NamedReference::new(item, "wrap").into());
             let wrap_set = item.borrow().is_binding_set("wrap", false)
@@ -8,6 +8,8 @@
 //! to a vector of path elements (PathData) that is assigned to the
 //! elements property of the Path element. That way the generators have to deal
 //! with path embedding only as part of the property assignment.
+//! Similarly, the `stroke-dash-array` model is converted to the vector of lengths of
+//! the `dash-array` property.
 
 use crate::diagnostics::BuildDiagnostics;
 use crate::expression_tree::*;
@@ -35,6 +37,8 @@ pub fn compile_paths(
             _ => return,
         };
 
+        compile_dash_array(elem_);
+
         let element_types = &accepted_type.additional_accepted_child_types;
 
         let commands_binding =
@@ -118,6 +122,25 @@ pub fn compile_paths(
     });
 }
 
+/// The native item can't hold a model, so this materializes the `stroke-dash-array` property
+/// and converts it to the vector of lengths of the hidden `dash-array` property of the item.
+fn compile_dash_array(elem: &ElementRc) {
+    let ty = elem.borrow().lookup_property("stroke-dash-array").property_type;
+    elem.borrow_mut().property_declarations.insert(
+        "stroke-dash-array".into(),
+        PropertyDeclaration {
+            property_type: ty,
+            visibility: PropertyVisibility::Input,
+            ..PropertyDeclaration::default()
+        },
+    );
+    let dash_array = Expression::Cast {
+        from: Expression::PropertyReference(NamedReference::new(elem, "stroke-dash-array")).into(),
+        to: Type::LayoutCache,
+    };
+    elem.borrow_mut().bindings.insert("dash-array".into(), RefCell::new(dash_array.into()));
+}
+
 fn compile_path_from_string_literal(
     commands: &str,
 ) -> Result<BindingExpression, lyon_extra::parser::ParseError> {
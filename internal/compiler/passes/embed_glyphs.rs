@@ -111,11 +111,18 @@ pub fn embed_glyphs<'a>(
                     },
                 }
             } else {
-                diag.push_error(
-                    "The environment variable `SLINT_DEFAULT_FONT` is set, but its value is not referring to a file".into(),
-                    &generic_diag_location,
-                );
-                None
+                let family = maybe_font_path.to_string_lossy();
+                let id = fontdb.query(&fontdb::Query {
+                    families: &[fontdb::Family::Name(&family)],
+                    ..Default::default()
+                });
+                if id.is_none() {
+                    diag.push_error(
+                        format!("The environment variable `SLINT_DEFAULT_FONT` is set, but its value is neither referring to a file nor to an installed font family: {}", family),
+                        &generic_diag_location,
+                    );
+                }
+                id
             }
         });
 
@@ -9,7 +9,9 @@
 //! be further inlined as it may expends to native widget that needs inlining
 
 use crate::diagnostics::BuildDiagnostics;
-use crate::expression_tree::{BindingExpression, Expression, NamedReference, Unit};
+use crate::expression_tree::{
+    BindingExpression, BuiltinFunction, Expression, NamedReference, Unit,
+};
 use crate::langtype::{ElementType, Type};
 use crate::object_tree::*;
 use std::cell::RefCell;
@@ -34,6 +36,10 @@ pub async fn lower_tabwidget(
         .import_component("std-widgets.slint", "TabBarImpl", &mut build_diags_to_ignore)
         .await
         .expect("can't load TabBarImpl from std-widgets.slint");
+    let vertical_tabbar_impl = type_loader
+        .import_component("std-widgets.slint", "VerticalTabBarImpl", &mut build_diags_to_ignore)
+        .await
+        .expect("can't load VerticalTabBarImpl from std-widgets.slint");
     let empty_type = type_loader.global_type_registry.borrow().empty_type();
 
     recurse_elem_including_sub_components_no_borrow(component, &(), &mut |elem, _| {
@@ -43,6 +49,7 @@ pub async fn lower_tabwidget(
                 ElementType::Component(tabwidget_impl.clone()),
                 ElementType::Component(tab_impl.clone()),
                 ElementType::Component(tabbar_impl.clone()),
+                ElementType::Component(vertical_tabbar_impl.clone()),
                 &empty_type,
                 diag,
             );
@@ -55,6 +62,7 @@ fn process_tabwidget(
     tabwidget_impl: ElementType,
     tab_impl: ElementType,
     tabbar_impl: ElementType,
+    vertical_tabbar_impl: ElementType,
     empty_type: &ElementType,
     diag: &mut BuildDiagnostics,
 ) {
@@ -63,32 +71,76 @@ fn process_tabwidget(
         return;
     }
 
+    // The tabs are laid out in a different element for a vertical tab bar, so the position
+    // must be known at compile time
+    let vertical_tabbar = match elem.borrow().bindings.get("tab-bar-position") {
+        None => false,
+        Some(binding) => match &binding.borrow().expression {
+            Expression::EnumerationValue(value) => {
+                matches!(value.enumeration.values[value.value].as_str(), "left" | "right")
+            }
+            // An error was already reported
+            Expression::Invalid if binding.borrow().two_way_bindings.is_empty() => false,
+            _ => {
+                diag.push_error(
+                    "The tab-bar-position of a TabWidget must be a constant value".to_owned(),
+                    &*binding.borrow(),
+                );
+                false
+            }
+        },
+    };
+
     elem.borrow_mut().base_type = tabwidget_impl;
     let mut children = std::mem::take(&mut elem.borrow_mut().children);
-    let num_tabs = children.len();
+
+    // The number of tabs is the number of static tabs, plus the number of rows of the models of the dynamic tabs
+    let mut num_static_tabs = 0;
+    let mut dynamic_counts = Vec::new();
+    for child in &children {
+        match &child.borrow().repeated {
+            Some(repeated) => dynamic_counts.push(repeated_count(repeated)),
+            None => num_static_tabs += 1,
+        }
+    }
+    let num_tabs = add_counts(num_static_tabs, dynamic_counts.iter().cloned());
+
     let mut tabs = Vec::new();
+    // The index of the first tab created by the current child
+    let mut num_static_tabs_before = 0;
+    let mut dynamic_counts_before = Vec::new();
+    let mut tab_bar_entries = Vec::new();
+    let mut tab_enabled_entries = Vec::new();
     for child in &mut children {
-        if child.borrow().repeated.is_some() {
-            diag.push_error(
-                "dynamic tabs ('if' or 'for') are currently not supported".into(),
-                &*child.borrow(),
-            );
-            continue;
-        }
         if child.borrow().base_type.to_string() != "Tab" {
             assert!(diag.has_error());
             continue;
         }
+        let repeated = child.borrow().repeated.clone();
+        let first_index = add_counts(num_static_tabs_before, dynamic_counts_before.iter().cloned());
+        match &repeated {
+            Some(repeated) => dynamic_counts_before.push(repeated_count(repeated)),
+            None => num_static_tabs_before += 1,
+        }
+
         let index = tabs.len();
         child.borrow_mut().base_type = empty_type.clone();
         child.borrow_mut().property_declarations.insert("title".to_owned(), Type::String.into());
+        child.borrow_mut().property_declarations.insert("icon".to_owned(), Type::Image.into());
+        child.borrow_mut().property_declarations.insert("closeable".to_owned(), Type::Bool.into());
+        child.borrow_mut().property_declarations.insert("enabled".to_owned(), Type::Bool.into());
+        child
+            .borrow_mut()
+            .bindings
+            .entry("enabled".to_owned())
+            .or_insert_with(|| RefCell::new(Expression::BoolLiteral(true).into()));
         set_geometry_prop(elem, child, "x", diag);
         set_geometry_prop(elem, child, "y", diag);
         set_geometry_prop(elem, child, "width", diag);
         set_geometry_prop(elem, child, "height", diag);
         let condition = Expression::BinaryExpression {
             lhs: Expression::PropertyReference(NamedReference::new(elem, "current-index")).into(),
-            rhs: Expression::NumberLiteral(index as _, Unit::None).into(),
+            rhs: tab_index(&first_index, child).into(),
             op: '=',
         };
         let old = child
@@ -102,38 +154,100 @@ fn process_tabwidget(
             );
         }
 
-        let mut tab = Element {
+        let tab = Rc::new(RefCell::new(Element {
             id: format!("{}-tab{}", elem.borrow().id, index),
             base_type: tab_impl.clone(),
             enclosing_component: elem.borrow().enclosing_component.clone(),
+            repeated: repeated.clone(),
             ..Default::default()
-        };
-        tab.bindings.insert(
-            "title".to_owned(),
-            BindingExpression::new_two_way(NamedReference::new(child, "title")).into(),
-        );
-        tab.bindings.insert(
+        }));
+        let tab_index = tab_index(&first_index, &tab);
+        // The index of the tab passed to the tab-enabled callback
+        let tab_arg = Expression::FunctionParameterReference { index: 0, ty: Type::Int32 };
+        match (&repeated, dynamic_counts_before.last()) {
+            (Some(repeated), Some(count)) => {
+                let condition = binary_expression(
+                    binary_expression(tab_arg.clone(), first_index.clone(), '≥'),
+                    binary_expression(
+                        tab_arg.clone(),
+                        binary_expression(first_index.clone(), count.clone(), '+'),
+                        '<',
+                    ),
+                    '&',
+                );
+                let row = binary_expression(tab_arg, first_index.clone(), '-');
+                let enabled = repeated_tab_binding_for_row(child, repeated, "enabled", &row)
+                    .unwrap_or(Expression::BoolLiteral(true));
+                tab_enabled_entries.push((condition, enabled));
+                tab_bar_entries.push(TabBarEntry::Dynamic {
+                    tab: tab.clone(),
+                    first_index: first_index.clone(),
+                    count: count.clone(),
+                });
+            }
+            _ => {
+                tab_enabled_entries.push((
+                    binary_expression(tab_arg, first_index.clone(), '='),
+                    Expression::PropertyReference(NamedReference::new(&tab, "enabled")),
+                ));
+                tab_bar_entries.push(TabBarEntry::Static { tab: tab.clone(), index: first_index });
+            }
+        }
+        for prop in ["title", "icon", "closeable", "enabled"] {
+            let binding = if repeated.is_some() {
+                // The tab in the tab bar is a different instance than the content, so it can't be
+                // bound to the content's properties. Evaluate the bindings in the tab instead.
+                dynamic_tab_binding(child, &tab, prop, diag)
+            } else {
+                Some(BindingExpression::new_two_way(NamedReference::new(child, prop)))
+            };
+            if let Some(binding) = binding {
+                tab.borrow_mut().bindings.insert(prop.to_owned(), binding.into());
+            }
+        }
+        let mut tab_mut = tab.borrow_mut();
+        tab_mut.bindings.insert(
             "current".to_owned(),
             BindingExpression::new_two_way(NamedReference::new(elem, "current-index")).into(),
         );
-        tab.bindings.insert(
+        tab_mut.bindings.insert(
             "current-focused".to_owned(),
             BindingExpression::new_two_way(NamedReference::new(elem, "current-focused")).into(),
         );
-        tab.bindings.insert(
-            "tab-index".to_owned(),
-            RefCell::new(Expression::NumberLiteral(index as _, Unit::None).into()),
+        tab_mut.bindings.insert(
+            "close-clicked".to_owned(),
+            RefCell::new(
+                Expression::FunctionCall {
+                    function: Expression::CallbackReference(
+                        NamedReference::new(elem, "tab-close-clicked"),
+                        None,
+                    )
+                    .into(),
+                    arguments: vec![tab_index.clone()],
+                    source_location: None,
+                }
+                .into(),
+            ),
         );
-        tab.bindings.insert(
-            "num-tabs".to_owned(),
-            RefCell::new(Expression::NumberLiteral(num_tabs as _, Unit::None).into()),
-        );
-        tabs.push(Rc::new(RefCell::new(tab)));
+        tab_mut.bindings.insert("tab-index".to_owned(), RefCell::new(tab_index.into()));
+        tab_mut.bindings.insert("num-tabs".to_owned(), RefCell::new(num_tabs.clone().into()));
+        // Only the native style draws its tabs differently depending on the side of the tab bar
+        if tab_impl.lookup_property("tab-bar-position").is_valid() {
+            tab_mut.bindings.insert(
+                "tab-bar-position".to_owned(),
+                RefCell::new(
+                    Expression::PropertyReference(NamedReference::new(elem, "tab-bar-position"))
+                        .into(),
+                ),
+            );
+        }
+        drop(tab_mut);
+        tabs.push(tab);
     }
 
     let tabbar = Element {
         id: format!("{}-tabbar", elem.borrow().id),
-        base_type: tabbar_impl,
+        base_type: if vertical_tabbar { vertical_tabbar_impl } else { tabbar_impl },
         enclosing_component: elem.borrow().enclosing_component.clone(),
         children: tabs,
         ..Default::default()
@@ -143,10 +257,57 @@ fn process_tabwidget(
     set_tabbar_geometry_prop(elem, &tabbar, "y");
     set_tabbar_geometry_prop(elem, &tabbar, "width");
     set_tabbar_geometry_prop(elem, &tabbar, "height");
-    tabbar.borrow_mut().bindings.insert(
-        "num-tabs".to_owned(),
-        RefCell::new(Expression::NumberLiteral(num_tabs as _, Unit::None).into()),
+    let (pos, size) = if vertical_tabbar { ("y", "height") } else { ("x", "width") };
+    let (scroll_tab_pos, scroll_tab_size) =
+        scroll_tab_geometry(&tabbar, &tab_bar_entries, pos, size);
+    tabbar
+        .borrow_mut()
+        .bindings
+        .insert("scroll-tab-pos".into(), RefCell::new(scroll_tab_pos.into()));
+    tabbar
+        .borrow_mut()
+        .bindings
+        .insert("scroll-tab-size".into(), RefCell::new(scroll_tab_size.into()));
+    let tab_enabled = tab_enabled_entries.into_iter().rev().fold(
+        Expression::BoolLiteral(true),
+        |false_expr, (condition, enabled)| Expression::Condition {
+            condition: condition.into(),
+            true_expr: enabled.into(),
+            false_expr: false_expr.into(),
+        },
     );
+    elem.borrow_mut().bindings.insert("tab-enabled".to_owned(), RefCell::new(tab_enabled.into()));
+    let call_tab_enabled = Expression::FunctionCall {
+        function: Expression::CallbackReference(NamedReference::new(elem, "tab-enabled"), None)
+            .into(),
+        arguments: vec![Expression::FunctionParameterReference { index: 0, ty: Type::Int32 }],
+        source_location: None,
+    };
+    tabbar
+        .borrow_mut()
+        .bindings
+        .insert("tab-enabled".to_owned(), RefCell::new(call_tab_enabled.into()));
+    let call_find_enabled_tab = Expression::FunctionCall {
+        function: Expression::FunctionReference(
+            NamedReference::new(elem, "find-enabled-tab"),
+            None,
+        )
+        .into(),
+        arguments: vec![
+            Expression::FunctionParameterReference { index: 0, ty: Type::Int32 },
+            Expression::FunctionParameterReference { index: 1, ty: Type::Bool },
+        ],
+        source_location: None,
+    };
+    tabbar
+        .borrow_mut()
+        .bindings
+        .insert("find-enabled-tab".to_owned(), RefCell::new(call_find_enabled_tab.into()));
+    tabbar
+        .borrow_mut()
+        .bindings
+        .insert("num-tabs".to_owned(), RefCell::new(num_tabs.clone().into()));
+    elem.borrow_mut().bindings.insert("num-tabs".to_owned(), RefCell::new(num_tabs.into()));
     tabbar.borrow_mut().bindings.insert(
         "current".to_owned(),
         BindingExpression::new_two_way(NamedReference::new(elem, "current-index")).into(),
@@ -155,6 +316,16 @@ fn process_tabwidget(
         "current-focused".to_owned(),
         BindingExpression::new_two_way(NamedReference::new(&tabbar, "current-focused")).into(),
     );
+    let focus_tabbar = Expression::FunctionCall {
+        function: Expression::FunctionReference(
+            NamedReference::new(&tabbar, "focus-current-tab"),
+            None,
+        )
+        .into(),
+        arguments: vec![],
+        source_location: None,
+    };
+    elem.borrow_mut().bindings.insert("focus-tabbar".to_owned(), RefCell::new(focus_tabbar.into()));
     elem.borrow_mut().bindings.insert(
         "tabbar-preferred-width".to_owned(),
         BindingExpression::new_two_way(NamedReference::new(&tabbar, "preferred-width")).into(),
@@ -164,8 +335,10 @@ fn process_tabwidget(
         BindingExpression::new_two_way(NamedReference::new(&tabbar, "preferred-height")).into(),
     );
 
+    // The minimum size of dynamic tabs can't be queried from outside of their repeater
     if let Some(expr) = children
         .iter()
+        .filter(|x| x.borrow().repeated.is_none())
         .map(|x| Expression::PropertyReference(NamedReference::new(x, "min-width")))
         .reduce(|lhs, rhs| crate::builtin_macros::min_max_expression(lhs, rhs, '>'))
     {
@@ -173,6 +346,7 @@ fn process_tabwidget(
     };
     if let Some(expr) = children
         .iter()
+        .filter(|x| x.borrow().repeated.is_none())
         .map(|x| Expression::PropertyReference(NamedReference::new(x, "min-height")))
         .reduce(|lhs, rhs| crate::builtin_macros::min_max_expression(lhs, rhs, '>'))
     {
@@ -218,3 +392,273 @@ fn set_tabbar_geometry_prop(tab_widget: &ElementRc, tabbar: &ElementRc, prop: &s
         ),
     );
 }
+
+/// The tabs of the tab bar, used to find the position of the current tab
+enum TabBarEntry {
+    /// A tab that is not repeated, at the given index
+    Static { tab: ElementRc, index: Expression },
+    /// The tabs created by a repeated Tab
+    Dynamic { tab: ElementRc, first_index: Expression, count: Expression },
+}
+
+/// The local variable that holds the index within its repeater of the tab at the `scroll-tab` index
+const SCROLL_TAB_ROW: &str = "scroll-tab-row";
+
+/// Returns the expressions for the position and size along the tab bar of the tab at the index
+/// given by the `scroll-tab` property of the tab bar, so that the tab bar can scroll to show it.
+/// The tabs of a repeater can't be queried from outside of it, so for these tabs the expressions
+/// refer to the position and size of the repeated tab while [`SCROLL_TAB_ROW`] is set. They are
+/// replaced by [`lower_repeated_tab_geometry`] once the layout of the tab bar is lowered.
+fn scroll_tab_geometry(
+    tabbar: &ElementRc,
+    entries: &[TabBarEntry],
+    pos: &str,
+    size: &str,
+) -> (Expression, Expression) {
+    let prop =
+        |e: &ElementRc, name: &str| Expression::PropertyReference(NamedReference::new(e, name));
+    let current = prop(tabbar, "scroll-tab");
+
+    let mut current_pos = Expression::NumberLiteral(0., Unit::Px);
+    let mut current_size = Expression::NumberLiteral(0., Unit::Px);
+    for entry in entries.iter().rev() {
+        let (condition, entry_pos, entry_size) = match entry {
+            TabBarEntry::Static { tab, index } => (
+                binary_expression(current.clone(), index.clone(), '='),
+                prop(tab, pos),
+                prop(tab, size),
+            ),
+            TabBarEntry::Dynamic { tab, first_index, count } => {
+                let condition = binary_expression(
+                    binary_expression(current.clone(), first_index.clone(), '≥'),
+                    binary_expression(
+                        current.clone(),
+                        binary_expression(first_index.clone(), count.clone(), '+'),
+                        '<',
+                    ),
+                    '&',
+                );
+                let row = binary_expression(current.clone(), first_index.clone(), '-');
+                let geometry = |name: &str| {
+                    Expression::CodeBlock(vec![
+                        Expression::StoreLocalVariable {
+                            name: SCROLL_TAB_ROW.into(),
+                            value: row.clone().into(),
+                        },
+                        prop(tab, name),
+                    ])
+                };
+                (condition, geometry(pos), geometry(size))
+            }
+        };
+        current_pos = Expression::Condition {
+            condition: condition.clone().into(),
+            true_expr: entry_pos.into(),
+            false_expr: current_pos.into(),
+        };
+        current_size = Expression::Condition {
+            condition: condition.into(),
+            true_expr: entry_size.into(),
+            false_expr: current_size.into(),
+        };
+    }
+    (current_pos, current_size)
+}
+
+/// Replaces the references to the position and size of the repeated tabs in the `scroll-tab-pos`
+/// and `scroll-tab-size` bindings of the tab bars with the position and size that the layout of the
+/// tab bar computes for the tab at the row [`SCROLL_TAB_ROW`] of the repeater.
+///
+/// Must be run after the layouts are lowered.
+pub fn lower_repeated_tab_geometry(component: &Rc<Component>) {
+    recurse_elem_including_sub_components_no_borrow(component, &(), &mut |elem, _| {
+        let enclosing_component = elem.borrow().enclosing_component.upgrade();
+        for prop in ["scroll-tab-pos", "scroll-tab-size"] {
+            let elem = elem.borrow();
+            let Some(binding) = elem.bindings.get(prop) else { continue };
+            binding.borrow_mut().expression.visit_recursive_mut(&mut |expr| {
+                let Expression::PropertyReference(nr) = expr else { return };
+                let tab = nr.element();
+                let repeater = tab
+                    .borrow()
+                    .enclosing_component
+                    .upgrade()
+                    .and_then(|c| c.parent_element.upgrade());
+                let is_repeated_tab = repeater.map_or(false, |repeater| {
+                    repeater.borrow().enclosing_component.upgrade().as_ref().map(Rc::as_ptr)
+                        == enclosing_component.as_ref().map(Rc::as_ptr)
+                });
+                if !is_repeated_tab {
+                    return;
+                }
+                let geometry =
+                    tab.borrow().bindings.get(nr.name()).map(|b| b.borrow().expression.clone());
+                *expr = match geometry {
+                    Some(Expression::LayoutCacheAccess { layout_cache_prop, index, .. }) => {
+                        Expression::LayoutCacheAccess {
+                            layout_cache_prop,
+                            index,
+                            repeater_index: Some(
+                                Expression::ReadLocalVariable {
+                                    name: SCROLL_TAB_ROW.into(),
+                                    ty: Type::Int32,
+                                }
+                                .into(),
+                            ),
+                        }
+                    }
+                    // The tab is not placed by the layout of the tab bar
+                    _ => Expression::NumberLiteral(0., Unit::Px),
+                };
+            });
+        }
+    })
+}
+
+fn binary_expression(lhs: Expression, rhs: Expression, op: char) -> Expression {
+    Expression::BinaryExpression { lhs: lhs.into(), rhs: rhs.into(), op }
+}
+
+/// Returns the binding of the property `prop` of the repeated tab `content`, evaluated for the
+/// tab at `row` within the repeater, or None if the property isn't set
+fn repeated_tab_binding_for_row(
+    content: &ElementRc,
+    repeated: &RepeatedElementInfo,
+    prop: &str,
+    row: &Expression,
+) -> Option<Expression> {
+    let content_borrow = content.borrow();
+    let binding = content_borrow.bindings.get(prop)?.borrow();
+    if !binding.two_way_bindings.is_empty() {
+        // The error is reported by dynamic_tab_binding
+        return None;
+    }
+    let model = match &repeated.model {
+        Expression::Cast { from, to: Type::Model } => &**from,
+        model => model,
+    };
+    // The model data of a repeater on a number is the index, and conditional tabs have no model data
+    let model_data = match model.ty() {
+        _ if repeated.is_conditional_element => row.clone(),
+        Type::Int32 | Type::Float32 => row.clone(),
+        _ => Expression::ArrayIndex { array: model.clone().into(), index: row.clone().into() },
+    };
+
+    let mut expression = binding.expression.clone();
+    expression.visit_recursive_mut(&mut |expr| {
+        let replacement = match expr {
+            Expression::RepeaterModelReference { element }
+                if element.upgrade().map_or(false, |e| Rc::ptr_eq(&e, content)) =>
+            {
+                model_data.clone()
+            }
+            Expression::RepeaterIndexReference { element }
+                if element.upgrade().map_or(false, |e| Rc::ptr_eq(&e, content)) =>
+            {
+                row.clone()
+            }
+            _ => return,
+        };
+        *expr = replacement;
+    });
+    Some(expression)
+}
+
+/// Returns an expression with the number of tabs created by a repeated Tab
+fn repeated_count(repeated: &RepeatedElementInfo) -> Expression {
+    if repeated.is_conditional_element {
+        return Expression::Condition {
+            condition: repeated.model.clone().into(),
+            true_expr: Expression::NumberLiteral(1., Unit::None).into(),
+            false_expr: Expression::NumberLiteral(0., Unit::None).into(),
+        };
+    }
+    let model = match &repeated.model {
+        Expression::Cast { from, to: Type::Model } => &**from,
+        model => model,
+    };
+    match model.ty() {
+        Type::Int32 | Type::Float32 => crate::builtin_macros::min_max_expression(
+            model.clone(),
+            Expression::NumberLiteral(0., Unit::None),
+            '>',
+        ),
+        _ => Expression::FunctionCall {
+            function: Expression::BuiltinFunctionReference(BuiltinFunction::ArrayLength, None)
+                .into(),
+            arguments: vec![model.clone()],
+            source_location: None,
+        },
+    }
+}
+
+/// Returns the index of a tab. For a tab repeated with `for`, that's the index of the first tab
+/// created by the repeater plus the index within the repeater of the `element`
+fn tab_index(first_index: &Expression, element: &ElementRc) -> Expression {
+    match &element.borrow().repeated {
+        Some(repeated) if !repeated.is_conditional_element => Expression::BinaryExpression {
+            lhs: first_index.clone().into(),
+            rhs: Expression::RepeaterIndexReference { element: Rc::downgrade(element) }.into(),
+            op: '+',
+        },
+        _ => first_index.clone(),
+    }
+}
+
+/// Returns an expression for the sum of `num_static` and all the `dynamic_counts`
+fn add_counts(num_static: usize, dynamic_counts: impl Iterator<Item = Expression>) -> Expression {
+    dynamic_counts.fold(Expression::NumberLiteral(num_static as _, Unit::None), |lhs, rhs| {
+        Expression::BinaryExpression { lhs: lhs.into(), rhs: rhs.into(), op: '+' }
+    })
+}
+
+/// Returns the binding for the property `prop` of the `tab` in the tab bar that corresponds to the
+/// repeated tab `content`: a copy of the content's binding, where the references to the model data
+/// and index of the content refer to the ones of the tab instead.
+fn dynamic_tab_binding(
+    content: &ElementRc,
+    tab: &ElementRc,
+    prop: &str,
+    diag: &mut BuildDiagnostics,
+) -> Option<BindingExpression> {
+    let content_borrow = content.borrow();
+    let binding = content_borrow.bindings.get(prop)?.borrow();
+    if !binding.two_way_bindings.is_empty() {
+        diag.push_error(
+            format!("The {prop} of a dynamic tab cannot be a two way binding"),
+            &*binding,
+        );
+        return None;
+    }
+
+    let mut content_elements = Vec::new();
+    recurse_elem(content, &(), &mut |e, _| content_elements.push(e.clone()));
+
+    let mut result = binding.clone();
+    let mut references_content = false;
+    result.expression.visit_recursive_mut(&mut |expr| match expr {
+        Expression::RepeaterModelReference { element }
+        | Expression::RepeaterIndexReference { element }
+            if element.upgrade().map_or(false, |e| Rc::ptr_eq(&e, content)) =>
+        {
+            *element = Rc::downgrade(tab);
+        }
+        Expression::PropertyReference(nr)
+        | Expression::CallbackReference(nr, _)
+        | Expression::FunctionReference(nr, _) => {
+            let element = nr.element();
+            references_content |= content_elements.iter().any(|e| Rc::ptr_eq(e, &element));
+        }
+        _ => {}
+    });
+    if references_content {
+        diag.push_error(
+            format!(
+                "The {prop} of a dynamic tab can only depend on the model data, not on the tab's content"
+            ),
+            &*binding,
+        );
+        return None;
+    }
+    Some(result)
+}
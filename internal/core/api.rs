@@ -351,6 +351,10 @@ pub fn show(&self) -> Result<(), PlatformError> {
     }
 
     /// De-registers the window from the windowing system, therefore hiding it.
+    ///
+    /// This can be used to close a window from code, for example from the handler of a "Quit" button:
+    /// when the last visible window is hidden, [`crate::run_event_loop()`](fn.run_event_loop.html) returns.
+    /// Calling this function on a window that is not shown does nothing.
     pub fn hide(&self) -> Result<(), PlatformError> {
         self.0.hide()
     }
@@ -370,7 +374,19 @@ pub fn on_close_requested(&self, callback: impl FnMut() -> CloseRequestResponse
         self.0.on_close_requested(callback);
     }
 
+    /// This function allows registering a callback that's invoked with the window's scale factor
+    /// when the window is first shown, and whenever the scale factor changes afterwards, for
+    /// example when the window is moved to a screen with a different resolution. Use this to
+    /// reload raster assets at the new pixel density.
+    pub fn on_scale_factor_changed(&self, callback: impl FnMut(f32) + 'static) {
+        self.0.on_scale_factor_changed(callback);
+    }
+
     /// This function issues a request to the windowing system to redraw the contents of the window.
+    ///
+    /// The window is redrawn the next time the event loop processes events, without the need for an
+    /// input event. Several requests made before that are coalesced into a single redraw, so this can
+    /// be called freely, for example on every tick of a [`Timer`](crate::timers::Timer) that drives an animation.
     pub fn request_redraw(&self) {
         self.0.window_adapter().request_redraw();
     }
@@ -414,6 +430,38 @@ pub fn set_size(&self, size: impl Into<WindowSize>) {
         }
     }
 
+    /// Sets whether the window is shown in fullscreen mode, covering the whole screen that
+    /// currently contains it. When leaving the fullscreen mode, the window gets back the size
+    /// and position it had before.
+    /// Note that on some windowing systems, this functionality is not available.
+    pub fn set_fullscreen(&self, fullscreen: bool) {
+        self.0.window_adapter().set_fullscreen(fullscreen)
+    }
+
+    /// Sets whether the window is maximized. When the window is restored, it gets back the size
+    /// and position it had before.
+    /// Note that on some windowing systems, this functionality is not available.
+    pub fn set_maximized(&self, maximized: bool) {
+        self.0.window_adapter().set_maximized(maximized)
+    }
+
+    /// Renders the window as it currently appears and returns a copy of its contents, for
+    /// example to attach to a bug report or to compare against a reference in a test.
+    ///
+    /// The returned buffer has the size of the window in physical pixels. Each pixel is stored
+    /// as four bytes in RGBA order, without premultiplied alpha, and rows are stored one after
+    /// the other without padding, so the stride is the width of the buffer.
+    ///
+    /// This returns an error if the renderer used by the backend can't read back its contents.
+    pub fn grab_frame(
+        &self,
+    ) -> Result<
+        crate::graphics::SharedPixelBuffer<crate::graphics::Rgba8Pixel>,
+        crate::platform::PlatformError,
+    > {
+        self.0.window_adapter().renderer().grab_frame()
+    }
+
     /// Dispatch a window event to the scene.
     ///
     /// Use this when you're implementing your own backend and want to forward user input events.
@@ -423,6 +471,7 @@ pub fn set_size(&self, size: impl Into<WindowSize>) {
     pub fn dispatch_event(&self, event: crate::platform::WindowEvent) {
         match event {
             crate::platform::WindowEvent::PointerPressed { position, button } => {
+                self.0.pressed_buttons.set(self.0.pressed_buttons.get().with(button));
                 self.0.process_mouse_input(MouseEvent::Pressed {
                     position: position.to_euclid().cast(),
                     button,
@@ -430,6 +479,7 @@ pub fn dispatch_event(&self, event: crate::platform::WindowEvent) {
                 });
             }
             crate::platform::WindowEvent::PointerReleased { position, button } => {
+                self.0.pressed_buttons.set(self.0.pressed_buttons.get().without(button));
                 self.0.process_mouse_input(MouseEvent::Released {
                     position: position.to_euclid().cast(),
                     button,
@@ -439,6 +489,7 @@ pub fn dispatch_event(&self, event: crate::platform::WindowEvent) {
             crate::platform::WindowEvent::PointerMoved { position } => {
                 self.0.process_mouse_input(MouseEvent::Moved {
                     position: position.to_euclid().cast(),
+                    buttons: self.0.pressed_buttons.get(),
                 });
             }
             crate::platform::WindowEvent::PointerScrolled { position, delta_x, delta_y } => {
@@ -448,7 +499,15 @@ pub fn dispatch_event(&self, event: crate::platform::WindowEvent) {
                     delta_y,
                 });
             }
+            crate::platform::WindowEvent::Touch { id, phase, position } => {
+                self.0.process_mouse_input(MouseEvent::Touch {
+                    id,
+                    phase,
+                    position: position.to_euclid().cast(),
+                });
+            }
             crate::platform::WindowEvent::PointerExited => {
+                self.0.pressed_buttons.take();
                 self.0.process_mouse_input(MouseEvent::Exit)
             }
 
@@ -558,6 +617,7 @@ fn as_weak(&self) -> Weak<Self>
 
     /// Marks the window of this component to be hidden on the screen. This de-registers
     /// the window from the windowing system and it will not receive any further events.
+    /// See [`Window::hide()`].
     fn hide(&self) -> Result<(), PlatformError>;
 
     /// Returns the Window associated with this component. The window API can be used
@@ -44,6 +44,7 @@ pub struct ComponentVTable {
     /// Visit the children of the item at index `index`.
     /// Note that the root item is at index 0, so passing 0 would visit the item under root (the children of root).
     /// If you want to visit the root item, you need to pass -1 as an index.
+    /// [`crate::item_tree::visit_root`] and [`crate::item_tree::visit_children`] wrap this convention.
     pub visit_children_item: extern "C" fn(
         core::pin::Pin<VRef<ComponentVTable>>,
         index: isize,
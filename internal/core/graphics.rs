@@ -11,10 +11,12 @@
     created by the backend in a type-erased manner.
 */
 extern crate alloc;
+use crate::items::FontStyle;
 use crate::lengths::LogicalLength;
 use crate::Coord;
 use crate::SharedString;
 use alloc::boxed::Box;
+use alloc::vec::Vec;
 
 pub use euclid;
 /// 2D Rectangle
@@ -130,6 +132,83 @@ pub fn clear(&mut self) {
         self.generation += 1;
     }
 }
+
+/// An OpenType feature setting, such as enabling tabular figures or disabling ligatures.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FontFeature {
+    /// The four letter tag of the feature, such as `tnum` or `liga`.
+    pub tag: [u8; 4],
+    /// Zero disables the feature, one enables it. Features with alternates use higher values to select one.
+    pub value: u32,
+}
+
+impl FontFeature {
+    /// Parses a comma separated list of feature settings, such as `"tnum, -liga"`. Each setting is a
+    /// feature tag, optionally prefixed with `+` to enable the feature or `-` to disable it, or
+    /// followed by `=` and a value. Invalid settings are ignored.
+    pub fn parse_list(list: &str) -> impl Iterator<Item = FontFeature> + '_ {
+        list.split(',').filter_map(|setting| {
+            let setting = setting.trim();
+            let (tag, value) = if let Some(tag) = setting.strip_prefix('-') {
+                (tag, 0)
+            } else if let Some((tag, value)) = setting.split_once('=') {
+                (tag.trim_end(), value.trim_start().parse().ok()?)
+            } else {
+                (setting.strip_prefix('+').unwrap_or(setting), 1)
+            };
+            Some(FontFeature { tag: tag.as_bytes().try_into().ok()?, value })
+        })
+    }
+}
+
+/// The value of a variation axis of a variable font, such as the weight or the width.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FontVariation {
+    /// The four letter tag of the axis, such as `wght` or `wdth`.
+    pub tag: [u8; 4],
+    /// The value on the axis, in the range that the font defines for it.
+    pub value: f32,
+}
+
+impl FontVariation {
+    /// Parses a comma separated list of axis values, such as `"wght=350, wdth=80"`. Invalid
+    /// values are ignored.
+    pub fn parse_list(list: &str) -> impl Iterator<Item = FontVariation> + '_ {
+        list.split(',').filter_map(|setting| {
+            let (tag, value) = setting.split_once('=')?;
+            Some(FontVariation {
+                tag: tag.trim().as_bytes().try_into().ok()?,
+                value: value.trim().parse().ok()?,
+            })
+        })
+    }
+}
+
+/// The vertical metrics of a font, such as its ascent and descent.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct FontVerticalMetrics<Length> {
+    /// The distance from the baseline to the top of the tallest glyphs.
+    pub ascent: Length,
+    /// The distance from the baseline to the bottom of the lowest glyphs. This is negative.
+    pub descent: Length,
+    /// The gap to add between the descent of a line and the ascent of the next one.
+    pub line_gap: Length,
+    /// The height of the capital letters above the baseline.
+    pub cap_height: Length,
+}
+
+impl<Length> FontVerticalMetrics<Length> {
+    /// Converts each of the metrics with `f`, for example to another unit.
+    pub fn map<T>(self, f: impl Fn(Length) -> T) -> FontVerticalMetrics<T> {
+        FontVerticalMetrics {
+            ascent: f(self.ascent),
+            descent: f(self.descent),
+            line_gap: f(self.line_gap),
+            cap_height: f(self.cap_height),
+        }
+    }
+}
+
 /// FontRequest collects all the developer-configurable properties for fonts, such as family, weight, etc.
 /// It is submitted as a request to the platform font system (i.e. CoreText on macOS) and in exchange the
 /// backend returns a `Box<dyn Font>`.
@@ -140,11 +219,69 @@ pub struct FontRequest {
     pub family: Option<SharedString>,
     /// If the weight is None, the system default font weight should be used.
     pub weight: Option<i32>,
+    /// Whether the upright, italic or oblique glyphs of the font should be used.
+    pub style: FontStyle,
     /// If the pixel size is None, the system default font size should be used.
     pub pixel_size: Option<LogicalLength>,
     /// The additional spacing (or shrinking if negative) between glyphs. This is usually not submitted to
     /// the font-subsystem but collected here for API convenience
     pub letter_spacing: Option<LogicalLength>,
+    /// The distance between the top of two consecutive lines of text. If the line height is None, the
+    /// height of the font is used. Like the letter spacing, this is not submitted to the font-subsystem.
+    pub line_height: Option<LogicalLength>,
+    /// The OpenType features to enable or disable when shaping the text, in addition to the defaults
+    /// of the shaper.
+    pub features: Vec<FontFeature>,
+    /// The values of the variation axes of a variable font. The weight is also applied to the
+    /// `wght` axis, unless it is set here.
+    pub variations: Vec<FontVariation>,
+}
+
+#[test]
+fn test_parse_font_features() {
+    let parse = |list| FontFeature::parse_list(list).collect::<Vec<_>>();
+    assert_eq!(
+        parse("tnum, -liga,+kern , salt=2"),
+        [
+            FontFeature { tag: *b"tnum", value: 1 },
+            FontFeature { tag: *b"liga", value: 0 },
+            FontFeature { tag: *b"kern", value: 1 },
+            FontFeature { tag: *b"salt", value: 2 },
+        ]
+    );
+    assert_eq!(parse(""), []);
+    assert_eq!(parse("ligatures, -tnum, ss01=x"), [FontFeature { tag: *b"tnum", value: 0 }]);
+}
+
+#[test]
+fn test_parse_font_variations() {
+    let parse = |list| FontVariation::parse_list(list).collect::<Vec<_>>();
+    assert_eq!(
+        parse("wght=350, wdth = 87.5"),
+        [
+            FontVariation { tag: *b"wght", value: 350. },
+            FontVariation { tag: *b"wdth", value: 87.5 },
+        ]
+    );
+    assert_eq!(parse(""), []);
+    assert_eq!(
+        parse("wght, weight=300, opsz=x, slnt=-10"),
+        [FontVariation { tag: *b"slnt", value: -10. }]
+    );
+}
+
+#[test]
+fn test_cached_graphics_data_invalidation() {
+    let property = Box::pin(crate::Property::new(1));
+    let cached = CachedGraphicsData::new(|| property.as_ref().get() * 2);
+    assert_eq!(cached.data, 2);
+    let tracker = cached.dependency_tracker.as_ref().unwrap();
+    assert!(!tracker.is_dirty());
+    property.as_ref().set(3);
+    assert!(tracker.is_dirty());
+    assert_eq!(tracker.as_ref().evaluate_if_dirty(|| property.as_ref().get() * 2), Some(6));
+    assert!(!tracker.is_dirty());
+    assert_eq!(tracker.as_ref().evaluate_if_dirty(|| unreachable!()), None::<i32>);
 }
 
 #[cfg(feature = "ffi")]
@@ -138,15 +138,33 @@ impl LinearGradientBrush {
     /// Creates a new linear gradient, described by the specified angle and the provided color stops.
     ///
     /// The angle need to be specified in degrees.
-    /// The stops don't need to be sorted as this function will sort them.
+    /// The stops don't need to be sorted as this function will sort them. Their positions
+    /// are clamped to the `0..1` range.
     pub fn new(angle: f32, stops: impl IntoIterator<Item = GradientStop>) -> Self {
         let stop_iter = stops.into_iter();
         let mut encoded_angle_and_stops = SharedVector::with_capacity(stop_iter.size_hint().0 + 1);
         // The gradient's first stop is a fake stop to store the angle
         encoded_angle_and_stops.push(GradientStop { color: Default::default(), position: angle });
-        encoded_angle_and_stops.extend(stop_iter);
+        encoded_angle_and_stops
+            .extend(stop_iter.map(|s| GradientStop { position: s.position.clamp(0., 1.), ..s }));
+        // The sort is stable, so stops at the same position keep their order, for hard transitions
+        encoded_angle_and_stops.make_mut_slice()[1..].sort_by(|a, b| {
+            a.position.partial_cmp(&b.position).unwrap_or(core::cmp::Ordering::Equal)
+        });
         Self(encoded_angle_and_stops)
     }
+
+    /// Creates a new linear gradient with the specified angle in degrees, that goes from
+    /// `start_color` to `end_color`.
+    pub fn new_two_stop(angle: f32, start_color: Color, end_color: Color) -> Self {
+        Self::new(
+            angle,
+            [
+                GradientStop { color: start_color, position: 0. },
+                GradientStop { color: end_color, position: 1. },
+            ],
+        )
+    }
     /// Returns the angle of the linear gradient in degrees.
     pub fn angle(&self) -> f32 {
         self.0[0].position
@@ -292,3 +310,28 @@ fn test_linear_gradient_encoding() {
     assert_eq!(grad.angle(), 256.);
     assert!(grad.stops().eq(stops.iter()));
 }
+
+#[test]
+#[allow(clippy::float_cmp)]
+fn test_linear_gradient_sorted_and_clamped() {
+    let red = Color::from_rgb_u8(255, 0, 0);
+    let green = Color::from_rgb_u8(0, 255, 0);
+    let blue = Color::from_rgb_u8(0, 0, 255);
+    let grad = LinearGradientBrush::new(
+        90.,
+        [
+            GradientStop { position: 1.5, color: blue },
+            GradientStop { position: 0.5, color: green },
+            GradientStop { position: -0.5, color: red },
+            GradientStop { position: 0.5, color: blue },
+        ],
+    );
+    assert_eq!(grad.angle(), 90.);
+    let stops: Vec<_> = grad.stops().map(|s| (s.position, s.color)).collect();
+    assert_eq!(stops, vec![(0., red), (0.5, green), (0.5, blue), (1., blue)]);
+
+    let grad = LinearGradientBrush::new_two_stop(45., red, blue);
+    assert_eq!(grad.angle(), 45.);
+    let stops: Vec<_> = grad.stops().map(|s| (s.position, s.color)).collect();
+    assert_eq!(stops, vec![(0., red), (1., blue)]);
+}
@@ -6,6 +6,7 @@
 */
 
 use crate::properties::InterpolatedPropertyValue;
+use crate::SharedString;
 
 #[cfg(not(feature = "std"))]
 use num_traits::float::Float;
@@ -93,6 +94,21 @@ fn from(col: RgbaColor<f32>) -> Self {
     }
 }
 
+impl From<(f32, f32, f32, f32)> for Color {
+    /// Converts a `(red, green, blue, alpha)` tuple, see [`Color::from_rgba_f32`].
+    fn from((red, green, blue, alpha): (f32, f32, f32, f32)) -> Self {
+        Self::from_rgba_f32(red, green, blue, alpha)
+    }
+}
+
+impl From<Color> for (f32, f32, f32, f32) {
+    /// Converts to a `(red, green, blue, alpha)` tuple with each channel in the range 0..1.
+    fn from(col: Color) -> Self {
+        let RgbaColor { red, green, blue, alpha } = col.to_argb_f32();
+        (red, green, blue, alpha)
+    }
+}
+
 impl Color {
     /// Construct a color from an integer encoded as `0xAARRGGBB`
     pub const fn from_argb_encoded(encoded: u32) -> Color {
@@ -104,6 +120,97 @@ pub const fn from_argb_encoded(encoded: u32) -> Color {
         }
     }
 
+    /// Parses a color from a CSS hex notation string: `#RGB`, `#RGBA`, `#RRGGBB` or
+    /// `#RRGGBBAA`. The leading `#` is optional. In the short forms, each digit is
+    /// expanded (so `#f80` is the same as `#ff8800`), and the alpha channel defaults
+    /// to 255 when omitted.
+    ///
+    /// Returns None if the string has an invalid length or contains non-hexadecimal
+    /// characters.
+    ///
+    /// ```
+    /// # use i_slint_core::graphics::Color;
+    /// assert_eq!(Color::from_hex("#f80"), Some(Color::from_rgb_u8(0xff, 0x88, 0x00)));
+    /// assert_eq!(Color::from_hex("11223344"), Some(Color::from_argb_u8(0x44, 0x11, 0x22, 0x33)));
+    /// assert_eq!(Color::from_hex("#12345"), None);
+    /// ```
+    pub fn from_hex(hex: &str) -> Option<Self> {
+        let hex = hex.strip_prefix('#').unwrap_or(hex);
+        let digits = hex.chars().map(|c| c.to_digit(16).map(|d| d as u8));
+        let mut nibbles = [0u8; 8];
+        let mut len = 0;
+        for d in digits {
+            *nibbles.get_mut(len)? = d?;
+            len += 1;
+        }
+        let [r, g, b, a] = match len {
+            3 | 4 => {
+                let mut c = [0xffu8; 4];
+                for (c, n) in c.iter_mut().zip(&nibbles[..len]) {
+                    *c = n * 0x11;
+                }
+                c
+            }
+            6 | 8 => {
+                let mut c = [0xffu8; 4];
+                for (c, n) in c.iter_mut().zip(nibbles[..len].chunks(2)) {
+                    *c = (n[0] << 4) | n[1];
+                }
+                c
+            }
+            _ => return None,
+        };
+        Some(Self::from_argb_u8(a, r, g, b))
+    }
+
+    /// Returns the color for one of the named colors of the CSS specification, such
+    /// as `"cornflowerblue"` or `"transparent"`. The name is matched case-insensitively
+    /// and surrounding whitespace is ignored.
+    ///
+    /// Returns None if the name is not a known color name.
+    ///
+    /// ```
+    /// # use i_slint_core::graphics::Color;
+    /// assert_eq!(Color::from_name(" RebeccaPurple"), Some(Color::from_rgb_u8(0x66, 0x33, 0x99)));
+    /// assert_eq!(Color::from_name("unknown").or_else(|| Color::from_hex("#abc")), Color::from_hex("#abc"));
+    /// ```
+    pub fn from_name(name: &str) -> Option<Self> {
+        let name = name.trim();
+        NAMED_COLORS
+            .binary_search_by(|(entry, _)| {
+                entry.bytes().cmp(name.bytes().map(|b| b.to_ascii_lowercase()))
+            })
+            .ok()
+            .map(|index| Self::from_argb_encoded(NAMED_COLORS[index].1))
+    }
+
+    /// Returns the color in the CSS hex notation `#rrggbbaa`, using lowercase digits.
+    /// This is the inverse of [`Self::from_hex`].
+    ///
+    /// ```
+    /// # use i_slint_core::graphics::Color;
+    /// assert_eq!(Color::from_argb_u8(0x80, 0xff, 0x88, 0x00).to_hex_string(), "#ff880080");
+    /// ```
+    pub fn to_hex_string(&self) -> SharedString {
+        crate::format!("#{:02x}{:02x}{:02x}{:02x}", self.red, self.green, self.blue, self.alpha)
+    }
+
+    /// Returns the color in the CSS hex notation `#rrggbb` if the color is opaque, or
+    /// `#rrggbbaa` otherwise, using lowercase digits.
+    ///
+    /// ```
+    /// # use i_slint_core::graphics::Color;
+    /// assert_eq!(Color::from_rgb_u8(0xff, 0x88, 0x00).to_rgb_hex_string(), "#ff8800");
+    /// assert_eq!(Color::from_argb_u8(0x80, 0xff, 0x88, 0x00).to_rgb_hex_string(), "#ff880080");
+    /// ```
+    pub fn to_rgb_hex_string(&self) -> SharedString {
+        if self.alpha == 0xff {
+            crate::format!("#{:02x}{:02x}{:02x}", self.red, self.green, self.blue)
+        } else {
+            self.to_hex_string()
+        }
+    }
+
     /// Returns `(alpha, red, green, blue)` encoded as u32
     pub fn as_argb_encoded(&self) -> u32 {
         ((self.red as u32) << 16)
@@ -134,6 +241,29 @@ pub fn from_rgb_f32(red: f32, green: f32, blue: f32) -> Self {
         Self::from_argb_f32(1.0, red, green, blue)
     }
 
+    /// Construct a color from the red, green, blue and alpha color channel parameters,
+    /// in the range 0..1. Values outside of that range are clamped, and each channel
+    /// is rounded to the nearest 8-bit value.
+    ///
+    /// This is the inverse of converting a color to a `(f32, f32, f32, f32)` tuple,
+    /// which is also available as `From<(f32, f32, f32, f32)>`.
+    ///
+    /// ```
+    /// # use i_slint_core::graphics::Color;
+    /// assert_eq!(Color::from_rgba_f32(1.0, 0.5, -1.0, 2.0), Color::from_rgb_u8(255, 128, 0));
+    /// let col = Color::from_argb_u8(0x80, 0xff, 0x88, 0x00);
+    /// let (red, green, blue, alpha) = col.into();
+    /// assert_eq!(Color::from_rgba_f32(red, green, blue, alpha), col);
+    /// ```
+    pub fn from_rgba_f32(red: f32, green: f32, blue: f32, alpha: f32) -> Self {
+        Self::from_argb_f32(
+            alpha.clamp(0., 1.),
+            red.clamp(0., 1.),
+            green.clamp(0., 1.),
+            blue.clamp(0., 1.),
+        )
+    }
+
     /// Converts this color to an RgbaColor struct for easy destructuring.
     pub fn to_argb_u8(&self) -> RgbaColor<u8> {
         RgbaColor::from(*self)
@@ -144,6 +274,58 @@ pub fn to_argb_f32(&self) -> RgbaColor<f32> {
         RgbaColor::from(*self)
     }
 
+    /// Construct a color from the hue, saturation, value and alpha parameters of the
+    /// HSV color model. The hue is in degrees and wraps around at 360, the other
+    /// parameters are clamped to the range 0..1.
+    pub fn from_hsva(hue: f32, saturation: f32, value: f32, alpha: f32) -> Self {
+        HsvaColor {
+            h: normalize_hue(hue),
+            s: saturation.clamp(0., 1.),
+            v: value.clamp(0., 1.),
+            alpha: alpha.clamp(0., 1.),
+        }
+        .into()
+    }
+
+    /// Construct an opaque color from the hue, saturation and value parameters of the HSV
+    /// color model, like [`Self::from_hsva`] with an alpha of 1.
+    pub fn from_hsv(hue: f32, saturation: f32, value: f32) -> Self {
+        Self::from_hsva(hue, saturation, value, 1.)
+    }
+
+    /// Converts this color to the HSV color model and returns `(hue, saturation, value, alpha)`.
+    /// The hue is in degrees in the range 0..360, the other components are in the range 0..1.
+    pub fn to_hsva(&self) -> (f32, f32, f32, f32) {
+        let HsvaColor { h, s, v, alpha } = (*self).into();
+        (h, s, v, alpha)
+    }
+
+    /// Construct a color from the hue, saturation, lightness and alpha parameters of the
+    /// HSL color model. The hue is in degrees and wraps around at 360, the other
+    /// parameters are clamped to the range 0..1.
+    pub fn from_hsla(hue: f32, saturation: f32, lightness: f32, alpha: f32) -> Self {
+        HslaColor {
+            h: normalize_hue(hue),
+            s: saturation.clamp(0., 1.),
+            l: lightness.clamp(0., 1.),
+            alpha: alpha.clamp(0., 1.),
+        }
+        .into()
+    }
+
+    /// Construct an opaque color from the hue, saturation and lightness parameters of the HSL
+    /// color model, like [`Self::from_hsla`] with an alpha of 1.
+    pub fn from_hsl(hue: f32, saturation: f32, lightness: f32) -> Self {
+        Self::from_hsla(hue, saturation, lightness, 1.)
+    }
+
+    /// Converts this color to the HSL color model and returns `(hue, saturation, lightness, alpha)`.
+    /// The hue is in degrees in the range 0..360, the other components are in the range 0..1.
+    pub fn to_hsla(&self) -> (f32, f32, f32, f32) {
+        let HslaColor { h, s, l, alpha } = HsvaColor::from(*self).into();
+        (h, s, l, alpha)
+    }
+
     /// Returns the red channel of the color as u8 in the range 0..255.
     pub fn red(self) -> u8 {
         self.red
@@ -192,6 +374,224 @@ pub fn darker(&self, factor: f32) -> Self {
         let rgba: RgbaColor<f32> = hsva.into();
         rgba.into()
     }
+
+    /// Returns a new version of this color that has the brightness moved towards the
+    /// maximum by the specified factor, which is clamped to the range 0..1. This is done
+    /// by converting the color to the HSV color space and adding the factor times the
+    /// difference between the brightness (value) and 1. Hue, saturation and the alpha
+    /// channel are unchanged.
+    /// In contrast to [`Self::brighter`], this also lightens black: `lighter(0.5)` turns
+    /// black into a mid-gray, and `lighter(1.)` always returns a color with full brightness.
+    #[must_use]
+    pub fn lighter(&self, factor: f32) -> Self {
+        let mut hsva: HsvaColor = (*self).into();
+        hsva.v += (1. - hsva.v) * factor.clamp(0., 1.);
+        hsva.into()
+    }
+
+    /// Returns a new version of this color with the lightness increased by the
+    /// specified amount. This is done by converting the color to the HSL color space
+    /// and adding the factor to the lightness, which is clamped to the range 0..1.
+    /// Hue, saturation and the alpha channel are unchanged.
+    /// So for example `lighten(0.1)` turns a mid-gray with 50% lightness into a gray
+    /// with 60% lightness, and `lighten(1.)` always returns white.
+    #[must_use]
+    pub fn lighten(&self, factor: f32) -> Self {
+        let mut hsla: HslaColor = HsvaColor::from(*self).into();
+        hsla.l = (hsla.l + factor).clamp(0., 1.);
+        hsla.into()
+    }
+
+    /// Returns a new version of this color with the lightness decreased by the
+    /// specified amount. This is done by converting the color to the HSL color space
+    /// and subtracting the factor from the lightness, which is clamped to the range 0..1.
+    /// Hue, saturation and the alpha channel are unchanged.
+    /// So for example `darken(0.1)` turns a mid-gray with 50% lightness into a gray
+    /// with 40% lightness, and `darken(1.)` always returns black.
+    #[must_use]
+    pub fn darken(&self, factor: f32) -> Self {
+        let mut hsla: HslaColor = HsvaColor::from(*self).into();
+        hsla.l = (hsla.l - factor).clamp(0., 1.);
+        hsla.into()
+    }
+
+    /// Returns a new version of this color with the red, green and blue channels inverted,
+    /// that is each channel subtracted from 255. The alpha channel is unchanged.
+    ///
+    /// ```
+    /// # use i_slint_core::graphics::Color;
+    /// assert_eq!(Color::from_argb_u8(128, 255, 136, 0).invert(), Color::from_argb_u8(128, 0, 119, 255));
+    /// ```
+    #[must_use]
+    pub fn invert(&self) -> Self {
+        Self {
+            red: 255 - self.red,
+            green: 255 - self.green,
+            blue: 255 - self.blue,
+            alpha: self.alpha,
+        }
+    }
+
+    /// Returns a new version of this color with the alpha channel replaced by `alpha`.
+    /// The red, green and blue channels are unchanged.
+    ///
+    /// ```
+    /// # use i_slint_core::graphics::Color;
+    /// assert_eq!(Color::from_rgb_u8(255, 136, 0).with_alpha(64), Color::from_argb_u8(64, 255, 136, 0));
+    /// ```
+    #[must_use]
+    pub fn with_alpha(&self, alpha: u8) -> Self {
+        Self { alpha, ..*self }
+    }
+
+    /// Returns a new version of this color with the alpha channel multiplied by `factor`.
+    /// The result is rounded and clamped to the range 0..255, so for example
+    /// `scale_alpha(0.5)` turns an opaque color into a half transparent one.
+    /// The red, green and blue channels are unchanged.
+    #[must_use]
+    pub fn scale_alpha(&self, factor: f32) -> Self {
+        let alpha = (self.alpha as f32 * factor).round().clamp(0., 255.) as u8;
+        Self { alpha, ..*self }
+    }
+
+    /// Returns a new version of this color with the red, green and blue channels multiplied
+    /// by the alpha channel, as used when compositing. The alpha channel is unchanged.
+    #[must_use]
+    pub fn premultiplied(&self) -> Self {
+        let multiply = |channel: u8| ((channel as u16 * self.alpha as u16 + 127) / 255) as u8;
+        Self {
+            red: multiply(self.red),
+            green: multiply(self.green),
+            blue: multiply(self.blue),
+            alpha: self.alpha,
+        }
+    }
+
+    /// Returns a new version of this color, assumed to have premultiplied red, green and blue
+    /// channels, with the alpha multiplication reverted. This is the inverse of
+    /// [`Self::premultiplied`], except for the precision lost by premultiplying.
+    /// A fully transparent color results in transparent black.
+    #[must_use]
+    pub fn unpremultiplied(&self) -> Self {
+        if self.alpha == 0 {
+            return Self::default();
+        }
+        let divide = |channel: u8| {
+            ((channel as u16 * 255 + self.alpha as u16 / 2) / self.alpha as u16).min(255) as u8
+        };
+        Self {
+            red: divide(self.red),
+            green: divide(self.green),
+            blue: divide(self.blue),
+            alpha: self.alpha,
+        }
+    }
+
+    /// Returns a gray version of this color, with the red, green and blue channels set to
+    /// the weighted sum `0.2126 * red + 0.7152 * green + 0.0722 * blue`, which approximates
+    /// the perceived brightness. The alpha channel is unchanged.
+    #[must_use]
+    pub fn to_grayscale(&self) -> Self {
+        let gray =
+            (0.2126 * self.red as f32 + 0.7152 * self.green as f32 + 0.0722 * self.blue as f32)
+                .round() as u8;
+        Self { red: gray, green: gray, blue: gray, alpha: self.alpha }
+    }
+
+    /// Returns a version of this color with the saturation reduced by the given amount,
+    /// by blending it with [`Self::to_grayscale`]. The amount is clamped to the range 0..1,
+    /// where 0 returns this color and 1 the gray version.
+    #[must_use]
+    pub fn desaturate(&self, amount: f32) -> Self {
+        self.mix(&self.to_grayscale(), amount)
+    }
+
+    /// Returns the relative luminance of the color as defined by the
+    /// [WCAG](https://www.w3.org/TR/WCAG21/#dfn-relative-luminance), where 0 is the
+    /// darkest black and 1 the lightest white. The red, green and blue channels are
+    /// linearized with the sRGB transfer function before being weighted.
+    /// The alpha channel is ignored.
+    pub fn relative_luminance(&self) -> f32 {
+        let linearize = |channel: u8| {
+            let c = channel as f32 / 255.;
+            if c <= 0.04045 {
+                c / 12.92
+            } else {
+                ((c + 0.055) / 1.055).powf(2.4)
+            }
+        };
+        0.2126 * linearize(self.red)
+            + 0.7152 * linearize(self.green)
+            + 0.0722 * linearize(self.blue)
+    }
+
+    /// Returns the contrast ratio between this color and `other` as defined by the
+    /// [WCAG](https://www.w3.org/TR/WCAG21/#dfn-contrast-ratio). The result ranges from
+    /// 1 (no contrast) to 21 (black on white), and doesn't depend on the order of the colors.
+    /// The WCAG recommends a ratio of at least 4.5 for normal text.
+    ///
+    /// ```
+    /// # use i_slint_core::graphics::Color;
+    /// let background = Color::from_rgb_u8(0x33, 0x66, 0x99);
+    /// let black = Color::from_rgb_u8(0, 0, 0);
+    /// let white = Color::from_rgb_u8(255, 255, 255);
+    /// let text_color = if background.contrast_ratio(&white) >= background.contrast_ratio(&black) {
+    ///     white
+    /// } else {
+    ///     black
+    /// };
+    /// assert_eq!(text_color, white);
+    /// ```
+    pub fn contrast_ratio(&self, other: &Self) -> f32 {
+        let l1 = self.relative_luminance();
+        let l2 = other.relative_luminance();
+        (l1.max(l2) + 0.05) / (l1.min(l2) + 0.05)
+    }
+
+    /// Returns a new color that is a blend of this color and `other`. The factor is
+    /// clamped to the range 0..1, where 0 returns this color and 1 returns `other`.
+    ///
+    /// All four channels are interpolated independently, like it is done when animating
+    /// a color property. When either of the colors is translucent, use
+    /// [`Self::mix_premultiplied`] instead to get the result one would expect when
+    /// compositing.
+    #[must_use]
+    pub fn mix(&self, other: &Self, factor: f32) -> Self {
+        let factor = factor.clamp(0., 1.);
+        let lerp = |a: u8, b: u8| (a as f32 + (b as f32 - a as f32) * factor).round() as u8;
+        Self {
+            red: lerp(self.red, other.red),
+            green: lerp(self.green, other.green),
+            blue: lerp(self.blue, other.blue),
+            alpha: lerp(self.alpha, other.alpha),
+        }
+    }
+
+    /// Returns a new color that is a blend of this color and `other`. The factor is
+    /// clamped to the range 0..1, where 0 returns this color and 1 returns `other`.
+    ///
+    /// In contrast to [`Self::mix`], the red, green and blue channels are multiplied
+    /// with the alpha channel before the interpolation. This way a fully transparent
+    /// color doesn't contribute any of its (invisible) color to the result.
+    #[must_use]
+    pub fn mix_premultiplied(&self, other: &Self, factor: f32) -> Self {
+        let factor = factor.clamp(0., 1.);
+        let lhs = self.to_argb_f32();
+        let rhs = other.to_argb_f32();
+        let lerp = |a: f32, b: f32| a + (b - a) * factor;
+        let alpha = lerp(lhs.alpha, rhs.alpha);
+        if alpha <= 0. {
+            return Self::default();
+        }
+        let channel = |a: f32, b: f32| lerp(a * lhs.alpha, b * rhs.alpha) / alpha;
+        RgbaColor {
+            red: channel(lhs.red, rhs.red),
+            green: channel(lhs.green, rhs.green),
+            blue: channel(lhs.blue, rhs.blue),
+            alpha,
+        }
+        .into()
+    }
 }
 
 impl InterpolatedPropertyValue for Color {
@@ -211,6 +611,159 @@ fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
     }
 }
 
+/// The CSS named colors, sorted by name and encoded as `0xAARRGGBB`
+const NAMED_COLORS: &[(&str, u32)] = &[
+    ("aliceblue", 0xfff0f8ff),
+    ("antiquewhite", 0xfffaebd7),
+    ("aqua", 0xff00ffff),
+    ("aquamarine", 0xff7fffd4),
+    ("azure", 0xfff0ffff),
+    ("beige", 0xfff5f5dc),
+    ("bisque", 0xffffe4c4),
+    ("black", 0xff000000),
+    ("blanchedalmond", 0xffffebcd),
+    ("blue", 0xff0000ff),
+    ("blueviolet", 0xff8a2be2),
+    ("brown", 0xffa52a2a),
+    ("burlywood", 0xffdeb887),
+    ("cadetblue", 0xff5f9ea0),
+    ("chartreuse", 0xff7fff00),
+    ("chocolate", 0xffd2691e),
+    ("coral", 0xffff7f50),
+    ("cornflowerblue", 0xff6495ed),
+    ("cornsilk", 0xfffff8dc),
+    ("crimson", 0xffdc143c),
+    ("cyan", 0xff00ffff),
+    ("darkblue", 0xff00008b),
+    ("darkcyan", 0xff008b8b),
+    ("darkgoldenrod", 0xffb8860b),
+    ("darkgray", 0xffa9a9a9),
+    ("darkgreen", 0xff006400),
+    ("darkgrey", 0xffa9a9a9),
+    ("darkkhaki", 0xffbdb76b),
+    ("darkmagenta", 0xff8b008b),
+    ("darkolivegreen", 0xff556b2f),
+    ("darkorange", 0xffff8c00),
+    ("darkorchid", 0xff9932cc),
+    ("darkred", 0xff8b0000),
+    ("darksalmon", 0xffe9967a),
+    ("darkseagreen", 0xff8fbc8f),
+    ("darkslateblue", 0xff483d8b),
+    ("darkslategray", 0xff2f4f4f),
+    ("darkslategrey", 0xff2f4f4f),
+    ("darkturquoise", 0xff00ced1),
+    ("darkviolet", 0xff9400d3),
+    ("deeppink", 0xffff1493),
+    ("deepskyblue", 0xff00bfff),
+    ("dimgray", 0xff696969),
+    ("dimgrey", 0xff696969),
+    ("dodgerblue", 0xff1e90ff),
+    ("firebrick", 0xffb22222),
+    ("floralwhite", 0xfffffaf0),
+    ("forestgreen", 0xff228b22),
+    ("fuchsia", 0xffff00ff),
+    ("gainsboro", 0xffdcdcdc),
+    ("ghostwhite", 0xfff8f8ff),
+    ("gold", 0xffffd700),
+    ("goldenrod", 0xffdaa520),
+    ("gray", 0xff808080),
+    ("green", 0xff008000),
+    ("greenyellow", 0xffadff2f),
+    ("grey", 0xff808080),
+    ("honeydew", 0xfff0fff0),
+    ("hotpink", 0xffff69b4),
+    ("indianred", 0xffcd5c5c),
+    ("indigo", 0xff4b0082),
+    ("ivory", 0xfffffff0),
+    ("khaki", 0xfff0e68c),
+    ("lavender", 0xffe6e6fa),
+    ("lavenderblush", 0xfffff0f5),
+    ("lawngreen", 0xff7cfc00),
+    ("lemonchiffon", 0xfffffacd),
+    ("lightblue", 0xffadd8e6),
+    ("lightcoral", 0xfff08080),
+    ("lightcyan", 0xffe0ffff),
+    ("lightgoldenrodyellow", 0xfffafad2),
+    ("lightgray", 0xffd3d3d3),
+    ("lightgreen", 0xff90ee90),
+    ("lightgrey", 0xffd3d3d3),
+    ("lightpink", 0xffffb6c1),
+    ("lightsalmon", 0xffffa07a),
+    ("lightseagreen", 0xff20b2aa),
+    ("lightskyblue", 0xff87cefa),
+    ("lightslategray", 0xff778899),
+    ("lightslategrey", 0xff778899),
+    ("lightsteelblue", 0xffb0c4de),
+    ("lightyellow", 0xffffffe0),
+    ("lime", 0xff00ff00),
+    ("limegreen", 0xff32cd32),
+    ("linen", 0xfffaf0e6),
+    ("magenta", 0xffff00ff),
+    ("maroon", 0xff800000),
+    ("mediumaquamarine", 0xff66cdaa),
+    ("mediumblue", 0xff0000cd),
+    ("mediumorchid", 0xffba55d3),
+    ("mediumpurple", 0xff9370db),
+    ("mediumseagreen", 0xff3cb371),
+    ("mediumslateblue", 0xff7b68ee),
+    ("mediumspringgreen", 0xff00fa9a),
+    ("mediumturquoise", 0xff48d1cc),
+    ("mediumvioletred", 0xffc71585),
+    ("midnightblue", 0xff191970),
+    ("mintcream", 0xfff5fffa),
+    ("mistyrose", 0xffffe4e1),
+    ("moccasin", 0xffffe4b5),
+    ("navajowhite", 0xffffdead),
+    ("navy", 0xff000080),
+    ("oldlace", 0xfffdf5e6),
+    ("olive", 0xff808000),
+    ("olivedrab", 0xff6b8e23),
+    ("orange", 0xffffa500),
+    ("orangered", 0xffff4500),
+    ("orchid", 0xffda70d6),
+    ("palegoldenrod", 0xffeee8aa),
+    ("palegreen", 0xff98fb98),
+    ("paleturquoise", 0xffafeeee),
+    ("palevioletred", 0xffdb7093),
+    ("papayawhip", 0xffffefd5),
+    ("peachpuff", 0xffffdab9),
+    ("peru", 0xffcd853f),
+    ("pink", 0xffffc0cb),
+    ("plum", 0xffdda0dd),
+    ("powderblue", 0xffb0e0e6),
+    ("purple", 0xff800080),
+    ("rebeccapurple", 0xff663399),
+    ("red", 0xffff0000),
+    ("rosybrown", 0xffbc8f8f),
+    ("royalblue", 0xff4169e1),
+    ("saddlebrown", 0xff8b4513),
+    ("salmon", 0xfffa8072),
+    ("sandybrown", 0xfff4a460),
+    ("seagreen", 0xff2e8b57),
+    ("seashell", 0xfffff5ee),
+    ("sienna", 0xffa0522d),
+    ("silver", 0xffc0c0c0),
+    ("skyblue", 0xff87ceeb),
+    ("slateblue", 0xff6a5acd),
+    ("slategray", 0xff708090),
+    ("slategrey", 0xff708090),
+    ("snow", 0xfffffafa),
+    ("springgreen", 0xff00ff7f),
+    ("steelblue", 0xff4682b4),
+    ("tan", 0xffd2b48c),
+    ("teal", 0xff008080),
+    ("thistle", 0xffd8bfd8),
+    ("tomato", 0xffff6347),
+    ("transparent", 0x00000000),
+    ("turquoise", 0xff40e0d0),
+    ("violet", 0xffee82ee),
+    ("wheat", 0xfff5deb3),
+    ("white", 0xffffffff),
+    ("whitesmoke", 0xfff5f5f5),
+    ("yellow", 0xffffff00),
+    ("yellowgreen", 0xff9acd32),
+];
+
 #[derive(Debug, Clone, Copy, PartialEq)]
 struct HsvaColor {
     h: f32,
@@ -245,7 +798,19 @@ fn from(col: RgbaColor<f32>) -> Self {
 
         let saturation = if max == 0. { 0. } else { chroma / max };
 
-        Self { h: hue, s: saturation, v: max, alpha: col.alpha }
+        Self { h: normalize_hue(hue), s: saturation, v: max, alpha: col.alpha }
+    }
+}
+
+impl From<Color> for HsvaColor {
+    fn from(col: Color) -> Self {
+        RgbaColor::<f32>::from(col).into()
+    }
+}
+
+impl From<HsvaColor> for Color {
+    fn from(col: HsvaColor) -> Self {
+        RgbaColor::<f32>::from(col).into()
     }
 }
 
@@ -273,6 +838,50 @@ fn from(col: HsvaColor) -> Self {
     }
 }
 
+/// Returns the hue in degrees wrapped into the range 0..360
+fn normalize_hue(hue: f32) -> f32 {
+    let hue = hue % 360.;
+    let hue = if hue < 0. { hue + 360. } else { hue };
+    // Adding 360 to a tiny negative value may round up to exactly 360
+    if hue >= 360. {
+        0.
+    } else {
+        hue
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct HslaColor {
+    h: f32,
+    s: f32,
+    l: f32,
+    alpha: f32,
+}
+
+impl From<HsvaColor> for HslaColor {
+    fn from(col: HsvaColor) -> Self {
+        // HSV to HSL conversion from https://en.wikipedia.org/wiki/HSL_and_HSV#HSV_to_HSL
+        let l = col.v * (1. - col.s / 2.);
+        let s = if l <= 0. || l >= 1. { 0. } else { (col.v - l) / l.min(1. - l) };
+        Self { h: col.h, s, l, alpha: col.alpha }
+    }
+}
+
+impl From<HslaColor> for HsvaColor {
+    fn from(col: HslaColor) -> Self {
+        // HSL to HSV conversion from https://en.wikipedia.org/wiki/HSL_and_HSV#HSL_to_HSV
+        let v = col.l + col.s * col.l.min(1. - col.l);
+        let s = if v <= 0. { 0. } else { 2. * (1. - col.l / v) };
+        Self { h: col.h, s, v, alpha: col.alpha }
+    }
+}
+
+impl From<HslaColor> for Color {
+    fn from(col: HslaColor) -> Self {
+        HsvaColor::from(col).into()
+    }
+}
+
 #[test]
 fn test_rgb_to_hsv() {
     // White
@@ -296,11 +905,220 @@ fn test_rgb_to_hsv() {
     );
 }
 
+#[test]
+fn test_hsva_hsla() {
+    fn assert_close(lhs: (f32, f32, f32, f32), rhs: (f32, f32, f32, f32)) {
+        let close = |a: f32, b: f32, epsilon: f32| (a - b).abs() < epsilon;
+        assert!(
+            close(lhs.0, rhs.0, 0.5)
+                && close(lhs.1, rhs.1, 0.01)
+                && close(lhs.2, rhs.2, 0.01)
+                && close(lhs.3, rhs.3, 0.01),
+            "{lhs:?} != {rhs:?}"
+        );
+    }
+
+    assert_eq!(Color::from_hsva(0., 1., 1., 1.), Color::from_rgb_u8(255, 0, 0));
+    assert_eq!(Color::from_hsva(120., 1., 1., 1.), Color::from_rgb_u8(0, 255, 0));
+    assert_eq!(Color::from_hsva(240., 1., 1., 1.), Color::from_rgb_u8(0, 0, 255));
+    assert_eq!(Color::from_hsva(360., 1., 1., 1.), Color::from_rgb_u8(255, 0, 0));
+    assert_eq!(Color::from_hsva(-120., 1., 1., 1.), Color::from_rgb_u8(0, 0, 255));
+    assert_eq!(Color::from_hsv(0., 1., 1.), Color::from_rgb_u8(255, 0, 0));
+    assert_eq!(Color::from_hsv(480., 2., 1.), Color::from_rgb_u8(0, 255, 0));
+    assert_eq!(Color::from_hsv(240., 1., -1.), Color::from_rgb_u8(0, 0, 0));
+    assert_eq!(Color::from_hsla(0., 1., 0.5, 1.), Color::from_rgb_u8(255, 0, 0));
+    assert_eq!(Color::from_hsl(120., 1., 0.5), Color::from_rgb_u8(0, 255, 0));
+    assert_eq!(Color::from_hsla(0., 0., 1., 0.), Color::from_argb_u8(0, 255, 255, 255));
+    assert_eq!(Color::from_hsla(200., 0.5, 0., 1.), Color::from_rgb_u8(0, 0, 0));
+
+    // Magenta has a hue computed from a negative intermediate value
+    assert_close(Color::from_rgb_u8(255, 0, 128).to_hsva(), (329.9, 1., 1., 1.));
+    assert_close(Color::from_rgb_u8(128, 128, 128).to_hsla(), (0., 0., 0.502, 1.));
+
+    for col in [
+        Color::from_rgb_u8(0, 0, 0),
+        Color::from_rgb_u8(255, 255, 255),
+        Color::from_argb_u8(128, 12, 200, 99),
+        Color::from_rgb_u8(250, 128, 114),
+        Color::from_argb_u8(10, 70, 130, 180),
+        Color::from_rgb_u8(255, 0, 0),
+        Color::from_rgb_u8(0, 255, 0),
+        Color::from_rgb_u8(0, 0, 255),
+    ] {
+        let (h, s, v, a) = col.to_hsva();
+        assert_eq!(Color::from_hsva(h, s, v, a), col);
+        let (h, s, l, a) = col.to_hsla();
+        assert_eq!(Color::from_hsla(h, s, l, a), col);
+    }
+
+    let (h, s, l, a) = (210., 0.4, 0.7, 0.5);
+    assert_close(Color::from_hsla(h, s, l, a).to_hsla(), (h, s, l, a));
+    let (h, s, v, a) = (30., 0.8, 0.6, 1.);
+    assert_close(Color::from_hsva(h, s, v, a).to_hsva(), (h, s, v, a));
+}
+
 #[test]
 fn test_brighter_darker() {
     let blue = Color::from_rgb_u8(0, 0, 128);
     assert_eq!(blue.brighter(0.5), Color::from_rgb_u8(0, 0, 192));
     assert_eq!(blue.darker(0.5), Color::from_rgb_u8(0, 0, 85));
+
+    assert_eq!(blue.lighter(0.), blue);
+    assert_eq!(blue.lighter(0.5), Color::from_rgb_u8(0, 0, 192));
+    assert_eq!(blue.lighter(2.), Color::from_rgb_u8(0, 0, 255));
+    assert_eq!(blue.lighter(-1.), blue);
+    let black = Color::from_argb_u8(100, 0, 0, 0);
+    assert_eq!(black.lighter(0.5), Color::from_argb_u8(100, 128, 128, 128));
+    assert_eq!(black.brighter(0.5), black);
+}
+
+#[test]
+fn test_lighten_darken() {
+    let gray = Color::from_rgb_u8(128, 128, 128);
+    assert_eq!(gray.lighten(0.2), Color::from_rgb_u8(179, 179, 179));
+    assert_eq!(gray.darken(0.2), Color::from_rgb_u8(77, 77, 77));
+    assert_eq!(gray.lighten(2.), Color::from_rgb_u8(255, 255, 255));
+    assert_eq!(gray.darken(2.), Color::from_rgb_u8(0, 0, 0));
+    assert_eq!(gray.lighten(0.), gray);
+
+    let red = Color::from_argb_u8(100, 255, 0, 0);
+    assert_eq!(red.lighten(0.3), Color::from_argb_u8(100, 255, 153, 153));
+    assert_eq!(red.darken(0.3), Color::from_argb_u8(100, 102, 0, 0));
+}
+
+#[test]
+fn test_luminance_contrast() {
+    let black = Color::from_rgb_u8(0, 0, 0);
+    let white = Color::from_rgb_u8(255, 255, 255);
+    assert_eq!(black.relative_luminance(), 0.);
+    assert!((white.relative_luminance() - 1.).abs() < 0.0001);
+    // Mid gray is much darker than 0.5 once linearized
+    assert!((Color::from_rgb_u8(128, 128, 128).relative_luminance() - 0.2158).abs() < 0.001);
+    assert!((Color::from_rgb_u8(0, 255, 0).relative_luminance() - 0.7152).abs() < 0.0001);
+
+    assert!((black.contrast_ratio(&white) - 21.).abs() < 0.001);
+    assert!((white.contrast_ratio(&black) - 21.).abs() < 0.001);
+    assert_eq!(white.contrast_ratio(&white), 1.);
+    // #777 on white is just below the 4.5 threshold
+    let gray = Color::from_rgb_u8(0x77, 0x77, 0x77);
+    assert!((gray.contrast_ratio(&white) - 4.48).abs() < 0.01);
+}
+
+#[test]
+fn test_premultiplied() {
+    let col = Color::from_argb_u8(128, 255, 100, 1);
+    assert_eq!(col.premultiplied(), Color::from_argb_u8(128, 128, 50, 1));
+    assert_eq!(col.premultiplied().unpremultiplied(), Color::from_argb_u8(128, 255, 100, 2));
+    let opaque = Color::from_rgb_u8(12, 34, 56);
+    assert_eq!(opaque.premultiplied(), opaque);
+    assert_eq!(opaque.unpremultiplied(), opaque);
+    assert_eq!(Color::from_argb_u8(0, 255, 255, 255).premultiplied(), Color::default());
+    assert_eq!(Color::from_argb_u8(0, 255, 255, 255).unpremultiplied(), Color::default());
+    // Invalid premultiplied data where a channel exceeds alpha is clamped
+    assert_eq!(
+        Color::from_argb_u8(10, 255, 0, 0).unpremultiplied(),
+        Color::from_argb_u8(10, 255, 0, 0)
+    );
+}
+
+#[test]
+fn test_grayscale() {
+    assert_eq!(Color::from_rgb_u8(255, 0, 0).to_grayscale(), Color::from_rgb_u8(54, 54, 54));
+    assert_eq!(Color::from_rgb_u8(0, 255, 0).to_grayscale(), Color::from_rgb_u8(182, 182, 182));
+    assert_eq!(
+        Color::from_argb_u8(20, 0, 0, 255).to_grayscale(),
+        Color::from_argb_u8(20, 18, 18, 18)
+    );
+    let gray = Color::from_rgb_u8(99, 99, 99);
+    assert_eq!(gray.to_grayscale(), gray);
+
+    let col = Color::from_argb_u8(200, 200, 100, 0);
+    assert_eq!(col.desaturate(0.), col);
+    assert_eq!(col.desaturate(1.), col.to_grayscale());
+    assert_eq!(col.desaturate(0.5), Color::from_argb_u8(200, 157, 107, 57));
+}
+
+#[test]
+fn test_mix() {
+    let red = Color::from_rgb_u8(255, 0, 0);
+    let blue = Color::from_argb_u8(0, 0, 0, 255);
+    assert_eq!(red.mix(&blue, 0.), red);
+    assert_eq!(red.mix(&blue, 1.), blue);
+    assert_eq!(red.mix(&blue, -1.), red);
+    assert_eq!(red.mix(&blue, 2.), blue);
+    assert_eq!(red.mix(&blue, 0.5), Color::from_argb_u8(128, 128, 0, 128));
+
+    // The transparent blue doesn't tint the result
+    assert_eq!(red.mix_premultiplied(&blue, 0.5), Color::from_argb_u8(128, 255, 0, 0));
+    assert_eq!(red.mix_premultiplied(&blue, 1.), Color::default());
+    let translucent_blue = Color::from_argb_u8(128, 0, 0, 255);
+    assert_eq!(red.mix_premultiplied(&translucent_blue, 0.5), Color::from_argb_u8(192, 170, 0, 85));
+}
+
+#[test]
+fn test_invert_and_alpha() {
+    let col = Color::from_argb_u8(200, 10, 128, 255);
+    assert_eq!(col.invert(), Color::from_argb_u8(200, 245, 127, 0));
+    assert_eq!(col.invert().invert(), col);
+    assert_eq!(col.with_alpha(0), Color::from_argb_u8(0, 10, 128, 255));
+    assert_eq!(col.scale_alpha(0.5), Color::from_argb_u8(100, 10, 128, 255));
+    assert_eq!(col.scale_alpha(0.), col.with_alpha(0));
+    assert_eq!(col.scale_alpha(2.), col.with_alpha(255));
+    assert_eq!(col.scale_alpha(-1.), col.with_alpha(0));
+    assert_eq!(col.scale_alpha(f32::NAN), col.with_alpha(0));
+}
+
+#[test]
+fn test_rgba_f32_tuple() {
+    assert_eq!(Color::from_rgba_f32(0., 0., 0., 0.), Color::default());
+    assert_eq!(Color::from_rgba_f32(-0.5, 0.2, 1.5, 0.5), Color::from_argb_u8(128, 0, 51, 255));
+    assert_eq!(Color::from((1., 0., 0., 1.)), Color::from_rgb_u8(255, 0, 0));
+    assert_eq!(<(f32, f32, f32, f32)>::from(Color::from_rgb_u8(255, 0, 0)), (1., 0., 0., 1.));
+    for alpha in 0..=255 {
+        let col = Color::from_argb_u8(alpha, 255 - alpha, alpha / 3, 7);
+        let tuple: (f32, f32, f32, f32) = col.into();
+        assert_eq!(Color::from(tuple), col);
+    }
+}
+
+#[test]
+fn test_from_hex() {
+    assert_eq!(Color::from_hex("#abc"), Some(Color::from_rgb_u8(0xaa, 0xbb, 0xcc)));
+    assert_eq!(Color::from_hex("AbC"), Some(Color::from_rgb_u8(0xaa, 0xbb, 0xcc)));
+    assert_eq!(Color::from_hex("#abcd"), Some(Color::from_argb_u8(0xdd, 0xaa, 0xbb, 0xcc)));
+    assert_eq!(Color::from_hex("#012345"), Some(Color::from_rgb_u8(0x01, 0x23, 0x45)));
+    assert_eq!(Color::from_hex("#01234567"), Some(Color::from_argb_u8(0x67, 0x01, 0x23, 0x45)));
+    assert_eq!(Color::from_hex(""), None);
+    assert_eq!(Color::from_hex("#"), None);
+    assert_eq!(Color::from_hex("##abc"), None);
+    assert_eq!(Color::from_hex("#+bc"), None);
+    assert_eq!(Color::from_hex("#abcde"), None);
+    assert_eq!(Color::from_hex("#1234567890"), None);
+    assert_eq!(Color::from_hex("#→↓←"), None);
+}
+
+#[test]
+fn test_from_name() {
+    assert!(NAMED_COLORS.windows(2).all(|w| w[0].0 < w[1].0));
+    assert_eq!(Color::from_name("aliceblue"), Some(Color::from_rgb_u8(240, 248, 255)));
+    assert_eq!(Color::from_name("CornflowerBlue"), Some(Color::from_rgb_u8(100, 149, 237)));
+    assert_eq!(Color::from_name("\tyellowgreen\n"), Some(Color::from_rgb_u8(154, 205, 50)));
+    assert_eq!(Color::from_name("transparent"), Some(Color::default()));
+    assert_eq!(Color::from_name("cornflower blue"), None);
+    assert_eq!(Color::from_name(""), None);
+    assert_eq!(Color::from_name("#fff"), None);
+}
+
+#[test]
+fn test_to_hex_string() {
+    assert_eq!(Color::from_rgb_u8(0xab, 0x0c, 0xde).to_hex_string(), "#ab0cdeff");
+    assert_eq!(Color::from_rgb_u8(0xab, 0x0c, 0xde).to_rgb_hex_string(), "#ab0cde");
+    assert_eq!(Color::default().to_hex_string(), "#00000000");
+    assert_eq!(Color::default().to_rgb_hex_string(), "#00000000");
+    for col in [Color::from_argb_u8(0x12, 0x34, 0x56, 0x78), Color::from_rgb_u8(0xff, 0, 0x01)] {
+        assert_eq!(Color::from_hex(&col.to_hex_string()), Some(col));
+        assert_eq!(Color::from_hex(&col.to_rgb_hex_string()), Some(col));
+    }
 }
 
 #[cfg(feature = "ffi")]
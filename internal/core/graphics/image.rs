@@ -14,11 +14,18 @@
 
 #[cfg(feature = "image-decoders")]
 pub mod cache;
+#[cfg(feature = "image-decoders")]
+mod data_uri;
 #[cfg(target_arch = "wasm32")]
 mod htmlimage;
+#[cfg(feature = "image-decoders")]
+mod remote;
 #[cfg(feature = "svg")]
 mod svg;
 
+#[cfg(feature = "image-decoders")]
+pub use remote::{set_image_url_fetcher, ImageFetchCompletion, RemoteImageState};
+
 #[allow(missing_docs)]
 #[vtable::vtable]
 #[repr(C)]
@@ -43,6 +50,12 @@ pub struct OpaqueImageVTable {
     pub static HTML_IMAGE_VT for htmlimage::HTMLImage
 }
 
+#[cfg(feature = "image-decoders")]
+OpaqueImageVTable_static! {
+    /// VTable for RC wrapped remote image helper struct.
+    pub static REMOTE_IMAGE_VT for remote::RemoteImage
+}
+
 /// SharedPixelBuffer is a container for storing image data as pixels. It is
 /// internally reference counted and cheap to clone.
 ///
@@ -77,6 +90,27 @@ pub fn size(&self) -> IntSize {
     }
 }
 
+/// Two buffers are equal if they have the same size and contain the same pixels. Unless both
+/// buffers share the same data, this compares every pixel.
+impl<Pixel: PartialEq> PartialEq for SharedPixelBuffer<Pixel> {
+    fn eq(&self, other: &Self) -> bool {
+        self.width == other.width
+            && self.height == other.height
+            && (core::ptr::eq(self.data.as_ptr(), other.data.as_ptr()) || self.data == other.data)
+    }
+}
+
+impl<Pixel: Eq> Eq for SharedPixelBuffer<Pixel> {}
+
+/// Hashing a buffer hashes every pixel, so it's O(n) in the number of pixels.
+impl<Pixel: core::hash::Hash> core::hash::Hash for SharedPixelBuffer<Pixel> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.width.hash(state);
+        self.height.hash(state);
+        self.data.as_slice().hash(state);
+    }
+}
+
 impl<Pixel: Clone> SharedPixelBuffer<Pixel> {
     /// Return a mutable slice to the pixel data. If the SharedPixelBuffer was shared, this will make a copy of the buffer.
     pub fn make_mut_slice(&mut self) -> &mut [Pixel] {
@@ -150,7 +184,7 @@ pub fn clone_from_slice<SourcePixelType>(
 ///
 /// The SharedImageBuffer's variants represent the different common formats for encoding
 /// images in pixels.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 #[repr(C)]
 pub enum SharedImageBuffer {
     /// This variant holds the data for an image where each pixel has three color channels (red, green,
@@ -200,22 +234,6 @@ pub fn size(&self) -> IntSize {
     }
 }
 
-impl PartialEq for SharedImageBuffer {
-    fn eq(&self, other: &Self) -> bool {
-        match self {
-            Self::RGB8(lhs_buffer) => {
-                matches!(other, Self::RGB8(rhs_buffer) if lhs_buffer.data.as_ptr().eq(&rhs_buffer.data.as_ptr()))
-            }
-            Self::RGBA8(lhs_buffer) => {
-                matches!(other, Self::RGBA8(rhs_buffer) if lhs_buffer.data.as_ptr().eq(&rhs_buffer.data.as_ptr()))
-            }
-            Self::RGBA8Premultiplied(lhs_buffer) => {
-                matches!(other, Self::RGBA8Premultiplied(rhs_buffer) if lhs_buffer.data.as_ptr().eq(&rhs_buffer.data.as_ptr()))
-            }
-        }
-    }
-}
-
 #[repr(u8)]
 #[derive(Clone, PartialEq, Debug, Copy)]
 /// The pixel format of a StaticTexture
@@ -257,7 +275,7 @@ pub struct StaticTexture {
 }
 
 #[repr(C)]
-#[derive(Clone, PartialEq, Debug)]
+#[derive(Clone, Debug)]
 /// A texture is stored in read-only memory and may be composed of sub-textures.
 
 pub struct StaticTextures {
@@ -272,6 +290,27 @@ pub struct StaticTextures {
     pub textures: Slice<'static, StaticTexture>,
 }
 
+/// Static textures are compared by the content of their pixel data and textures, unless
+/// both refer to the same data.
+impl PartialEq for StaticTextures {
+    fn eq(&self, other: &Self) -> bool {
+        self.size == other.size
+            && self.original_size == other.original_size
+            && (core::ptr::eq(self.data.as_slice(), other.data.as_slice())
+                || self.data.as_slice() == other.data.as_slice())
+            && self.textures.as_slice() == other.textures.as_slice()
+    }
+}
+
+/// Hashing static textures hashes all of their pixel data, so it's O(n) in the size of the data.
+impl core::hash::Hash for StaticTextures {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.size.hash(state);
+        self.original_size.hash(state);
+        self.data.as_slice().hash(state);
+    }
+}
+
 /// ImageCacheKey encapsulates the different ways of indexing images in the
 /// cache of decoded images.
 #[derive(PartialEq, Eq, Debug, Hash, Clone)]
@@ -283,7 +322,6 @@ pub enum ImageCacheKey {
     /// The image is identified by its path on the file system.
     Path(SharedString) = 1,
     /// The image is identified by a URL.
-    #[cfg(target_arch = "wasm32")]
     URL(SharedString) = 2,
     /// The image is identified by the static address of its encoded data.
     EmbeddedData(usize) = 3,
@@ -303,6 +341,8 @@ pub fn new(resource: &ImageInner) -> Option<Self> {
             ImageInner::Svg(parsed_svg) => parsed_svg.cache_key(),
             #[cfg(target_arch = "wasm32")]
             ImageInner::HTMLImage(htmlimage) => Self::URL(htmlimage.source().into()),
+            #[cfg(feature = "image-decoders")]
+            ImageInner::RemoteImage(remote) => Self::URL(remote.url().clone()),
             ImageInner::BackendStorage(x) => vtable::VRc::borrow(x).cache_key(),
         };
         if matches!(key, ImageCacheKey::Invalid) {
@@ -318,6 +358,17 @@ pub fn from_embedded_image_data(data: &'static [u8]) -> Self {
     }
 }
 
+/// The loading state of an [`Image`], as returned by [`Image::load_state()`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ImageLoadState {
+    /// The image data is still being fetched. Renderers draw nothing for such images.
+    Loading,
+    /// The image is available, or it was never loaded from a resource, such as the default image.
+    Ready,
+    /// The image data could not be fetched or decoded. Renderers draw a placeholder for such images.
+    Error,
+}
+
 /// A resource is a reference to binary data, for example images. They can be accessible on the file
 /// system or embedded in the resulting binary. Or they might be URLs to a web server and a downloaded
 /// is necessary before they can be used.
@@ -338,6 +389,9 @@ pub enum ImageInner {
     StaticTextures(&'static StaticTextures),
     #[cfg(target_arch = "wasm32")]
     HTMLImage(vtable::VRc<OpaqueImageVTable, htmlimage::HTMLImage>),
+    /// An image referenced by URL, whose data is fetched asynchronously.
+    #[cfg(feature = "image-decoders")]
+    RemoteImage(vtable::VRc<OpaqueImageVTable, remote::RemoteImage>),
     BackendStorage(vtable::VRc<OpaqueImageVTable>),
 }
 
@@ -422,6 +476,10 @@ pub fn render_to_buffer(
                 }
                 Some(SharedImageBuffer::RGBA8Premultiplied(buffer))
             }
+            #[cfg(feature = "image-decoders")]
+            ImageInner::RemoteImage(remote) => {
+                remote.loaded_image()?.render_to_buffer(_target_size_for_scalable_source)
+            }
             _ => None,
         }
     }
@@ -433,28 +491,152 @@ pub fn is_svg(&self) -> bool {
             Self::Svg(_) => true,
             #[cfg(target_arch = "wasm32")]
             Self::HTMLImage(html_image) => html_image.is_svg(),
+            #[cfg(feature = "image-decoders")]
+            Self::RemoteImage(remote) => {
+                remote.loaded_image().map_or(false, |image| image.is_svg())
+            }
             _ => false,
         }
     }
+
+    /// Returns whether the image data is still being fetched, is available, or failed to load.
+    ///
+    /// When called from a binding, the binding is re-evaluated once the loading has finished.
+    pub fn load_state(&self) -> ImageLoadState {
+        match self {
+            #[cfg(target_arch = "wasm32")]
+            Self::HTMLImage(html_image) if html_image.size().is_none() => ImageLoadState::Loading,
+            #[cfg(feature = "image-decoders")]
+            Self::RemoteImage(remote) => match remote.state() {
+                RemoteImageState::Loading => ImageLoadState::Loading,
+                RemoteImageState::Loaded(_) => ImageLoadState::Ready,
+                RemoteImageState::Error => ImageLoadState::Error,
+            },
+            _ => ImageLoadState::Ready,
+        }
+    }
+
+    /// Returns true if the image data is still being fetched, for example for images
+    /// created with [`Image::load_from_url`].
+    ///
+    /// When called from a binding, the binding is re-evaluated once the loading has finished.
+    pub fn is_loading(&self) -> bool {
+        self.load_state() == ImageLoadState::Loading
+    }
 }
 
-impl PartialEq for ImageInner {
-    fn eq(&self, other: &Self) -> bool {
+impl ImageInner {
+    /// Returns true if both images refer to the same pixel data, without looking at the pixels.
+    fn shares_data_with(&self, other: &Self) -> bool {
         match (self, other) {
             (
                 Self::EmbeddedImage { cache_key: l_cache_key, buffer: l_buffer },
                 Self::EmbeddedImage { cache_key: r_cache_key, buffer: r_buffer },
-            ) => l_cache_key == r_cache_key && l_buffer == r_buffer,
+            ) => {
+                l_cache_key == r_cache_key
+                    && l_buffer.size() == r_buffer.size()
+                    && match (l_buffer, r_buffer) {
+                        (SharedImageBuffer::RGB8(l), SharedImageBuffer::RGB8(r)) => {
+                            core::ptr::eq(l.data.as_ptr(), r.data.as_ptr())
+                        }
+                        (SharedImageBuffer::RGBA8(l), SharedImageBuffer::RGBA8(r))
+                        | (
+                            SharedImageBuffer::RGBA8Premultiplied(l),
+                            SharedImageBuffer::RGBA8Premultiplied(r),
+                        ) => core::ptr::eq(l.data.as_ptr(), r.data.as_ptr()),
+                        _ => false,
+                    }
+            }
+            (Self::StaticTextures(l), Self::StaticTextures(r)) => {
+                l.size == r.size
+                    && l.original_size == r.original_size
+                    && core::ptr::eq(l.data.as_slice(), r.data.as_slice())
+                    && core::ptr::eq(l.textures.as_slice(), r.textures.as_slice())
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Images are only equal when they share the same data or instance, so that comparing them is
+/// cheap, also when setting an image property. [`ImageContentKey`] compares images by content.
+impl PartialEq for ImageInner {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::EmbeddedImage { .. }, Self::EmbeddedImage { .. })
+            | (Self::StaticTextures(_), Self::StaticTextures(_)) => self.shares_data_with(other),
             #[cfg(feature = "svg")]
             (Self::Svg(l0), Self::Svg(r0)) => vtable::VRc::ptr_eq(l0, r0),
-            (Self::StaticTextures(l0), Self::StaticTextures(r0)) => l0 == r0,
             #[cfg(target_arch = "wasm32")]
             (Self::HTMLImage(l0), Self::HTMLImage(r0)) => vtable::VRc::ptr_eq(l0, r0),
+            #[cfg(feature = "image-decoders")]
+            (Self::RemoteImage(l0), Self::RemoteImage(r0)) => vtable::VRc::ptr_eq(l0, r0),
+            (Self::BackendStorage(l0), Self::BackendStorage(r0)) => vtable::VRc::ptr_eq(l0, r0),
             _ => core::mem::discriminant(self) == core::mem::discriminant(other),
         }
     }
 }
 
+/// Identifies an [`Image`] by its content, for example to use it as a key in a cache of rendered
+/// images.
+///
+/// Images only compare equal when they share the same data, but the keys of images created from
+/// pixel buffers or static textures are equal if the images have the same pixels. Comparing the
+/// keys of images that don't share their data, and hashing a key, is O(n) in the size of the
+/// image data. The keys of other images, for example those loaded from SVG, are only equal for
+/// copies of the same image. The key of the default image is distinct from the key of an image
+/// without pixels.
+#[derive(Clone, Debug)]
+pub struct ImageContentKey(ImageInner);
+
+impl PartialEq for ImageContentKey {
+    fn eq(&self, other: &Self) -> bool {
+        if self.0.shares_data_with(&other.0) {
+            return true;
+        }
+        match (&self.0, &other.0) {
+            (
+                ImageInner::EmbeddedImage { cache_key: l_cache_key, buffer: l_buffer },
+                ImageInner::EmbeddedImage { cache_key: r_cache_key, buffer: r_buffer },
+            ) => l_cache_key == r_cache_key && l_buffer == r_buffer,
+            (ImageInner::StaticTextures(l0), ImageInner::StaticTextures(r0)) => l0 == r0,
+            (l, r) => l == r,
+        }
+    }
+}
+
+impl Eq for ImageContentKey {}
+
+/// The hash is consistent with the equality: decoded pixel buffers and static textures are hashed
+/// by their content. Images that are only equal when they share the same instance, such as SVGs,
+/// are hashed by their address.
+impl core::hash::Hash for ImageContentKey {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        core::mem::discriminant(&self.0).hash(state);
+        match &self.0 {
+            ImageInner::None => {}
+            ImageInner::EmbeddedImage { cache_key, buffer } => {
+                cache_key.hash(state);
+                buffer.hash(state);
+            }
+            #[cfg(feature = "svg")]
+            ImageInner::Svg(svg) => vtable::VRef::as_ptr(vtable::VRc::borrow(svg)).hash(state),
+            ImageInner::StaticTextures(textures) => textures.hash(state),
+            #[cfg(target_arch = "wasm32")]
+            ImageInner::HTMLImage(html_image) => {
+                vtable::VRef::as_ptr(vtable::VRc::borrow(html_image)).hash(state)
+            }
+            #[cfg(feature = "image-decoders")]
+            ImageInner::RemoteImage(remote) => {
+                vtable::VRef::as_ptr(vtable::VRc::borrow(remote)).hash(state)
+            }
+            ImageInner::BackendStorage(x) => {
+                vtable::VRef::as_ptr(vtable::VRc::borrow(x)).hash(state)
+            }
+        }
+    }
+}
+
 impl<'a> From<&'a Image> for &'a ImageInner {
     fn from(other: &'a Image) -> Self {
         &other.0
@@ -542,6 +724,12 @@ fn from(other: &'a Image) -> Self {
 /// let image = Image::from_rgba8_premultiplied(pixel_buffer);
 /// ```
 ///
+/// ### Comparing images
+///
+/// Images only compare equal if they share the same data, for example copies of an image, so
+/// comparing images never looks at the pixels. Use [`Image::content_key()`] to compare images by
+/// their content.
+///
 /// ### Sending Image to a thread
 ///
 /// `Image` is not [`Send`], because it uses internal cache that are local to the Slint thread.
@@ -575,6 +763,72 @@ pub fn load_from_path(path: &std::path::Path) -> Result<Self, LoadImageError> {
         })
     }
 
+    #[cfg(feature = "image-decoders")]
+    /// Load an Image from a URL.
+    ///
+    /// The image data is fetched asynchronously with the function installed with
+    /// [`set_image_url_fetcher`]. Until the data is available, the image has a size of zero
+    /// and [`Self::is_loading`] returns true.
+    pub fn load_from_url(url: &str) -> Self {
+        self::cache::IMAGE_CACHE.with(|global_cache| {
+            global_cache.borrow_mut().load_image_from_url(&url.into()).unwrap_or_default()
+        })
+    }
+
+    #[cfg(feature = "image-decoders")]
+    /// Creates an Image from a `data:` URI that embeds the encoded image, such as
+    /// `data:image/png;base64,iVBORw0KGgo...`.
+    ///
+    /// The payload can be base64 or percent-encoded. Returns None if the URI is malformed,
+    /// if its media type is not an image type, or if the image cannot be decoded.
+    pub fn from_data_uri(uri: &str) -> Option<Self> {
+        let (media_type, data) = data_uri::parse_data_uri(uri)?;
+        if !media_type.get(..6).map_or(false, |t| t.eq_ignore_ascii_case("image/")) {
+            return None;
+        }
+        Self::load_from_data(&data).ok()
+    }
+
+    #[cfg(feature = "image-decoders")]
+    /// Creates a new Image from the specified buffer, which contains encoded image data, such as
+    /// the contents of a PNG or JPEG file, or SVG if the `svg` feature is enabled.
+    ///
+    /// The buffer is only needed while decoding, so it can be data that is created at run-time,
+    /// for example a [`SharedVector<u8>`] with bytes that were read from an archive.
+    pub fn load_from_data(buffer: &[u8]) -> Result<Self, LoadImageError> {
+        match image::load_from_memory(buffer) {
+            Ok(image) => Ok(Image(ImageInner::EmbeddedImage {
+                cache_key: ImageCacheKey::Invalid,
+                buffer: cache::dynamic_image_to_shared_image_buffer(image),
+            })),
+            #[cfg(feature = "svg")]
+            Err(_) => Self::load_from_svg_data(buffer),
+            #[cfg(not(feature = "svg"))]
+            Err(_) => Err(LoadImageError(())),
+        }
+    }
+
+    /// Returns true if the image data is still being fetched, for example for images
+    /// created with [`Self::load_from_url`].
+    ///
+    /// When called from a binding, the binding is re-evaluated once the loading has finished.
+    pub fn is_loading(&self) -> bool {
+        self.0.is_loading()
+    }
+
+    /// Returns a key that identifies this image by its content, see [`ImageContentKey`].
+    pub fn content_key(&self) -> ImageContentKey {
+        ImageContentKey(self.0.clone())
+    }
+
+    /// Returns whether the image data is still being fetched, is available, or failed to load.
+    /// Only images created with [`Self::load_from_url`] go through the `Loading` state.
+    ///
+    /// When called from a binding, the binding is re-evaluated once the loading has finished.
+    pub fn load_state(&self) -> ImageLoadState {
+        self.0.load_state()
+    }
+
     /// Creates a new Image from the specified shared pixel buffer, where each pixel has three color
     /// channels (red, green and blue) encoded as u8.
     pub fn from_rgb8(buffer: SharedPixelBuffer<Rgb8Pixel>) -> Self {
@@ -624,6 +878,8 @@ pub fn size(&self) -> IntSize {
             ImageInner::Svg(svg) => svg.size(),
             #[cfg(target_arch = "wasm32")]
             ImageInner::HTMLImage(htmlimage) => htmlimage.size().unwrap_or_default(),
+            #[cfg(feature = "image-decoders")]
+            ImageInner::RemoteImage(remote) => remote.size(),
             ImageInner::BackendStorage(x) => vtable::VRc::borrow(x).size(),
         }
     }
@@ -674,6 +930,41 @@ fn test_image_size_from_buffer_without_backend() {
     }
 }
 
+#[cfg(feature = "std")]
+#[test]
+fn test_image_content_key() {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+    fn hash(key: &ImageContentKey) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        key.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    let pixels = [1u8, 2, 3, 4, 5, 6];
+    let a = Image::from_rgb8(SharedPixelBuffer::clone_from_slice(&pixels, 2, 1));
+    let b = Image::from_rgb8(SharedPixelBuffer::clone_from_slice(&pixels, 2, 1));
+    // Images are only equal if they share their data
+    assert_ne!(a, b);
+    assert_eq!(a, a.clone());
+    let buffer = SharedPixelBuffer::<Rgb8Pixel>::clone_from_slice(&pixels, 2, 1);
+    assert_eq!(Image::from_rgb8(buffer.clone()), Image::from_rgb8(buffer));
+
+    assert_eq!(a.content_key(), b.content_key());
+    assert_eq!(hash(&a.content_key()), hash(&b.content_key()));
+    assert_eq!(a.content_key(), a.clone().content_key());
+
+    let transposed = Image::from_rgb8(SharedPixelBuffer::clone_from_slice(&pixels, 1, 2));
+    assert_ne!(a.content_key(), transposed.content_key());
+    let other = Image::from_rgb8(SharedPixelBuffer::clone_from_slice(&pixels[..3], 1, 1));
+    assert_ne!(a.content_key(), other.content_key());
+
+    let empty = Image::from_rgb8(SharedPixelBuffer::new(0, 0));
+    assert_ne!(empty.content_key(), Image::default().content_key());
+    assert_ne!(hash(&empty.content_key()), hash(&Image::default().content_key()));
+    assert_eq!(hash(&Image::default().content_key()), hash(&Image::default().content_key()));
+}
+
 #[cfg(feature = "svg")]
 #[test]
 fn test_image_size_from_svg() {
@@ -690,6 +981,116 @@ fn test_image_invalid_svg() {
     assert!(result.is_err());
 }
 
+#[cfg(feature = "svg")]
+#[test]
+fn test_image_load_from_url() {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    let pending = Rc::new(RefCell::new(Vec::<ImageFetchCompletion>::new()));
+    set_image_url_fetcher({
+        let pending = pending.clone();
+        move |_, completion| pending.borrow_mut().push(completion)
+    });
+
+    let image = Image::load_from_url("https://example.com/image.svg");
+    assert!(image.is_loading());
+    assert_eq!(image.load_state(), ImageLoadState::Loading);
+    assert_eq!(image.size(), IntSize::default());
+    assert_eq!(Image::load_from_url("https://example.com/image.svg"), image);
+    assert_eq!(pending.borrow().len(), 1);
+
+    let simple_svg = r#"<svg width="320" height="200" xmlns="http://www.w3.org/2000/svg"></svg>"#;
+    let completion = pending.borrow_mut().pop().unwrap();
+    assert_eq!(completion.url(), "https://example.com/image.svg");
+    completion.complete(Ok(simple_svg.as_bytes().to_vec()));
+    assert!(!image.is_loading());
+    assert_eq!(image.load_state(), ImageLoadState::Ready);
+    assert_eq!(image.size(), [320, 200].into());
+    // The cache now holds the decoded image, which accounts for its size
+    let cached = Image::load_from_url("https://example.com/image.svg");
+    assert!(matches!(&cached.0, ImageInner::Svg(_)));
+    assert!(pending.borrow().is_empty());
+
+    let failing = Image::load_from_url("https://example.com/missing.png");
+    drop(pending.borrow_mut().pop());
+    assert!(!failing.is_loading());
+    assert_eq!(failing.load_state(), ImageLoadState::Error);
+    assert_eq!(failing.size(), IntSize::default());
+    // Loading a failed URL again starts a new fetch
+    let _retry = Image::load_from_url("https://example.com/missing.png");
+    assert_eq!(pending.borrow().len(), 1);
+}
+
+#[cfg(feature = "svg")]
+#[test]
+fn test_image_load_svg_from_url() {
+    let simple_svg = r#"<svg width="320" height="200" xmlns="http://www.w3.org/2000/svg"></svg>"#;
+    set_image_url_fetcher(move |_, completion| {
+        completion.complete(Ok(simple_svg.as_bytes().to_vec()));
+    });
+    for url in [
+        "https://example.com/image.SVG",
+        "https://example.com/image.svg?width=320",
+        "https://example.com/image.svg#icon",
+        "https://example.com/render?format=svg",
+    ] {
+        let image = Image::load_from_url(url);
+        assert_eq!(image.load_state(), ImageLoadState::Ready, "{url}");
+        assert_eq!(image.size(), [320, 200].into(), "{url}");
+    }
+}
+
+#[cfg(feature = "image-decoders")]
+#[test]
+fn test_image_load_from_data() {
+    // A 1x1 red PNG
+    let png: SharedVector<u8> = [
+        0x89, 0x50, 0x4e, 0x47, 0x0d, 0x0a, 0x1a, 0x0a, 0x00, 0x00, 0x00, 0x0d, 0x49, 0x48, 0x44,
+        0x52, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x08, 0x02, 0x00, 0x00, 0x00, 0x90,
+        0x77, 0x53, 0xde, 0x00, 0x00, 0x00, 0x0c, 0x49, 0x44, 0x41, 0x54, 0x78, 0x9c, 0x63, 0xf8,
+        0xcf, 0xc0, 0x00, 0x00, 0x03, 0x01, 0x01, 0x00, 0xc9, 0xfe, 0x92, 0xef, 0x00, 0x00, 0x00,
+        0x00, 0x49, 0x45, 0x4e, 0x44, 0xae, 0x42, 0x60, 0x82,
+    ]
+    .into_iter()
+    .collect();
+    let image = Image::load_from_data(&png).unwrap();
+    assert_eq!(image.size(), [1, 1].into());
+    assert!(matches!(
+        image.0.render_to_buffer(None),
+        Some(SharedImageBuffer::RGB8(pixels)) if pixels.as_slice() == [Rgb8Pixel::new(255, 0, 0)]
+    ));
+
+    #[cfg(feature = "svg")]
+    {
+        let svg = r#"<svg width="320" height="200" xmlns="http://www.w3.org/2000/svg"></svg>"#;
+        assert_eq!(Image::load_from_data(svg.as_bytes()).unwrap().size(), [320, 200].into());
+    }
+
+    assert!(Image::load_from_data(&png[..20]).is_err());
+    assert!(Image::load_from_data(b"AaBbCcDd").is_err());
+}
+
+#[cfg(feature = "image-decoders")]
+#[test]
+fn test_image_from_data_uri() {
+    // A 1x1 red PNG
+    let png = "data:image/png;base64,iVBORw0KGgoAAAANSUhEUgAAAAEAAAABCAIAAACQd1PeAAAADElEQVR4nGP4z8AAAAMBAQDJ/pLvAAAAAElFTkSuQmCC";
+    let image = Image::from_data_uri(png).unwrap();
+    assert_eq!(image.size(), [1, 1].into());
+
+    #[cfg(feature = "svg")]
+    {
+        let svg = "data:image/svg+xml,%3Csvg%20width%3D%2232%22%20height%3D%2216%22%20xmlns%3D%22http%3A%2F%2Fwww.w3.org%2F2000%2Fsvg%22%2F%3E";
+        assert_eq!(Image::from_data_uri(svg).unwrap().size(), [32, 16].into());
+    }
+
+    assert!(Image::from_data_uri("data:text/plain;base64,iVBORw0KGgo=").is_none());
+    assert!(Image::from_data_uri("data:image/png;base64,iVBORw0KGgo=").is_none());
+    assert!(Image::from_data_uri("data:image/png;base64,not base64!").is_none());
+    assert!(Image::from_data_uri("image/png;base64,iVBORw0KGgo=").is_none());
+}
+
 /// Return an size that can be used to render an image in a buffer that matches a given ImageFit
 pub fn fit_size(
     image_fit: ImageFit,
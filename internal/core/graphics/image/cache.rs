@@ -23,6 +23,9 @@ fn weight(&self, _: &ImageCacheKey, value: &ImageInner) -> usize {
             ImageInner::Svg(_) => 512, // Don't know how to measure the size of the parsed SVG tree...
             #[cfg(target_arch = "wasm32")]
             ImageInner::HTMLImage(_) => 512, // Something... the web browser maintainers its own cache. The purpose of this cache is to reduce the amount of DOM elements.
+            // The weight must not change while the image is in the cache. Once fetched, the remote image is
+            // replaced in the cache by the decoded image, see replace_fetched_image.
+            ImageInner::RemoteImage(_) => 0,
             ImageInner::StaticTextures(_) => 0,
             ImageInner::BackendStorage(x) => vtable::VRc::borrow(x).size().area() as usize,
         }
@@ -109,6 +112,37 @@ pub(crate) fn load_image_from_path(&mut self, path: &SharedString) -> Option<Ima
         });
     }
 
+    pub(crate) fn load_image_from_url(&mut self, url: &SharedString) -> Option<Image> {
+        if url.is_empty() {
+            return None;
+        }
+        let cache_key = ImageCacheKey::URL(url.clone());
+        #[cfg(target_arch = "wasm32")]
+        return self.lookup_image_in_cache_or_create(cache_key, |_| {
+            return Some(ImageInner::HTMLImage(vtable::VRc::new(
+                super::htmlimage::HTMLImage::new(&url),
+            )));
+        });
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            // Drop images whose fetch failed, so that loading the same URL again retries.
+            if matches!(self.0.peek(&cache_key), Some(ImageInner::RemoteImage(remote))
+                    if remote.state() == super::RemoteImageState::Error)
+            {
+                self.0.pop(&cache_key);
+            }
+            self.lookup_image_in_cache_or_create(cache_key, |_| {
+                let remote = super::remote::RemoteImage::new(url.clone());
+                // The fetcher may have completed right away
+                Some(
+                    remote
+                        .loaded_image()
+                        .unwrap_or_else(|| ImageInner::RemoteImage(vtable::VRc::new(remote))),
+                )
+            })
+        }
+    }
+
     pub(crate) fn load_image_from_embedded_data(
         &mut self,
         data: Slice<'static, u8>,
@@ -152,7 +186,9 @@ pub(crate) fn load_image_from_embedded_data(
     }
 }
 
-fn dynamic_image_to_shared_image_buffer(dynamic_image: image::DynamicImage) -> SharedImageBuffer {
+pub(super) fn dynamic_image_to_shared_image_buffer(
+    dynamic_image: image::DynamicImage,
+) -> SharedImageBuffer {
     if dynamic_image.color().has_alpha() {
         let rgba8image = dynamic_image.to_rgba8();
         SharedImageBuffer::RGBA8(SharedPixelBuffer::clone_from_slice(
@@ -178,3 +214,19 @@ pub fn replace_cached_image(key: ImageCacheKey, value: ImageInner) {
     let _ =
         IMAGE_CACHE.with(|global_cache| global_cache.borrow_mut().0.put_with_weight(key, value));
 }
+
+/// Replaces the remote image cached for the URL with the decoded image, so that its size counts
+/// towards the size of the cache.
+pub(super) fn replace_fetched_image(url: &SharedString, image: ImageInner) {
+    let key = ImageCacheKey::URL(url.clone());
+    // Use try_with, as a fetch might complete while the thread locals are destroyed.
+    let _ = IMAGE_CACHE.try_with(|global_cache| {
+        // The cache is borrowed when the fetch completes while the image is being created. It's
+        // not cached yet then, and load_image_from_url caches the decoded image directly.
+        if let Ok(mut global_cache) = global_cache.try_borrow_mut() {
+            if global_cache.0.peek(&key).is_some() {
+                let _ = global_cache.0.put_with_weight(key, image);
+            }
+        }
+    });
+}
@@ -0,0 +1,99 @@
+// Copyright © SixtyFPS GmbH <info@slint-ui.com>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-commercial
+
+/*!
+Parsing of `data:` URIs as described in RFC 2397, for example `data:image/png;base64,iVBORw0KGgo...`
+*/
+
+use alloc::vec::Vec;
+
+/// Splits a data URI into its media type and its decoded payload.
+///
+/// The payload may be percent-encoded and, if the `;base64` parameter is present, base64 encoded.
+/// Returns None if the URI is not a data URI or if the payload is malformed.
+pub(super) fn parse_data_uri(uri: &str) -> Option<(&str, Vec<u8>)> {
+    let scheme = uri.get(..5)?;
+    if !scheme.eq_ignore_ascii_case("data:") {
+        return None;
+    }
+    let (header, payload) = uri[5..].split_once(',')?;
+    let mut params = header.split(';');
+    let media_type = params.next().unwrap_or_default().trim();
+    let is_base64 = params.next_back().map_or(false, |p| p.trim().eq_ignore_ascii_case("base64"));
+
+    let payload = percent_decode(payload)?;
+    let payload = if is_base64 { base64_decode(&payload)? } else { payload };
+    Some((media_type, payload))
+}
+
+fn percent_decode(input: &str) -> Option<Vec<u8>> {
+    let mut result = Vec::with_capacity(input.len());
+    let mut bytes = input.bytes();
+    while let Some(b) = bytes.next() {
+        if b == b'%' {
+            let hi = (bytes.next()? as char).to_digit(16)?;
+            let lo = (bytes.next()? as char).to_digit(16)?;
+            result.push((hi * 16 + lo) as u8);
+        } else {
+            result.push(b);
+        }
+    }
+    Some(result)
+}
+
+fn base64_decode(input: &[u8]) -> Option<Vec<u8>> {
+    fn value(b: u8) -> Option<u32> {
+        Some(match b {
+            b'A'..=b'Z' => b - b'A',
+            b'a'..=b'z' => b - b'a' + 26,
+            b'0'..=b'9' => b - b'0' + 52,
+            b'+' | b'-' => 62,
+            b'/' | b'_' => 63,
+            _ => return None,
+        } as u32)
+    }
+
+    let mut input: Vec<u8> = input.iter().copied().filter(|b| !b.is_ascii_whitespace()).collect();
+    // Padding is optional, but if present it must complete the last group of four characters.
+    if input.len() % 4 == 0 {
+        while input.last() == Some(&b'=') {
+            input.pop();
+        }
+    }
+    if input.len() % 4 == 1 {
+        return None;
+    }
+
+    let mut result = Vec::with_capacity(input.len() * 3 / 4);
+    for chunk in input.chunks(4) {
+        let mut bits = 0u32;
+        for (i, b) in chunk.iter().enumerate() {
+            bits |= value(*b)? << (18 - 6 * i);
+        }
+        let bytes = bits.to_be_bytes();
+        result.extend_from_slice(&bytes[1..chunk.len()]);
+    }
+    Some(result)
+}
+
+#[test]
+fn test_parse_data_uri() {
+    assert_eq!(
+        parse_data_uri("data:image/png;base64,iVBORw0KGgo="),
+        Some(("image/png", b"\x89PNG\r\n\x1a\n".to_vec()))
+    );
+    assert_eq!(
+        parse_data_uri("DATA:image/svg+xml;charset=utf-8,%3Csvg%2F%3E"),
+        Some(("image/svg+xml", b"<svg/>".to_vec()))
+    );
+    assert_eq!(parse_data_uri("data:,Hello"), Some(("", b"Hello".to_vec())));
+    assert_eq!(parse_data_uri("data:;base64,SGVs%0AbG8"), Some(("", b"Hello".to_vec())));
+    assert_eq!(parse_data_uri("data:;base64,SGVsbA=="), Some(("", b"Hell".to_vec())));
+
+    assert_eq!(parse_data_uri("http://example.com/image.png"), None);
+    assert_eq!(parse_data_uri("data:image/png;base64"), None);
+    assert_eq!(parse_data_uri("data:image/png;base64,SGVsbA*="), None);
+    assert_eq!(parse_data_uri("data:image/png;base64,SGVsb"), None);
+    assert_eq!(parse_data_uri("data:image/png,%4"), None);
+    assert_eq!(parse_data_uri("data:image/png,%zz"), None);
+}
@@ -0,0 +1,252 @@
+// Copyright © SixtyFPS GmbH <info@slint-ui.com>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-commercial
+
+/*!
+Support for images that are referenced by a URL and whose data needs to be fetched
+asynchronously before they can be decoded. The actual fetching is delegated to a
+fetcher function installed by the application with [`set_image_url_fetcher`].
+*/
+
+use alloc::rc::Rc;
+use core::cell::RefCell;
+use core::pin::Pin;
+use std::collections::HashMap;
+
+use super::{ImageCacheKey, ImageInner, LoadImageError};
+use crate::graphics::IntSize;
+use crate::{Property, SharedString};
+
+/// The state of an image that is fetched from a URL.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum RemoteImageState {
+    /// The data is being fetched.
+    #[default]
+    Loading,
+    /// The data was fetched and decoded successfully.
+    Loaded(ImageInner),
+    /// The data could not be fetched or decoded.
+    Error,
+}
+
+pub struct RemoteImage {
+    url: SharedString,
+    /// Reading this property from within a binding or the rendering of a graphics item
+    /// registers a dependency, so that the item is updated once the fetch completes.
+    state: Pin<Rc<Property<RemoteImageState>>>,
+}
+
+type ImageUrlFetcher = Rc<dyn Fn(&str, ImageFetchCompletion)>;
+type PendingFetches = HashMap<SharedString, Vec<Pin<Rc<Property<RemoteImageState>>>>>;
+
+thread_local! {
+    static IMAGE_URL_FETCHER: RefCell<Option<ImageUrlFetcher>> = Default::default();
+    /// Images whose data is currently being fetched, by URL.
+    static PENDING_FETCHES: RefCell<PendingFetches> = Default::default();
+}
+
+/// Installs the function that is used to fetch the data of images loaded with
+/// [`Image::load_from_url()`](super::Image::load_from_url).
+///
+/// The function is called with the URL of the image and an [`ImageFetchCompletion`]. It should
+/// start fetching the data without blocking and hand the resulting encoded bytes (for example
+/// the contents of a PNG file) to [`ImageFetchCompletion::complete()`], which may be called from
+/// any thread.
+///
+/// The fetcher is local to the calling thread, which should be the thread running the Slint event loop.
+pub fn set_image_url_fetcher(fetcher: impl Fn(&str, ImageFetchCompletion) + 'static) {
+    IMAGE_URL_FETCHER.with(|f| *f.borrow_mut() = Some(Rc::new(fetcher)));
+}
+
+/// Handle passed to the function installed with [`set_image_url_fetcher`], used to report
+/// the result of fetching an image.
+///
+/// Dropping the handle without calling [`Self::complete()`] marks the image as failed to load.
+pub struct ImageFetchCompletion {
+    url: Option<String>,
+    thread: std::thread::ThreadId,
+}
+
+impl ImageFetchCompletion {
+    /// Returns the URL of the image to fetch.
+    pub fn url(&self) -> &str {
+        self.url.as_deref().unwrap_or_default()
+    }
+
+    /// Reports the encoded image data, or an error if the data could not be fetched.
+    ///
+    /// The data is decoded and all images waiting for this URL are updated in the
+    /// Slint event loop.
+    pub fn complete(mut self, result: Result<Vec<u8>, LoadImageError>) {
+        if let Some(url) = self.url.take() {
+            self.dispatch(url, result);
+        }
+    }
+
+    fn dispatch(&self, url: String, result: Result<Vec<u8>, LoadImageError>) {
+        if std::thread::current().id() == self.thread {
+            finish_fetch(&url.into(), result);
+        } else {
+            crate::api::invoke_from_event_loop(move || finish_fetch(&url.into(), result)).ok();
+        }
+    }
+}
+
+impl Drop for ImageFetchCompletion {
+    fn drop(&mut self) {
+        if let Some(url) = self.url.take() {
+            self.dispatch(url, Err(LoadImageError(())));
+        }
+    }
+}
+
+fn finish_fetch(url: &SharedString, result: Result<Vec<u8>, LoadImageError>) {
+    // Use try_with, as a completion might be dropped while the thread locals are destroyed.
+    let pending = match PENDING_FETCHES.try_with(|p| p.borrow_mut().remove(url)) {
+        Ok(Some(pending)) => pending,
+        _ => return,
+    };
+    let state = match result.and_then(|data| decode(url, &data)) {
+        Ok(image) => RemoteImageState::Loaded(image),
+        Err(_) => {
+            eprintln!("Error loading image from {}", url);
+            RemoteImageState::Error
+        }
+    };
+    if let RemoteImageState::Loaded(image) = &state {
+        super::cache::replace_fetched_image(url, image.clone());
+    }
+    for property in pending {
+        property.as_ref().set(state.clone());
+    }
+}
+
+fn decode(url: &SharedString, data: &[u8]) -> Result<ImageInner, LoadImageError> {
+    let cache_key = ImageCacheKey::URL(url.clone());
+    #[cfg(feature = "svg")]
+    if has_svg_extension(url) {
+        return decode_svg(data, cache_key);
+    }
+    match image::load_from_memory(data) {
+        Ok(image) => Ok(ImageInner::EmbeddedImage {
+            cache_key,
+            buffer: super::cache::dynamic_image_to_shared_image_buffer(image),
+        }),
+        // Not every URL of an SVG ends with .svg, for example when the image is generated by a server
+        #[cfg(feature = "svg")]
+        Err(_) => decode_svg(data, cache_key),
+        #[cfg(not(feature = "svg"))]
+        Err(_) => Err(LoadImageError(())),
+    }
+}
+
+#[cfg(feature = "svg")]
+fn decode_svg(data: &[u8], cache_key: ImageCacheKey) -> Result<ImageInner, LoadImageError> {
+    super::svg::load_from_data(data, cache_key)
+        .map(|svg| ImageInner::Svg(vtable::VRc::new(svg)))
+        .map_err(|_| LoadImageError(()))
+}
+
+/// Returns true if the path of the URL, without its query and fragment, has an SVG file extension.
+#[cfg(feature = "svg")]
+fn has_svg_extension(url: &str) -> bool {
+    let path = url.split(['?', '#']).next().unwrap_or_default().to_ascii_lowercase();
+    path.ends_with(".svg") || path.ends_with(".svgz")
+}
+
+impl RemoteImage {
+    /// Creates the image and starts fetching its data with the installed fetcher.
+    pub fn new(url: SharedString) -> Self {
+        let state = Rc::pin(Property::new(RemoteImageState::Loading));
+        let fetcher = IMAGE_URL_FETCHER.with(|f| f.borrow().clone());
+        match fetcher {
+            Some(fetcher) => {
+                let already_pending = PENDING_FETCHES.with(|p| {
+                    let mut pending = p.borrow_mut();
+                    let entry = pending.entry(url.clone()).or_default();
+                    entry.push(state.clone());
+                    entry.len() > 1
+                });
+                if !already_pending {
+                    let completion = ImageFetchCompletion {
+                        url: Some(url.to_string()),
+                        thread: std::thread::current().id(),
+                    };
+                    fetcher(&url, completion);
+                }
+            }
+            None => {
+                eprintln!("Cannot load image from {}: no image URL fetcher was installed", url);
+                state.as_ref().set(RemoteImageState::Error);
+            }
+        }
+        Self { url, state }
+    }
+
+    /// Returns the current state of the fetch.
+    pub fn state(&self) -> RemoteImageState {
+        self.state.as_ref().get()
+    }
+
+    /// Returns the decoded image if it has finished loading.
+    pub fn loaded_image(&self) -> Option<ImageInner> {
+        match self.state() {
+            RemoteImageState::Loaded(image) => Some(image),
+            _ => None,
+        }
+    }
+
+    pub fn size(&self) -> IntSize {
+        self.loaded_image().map(|image| super::Image(image).size()).unwrap_or_default()
+    }
+
+    pub fn url(&self) -> &SharedString {
+        &self.url
+    }
+}
+
+impl Drop for RemoteImage {
+    fn drop(&mut self) {
+        // Forget about the fetch if this was the last image waiting for it. The completion
+        // of the fetch then finds no pending entry and is ignored.
+        let state: *const Property<RemoteImageState> = &*self.state;
+        PENDING_FETCHES
+            .try_with(|p| {
+                let mut pending = p.borrow_mut();
+                if let Some(waiting) = pending.get_mut(&self.url) {
+                    waiting.retain(|s| !core::ptr::eq(&**s, state));
+                    if waiting.is_empty() {
+                        pending.remove(&self.url);
+                    }
+                }
+            })
+            .ok();
+    }
+}
+
+impl super::OpaqueImage for RemoteImage {
+    fn size(&self) -> IntSize {
+        self.size()
+    }
+    fn cache_key(&self) -> ImageCacheKey {
+        ImageCacheKey::URL(self.url.clone())
+    }
+}
+
+#[test]
+fn test_drop_pending_remote_image() {
+    let completions = Rc::new(RefCell::new(Vec::new()));
+    set_image_url_fetcher({
+        let completions = completions.clone();
+        move |_, completion| completions.borrow_mut().push(completion)
+    });
+    let url = SharedString::from("https://example.com/dropped.png");
+    let first = RemoteImage::new(url.clone());
+    let second = RemoteImage::new(url.clone());
+    assert_eq!(completions.borrow().len(), 1);
+    drop(first);
+    assert!(PENDING_FETCHES.with(|p| p.borrow().contains_key(&url)));
+    drop(second);
+    assert!(!PENDING_FETCHES.with(|p| p.borrow().contains_key(&url)));
+    // The late completion is ignored
+    completions.borrow_mut().pop().unwrap().complete(Err(LoadImageError(())));
+}
@@ -100,8 +100,8 @@ pub struct PathCubicTo {
 #[repr(C)]
 #[derive(FieldOffsets, Default, SlintElement, Clone, Debug, PartialEq)]
 #[pin]
-/// PathCubicTo describes a smooth Bézier curve from the path's current position
-/// to the specified x/y location, using one control points.
+/// PathQuadraticTo describes a smooth Bézier curve from the path's current position
+/// to the specified x/y location, using one control point.
 pub struct PathQuadraticTo {
     #[rtti_field]
     /// The x coordinate of the curve's end point.
@@ -138,8 +138,10 @@ pub enum PathElement {
 struct ToLyonPathEventIterator<'a> {
     events_it: core::slice::Iter<'a, PathEvent>,
     coordinates_it: core::slice::Iter<'a, lyon_path::math::Point>,
-    first: Option<&'a lyon_path::math::Point>,
-    last: Option<&'a lyon_path::math::Point>,
+    /// The start of the current sub-path
+    first: lyon_path::math::Point,
+    /// The end point of the last event
+    last: lyon_path::math::Point,
 }
 
 impl<'a> Iterator for ToLyonPathEventIterator<'a> {
@@ -147,30 +149,38 @@ impl<'a> Iterator for ToLyonPathEventIterator<'a> {
     fn next(&mut self) -> Option<Self::Item> {
         use lyon_path::Event;
 
-        self.events_it.next().map(|event| match event {
-            PathEvent::Begin => Event::Begin { at: *self.coordinates_it.next().unwrap() },
-            PathEvent::Line => Event::Line {
-                from: *self.coordinates_it.next().unwrap(),
-                to: *self.coordinates_it.next().unwrap(),
-            },
+        // An event without enough coordinates ends the iteration, see PathData::validate
+        let event = match self.events_it.next()? {
+            PathEvent::Begin => {
+                let at = *self.coordinates_it.next()?;
+                self.first = at;
+                Event::Begin { at }
+            }
+            PathEvent::Line => {
+                Event::Line { from: *self.coordinates_it.next()?, to: *self.coordinates_it.next()? }
+            }
             PathEvent::Quadratic => Event::Quadratic {
-                from: *self.coordinates_it.next().unwrap(),
-                ctrl: *self.coordinates_it.next().unwrap(),
-                to: *self.coordinates_it.next().unwrap(),
+                from: *self.coordinates_it.next()?,
+                ctrl: *self.coordinates_it.next()?,
+                to: *self.coordinates_it.next()?,
             },
             PathEvent::Cubic => Event::Cubic {
-                from: *self.coordinates_it.next().unwrap(),
-                ctrl1: *self.coordinates_it.next().unwrap(),
-                ctrl2: *self.coordinates_it.next().unwrap(),
-                to: *self.coordinates_it.next().unwrap(),
+                from: *self.coordinates_it.next()?,
+                ctrl1: *self.coordinates_it.next()?,
+                ctrl2: *self.coordinates_it.next()?,
+                to: *self.coordinates_it.next()?,
             },
-            PathEvent::EndOpen => {
-                Event::End { first: *self.first.unwrap(), last: *self.last.unwrap(), close: false }
-            }
-            PathEvent::EndClosed => {
-                Event::End { first: *self.first.unwrap(), last: *self.last.unwrap(), close: true }
-            }
-        })
+            PathEvent::EndOpen => Event::End { first: self.first, last: self.last, close: false },
+            PathEvent::EndClosed => Event::End { first: self.first, last: self.last, close: true },
+        };
+        match event {
+            Event::Begin { at: to }
+            | Event::Line { to, .. }
+            | Event::Quadratic { to, .. }
+            | Event::Cubic { to, .. } => self.last = to,
+            Event::End { .. } => {}
+        }
+        Some(event)
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
@@ -235,8 +245,8 @@ pub fn iter(
                     it: ToLyonPathEventIterator {
                         events_it: events.iter(),
                         coordinates_it: coordinates.iter(),
-                        first: coordinates.first(),
-                        last: coordinates.last(),
+                        first: Default::default(),
+                        last: Default::default(),
                     },
                     transform: self.transform,
                 }
@@ -244,17 +254,151 @@ pub fn iter(
         }
     }
 
+    /// Returns an iterator over the events of the path, where curves and arcs are approximated
+    /// with line segments. This is meant for renderers that can only draw straight lines.
+    ///
+    /// `tolerance` is the maximum distance between a curve and the line segments that approximate
+    /// it, in the coordinate system of the events, after applying any transformation. A lower tolerance
+    /// produces more segments. To get a consistent quality, divide a tolerance in physical pixels by
+    /// the scale factor, for example [`Self::DEFAULT_TOLERANCE`] `/ scale_factor`.
+    pub fn flattened(
+        &self,
+        tolerance: f32,
+    ) -> impl Iterator<Item = lyon_path::Event<lyon_path::math::Point, lyon_path::math::Point>> + '_
+    {
+        use lyon_path::iterator::PathIterator;
+        self.iter().flattened(tolerance)
+    }
+
+    /// Returns the events of the dashes when stroking the path with the dash pattern given by
+    /// `dash_array`, like SVG's `stroke-dasharray` and `stroke-dashoffset`. Each dash is an
+    /// open sub-path made of line segments, approximating curves as described in [`Self::flattened`].
+    ///
+    /// `dash_array` alternates between the lengths of dashes and gaps, and is repeated if it has an
+    /// odd number of entries. The pattern starts anew at the beginning of each sub-path, shifted by
+    /// `dash_offset`. If the dash array is empty, or contains negative values or only zeros, the
+    /// stroke is solid and the events of the path are returned unchanged.
+    pub fn dashed(
+        &self,
+        dash_array: &[f32],
+        dash_offset: f32,
+        tolerance: f32,
+    ) -> Vec<lyon_path::PathEvent> {
+        use lyon_path::Event;
+
+        let period = dash_array.iter().sum::<f32>();
+        if dash_array.iter().any(|length| *length < 0.) || !(period > 0. && period.is_finite()) {
+            return self.iter().collect();
+        }
+        let pattern =
+            if dash_array.len() % 2 == 1 { dash_array.repeat(2) } else { dash_array.to_vec() };
+        let period = period * (pattern.len() / dash_array.len()) as f32;
+
+        let mut result = Vec::new();
+        // Index in the pattern (even for dashes, odd for gaps) and the length left of that entry
+        let mut index = 0;
+        let mut remaining = 0.;
+        // The start of the dash that is currently being emitted
+        let mut dash_start = None;
+
+        fn end_dash(
+            result: &mut Vec<lyon_path::PathEvent>,
+            dash_start: &mut Option<lyon_path::math::Point>,
+            last: lyon_path::math::Point,
+        ) {
+            if let Some(first) = dash_start.take() {
+                result.push(Event::End { last, first, close: false });
+            }
+        }
+
+        for event in self.flattened(tolerance) {
+            let (from, to) = match event {
+                Event::Begin { .. } => {
+                    let mut offset = dash_offset.rem_euclid(period);
+                    index = 0;
+                    while offset >= pattern[index] {
+                        offset -= pattern[index];
+                        index = (index + 1) % pattern.len();
+                    }
+                    remaining = pattern[index] - offset;
+                    continue;
+                }
+                Event::Line { from, to } => (from, to),
+                Event::End { last, first, close: true } => (last, first),
+                Event::End { last, .. } => {
+                    end_dash(&mut result, &mut dash_start, last);
+                    continue;
+                }
+                Event::Quadratic { .. } | Event::Cubic { .. } => unreachable!(),
+            };
+
+            let length = (to - from).length();
+            let direction = if length > 0. { (to - from) / length } else { Default::default() };
+            let mut position = from;
+            let mut left = length;
+            while left > 0. {
+                let step = f32::min(remaining, left);
+                let next = position + direction * step;
+                if index % 2 == 0 {
+                    if dash_start.is_none() {
+                        dash_start = Some(position);
+                        result.push(Event::Begin { at: position });
+                    }
+                    result.push(Event::Line { from: position, to: next });
+                }
+                left -= step;
+                remaining -= step;
+                position = next;
+                if remaining <= 0. {
+                    end_dash(&mut result, &mut dash_start, position);
+                    index = (index + 1) % pattern.len();
+                    remaining = pattern[index];
+                }
+            }
+            if matches!(event, Event::End { .. }) {
+                end_dash(&mut result, &mut dash_start, position);
+            }
+        }
+        result
+    }
+
+    /// The flattening tolerance, in physical pixels, that renderers should use by default. Curves
+    /// approximated with this tolerance don't show visible facets.
+    pub const DEFAULT_TOLERANCE: f32 = 0.1;
+
     /// Applies a transformation on the elements this iterator provides that tries to fit everything
     /// into the specified width/height, respecting the provided viewbox. If no viewbox is specified,
     /// the bounding rectangle of the path is used.
     pub fn fit(&mut self, width: f32, height: f32, viewbox: Option<lyon_path::math::Box2D>) {
+        self.fit_with_style(width, height, viewbox, lyon_algorithms::fit::FitStyle::Min)
+    }
+
+    /// Applies the given affine transformation, such as a rotation or a skew, on the elements
+    /// this iterator provides. The transformation is applied after any transformation that is
+    /// already in place, so to transform a path that is fitted into a rectangle, call this
+    /// function after [`Self::fit`].
+    pub fn transform(&mut self, transform: lyon_path::math::Transform) {
+        self.transform = self.transform.then(&transform);
+    }
+
+    /// Same as [`Self::fit`], but with the given `style` deciding how the path is scaled if
+    /// its aspect ratio differs from the one of the specified width/height. [`Self::fit`]
+    /// uses [`FitStyle::Min`](lyon_algorithms::fit::FitStyle::Min), which scales the path
+    /// uniformly so that it fits without overflowing.
+    pub fn fit_with_style(
+        &mut self,
+        width: f32,
+        height: f32,
+        viewbox: Option<lyon_path::math::Box2D>,
+        style: lyon_algorithms::fit::FitStyle,
+    ) {
         if width > 0. || height > 0. {
             let viewbox =
                 viewbox.unwrap_or_else(|| lyon_algorithms::aabb::bounding_box(self.iter()));
             self.transform = lyon_algorithms::fit::fit_box(
                 &viewbox,
                 &lyon_path::math::Box2D::from_size(lyon_path::math::Size::new(width, height)),
-                lyon_algorithms::fit::FitStyle::Min,
+                style,
             );
         }
     }
@@ -282,7 +426,210 @@ fn default() -> Self {
     }
 }
 
+/// Creates a path that contains the sub-paths of all the paths, in the [`PathData::Events`]
+/// representation.
+impl FromIterator<PathData> for PathData {
+    fn from_iter<T: IntoIterator<Item = PathData>>(paths: T) -> Self {
+        let mut events = Vec::new();
+        for it in paths.into_iter().filter_map(PathData::iter) {
+            events.extend(it.iter());
+        }
+        Self::from_lyon_events(events.into_iter())
+    }
+}
+
+/// Error returned by [`PathData::from_svg`] when the SVG path commands cannot be parsed.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PathParseError {
+    /// The byte offset in the source string of the character at which the error was detected.
+    pub offset: usize,
+    message: std::string::String,
+}
+
+impl core::fmt::Display for PathParseError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{} (at offset {})", self.message, self.offset)
+    }
+}
+
+impl std::error::Error for PathParseError {}
+
+/// Error returned by [`PathData::validate`] when the events of a [`PathData::Events`] path
+/// don't match its coordinates.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PathError {
+    /// The event at `event_index` needs more coordinates than are left after the previous events.
+    MissingCoordinates {
+        /// The index of the offending event.
+        event_index: usize,
+    },
+    /// There are coordinates left that are not used by any of the events.
+    UnusedCoordinates {
+        /// The number of coordinates that are left.
+        count: usize,
+    },
+}
+
+impl core::fmt::Display for PathError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            PathError::MissingCoordinates { event_index } => {
+                write!(f, "missing coordinates for the path event at index {event_index}")
+            }
+            PathError::UnusedCoordinates { count } => {
+                write!(f, "{count} path coordinates are not used by any event")
+            }
+        }
+    }
+}
+
+impl std::error::Error for PathError {}
+
+/// Converts a line and column, as counted by lyon's path parser, to a byte offset in `source`.
+fn byte_offset(source: &str, line: i32, column: i32) -> usize {
+    // lyon counts from zero and puts a newline at column -1 of the line it starts, except
+    // when the source starts with a newline.
+    let (mut current_line, mut current_column) = (0, -1);
+    for (index, ch) in source.char_indices() {
+        if ch == '\n' {
+            current_line += 1;
+            current_column = -1;
+        } else {
+            current_column += 1;
+        }
+        if index == 0 && ch == '\n' {
+            current_column = 0;
+        }
+        if (current_line, current_column) == (line, column) {
+            return index;
+        }
+    }
+    source.len()
+}
+
 impl PathData {
+    /// Parses a string of SVG path commands, such as `M 0 0 L 10 0 A 5 5 0 0 1 10 10 Z`, and
+    /// returns the path in the [`PathData::Events`] representation.
+    ///
+    /// All commands of the SVG path syntax are supported, including the relative variants,
+    /// implicit line-to commands following a move-to and the smooth `S`/`T` curves. Arcs are
+    /// converted to Bézier curves.
+    pub fn from_svg(commands: &str) -> Result<Self, PathParseError> {
+        use lyon_extra::parser::ParseError;
+
+        let mut builder = lyon_path::Path::builder();
+        lyon_extra::parser::PathParser::new()
+            .parse(
+                &lyon_extra::parser::ParserOptions::DEFAULT,
+                &mut lyon_extra::parser::Source::new(commands.chars()),
+                &mut builder,
+            )
+            .map_err(|err| {
+                let offset = match &err {
+                    ParseError::Number { line, column, .. }
+                    | ParseError::Flag { line, column, .. }
+                    | ParseError::Command { line, column, .. }
+                    | ParseError::MissingMoveTo { line, column, .. } => {
+                        byte_offset(commands, *line, *column)
+                    }
+                    _ => commands.len(),
+                };
+                // Strip lyon's line/column prefix, as the offset is reported separately.
+                let message = err.to_string();
+                let message = message.split_once(": ").map_or(&*message, |(_, m)| m).into();
+                PathParseError { offset, message }
+            })?;
+        Ok(Self::from_lyon_events(builder.build().iter()))
+    }
+
+    /// Checks that the events of a [`PathData::Events`] path match its coordinates: `Begin`
+    /// consumes one coordinate, `Line` two, `Quadratic` three, `Cubic` four and the end events
+    /// none. Paths in the other representations are always valid.
+    ///
+    /// Iterating over an invalid path stops at the first event that lacks coordinates, without
+    /// reporting an error. Call this function once when constructing the events by hand, to
+    /// report the error instead.
+    pub fn validate(&self) -> Result<(), PathError> {
+        let (events, coordinates) = match self {
+            PathData::Events(events, coordinates) => (events, coordinates),
+            _ => return Ok(()),
+        };
+        let mut count = 0;
+        for (event_index, event) in events.iter().enumerate() {
+            count += match event {
+                PathEvent::Begin => 1,
+                PathEvent::Line => 2,
+                PathEvent::Quadratic => 3,
+                PathEvent::Cubic => 4,
+                PathEvent::EndOpen | PathEvent::EndClosed => 0,
+            };
+            if count > coordinates.len() {
+                return Err(PathError::MissingCoordinates { event_index });
+            }
+        }
+        if count < coordinates.len() {
+            return Err(PathError::UnusedCoordinates { count: coordinates.len() - count });
+        }
+        Ok(())
+    }
+
+    /// Returns the same path, traversed in the opposite direction. Sub-paths are reversed
+    /// and their order is reversed, too. Closed sub-paths remain closed.
+    ///
+    /// The resulting path uses the [`PathData::Events`] representation.
+    pub fn reversed(&self) -> Self {
+        match self.clone().iter() {
+            Some(it) => {
+                let path: lyon_path::Path = it.iter().collect();
+                Self::from_lyon_events(path.reversed())
+            }
+            None => PathData::None,
+        }
+    }
+
+    /// Appends the sub-paths of `other` to this path. The sub-paths of both paths remain
+    /// separate, so they are filled independently as defined by the fill rule.
+    ///
+    /// The resulting path uses the [`PathData::Events`] representation.
+    pub fn append(&mut self, other: &PathData) {
+        *self = [self.clone(), other.clone()].into_iter().collect();
+    }
+
+    fn from_lyon_events(lyon_events: impl Iterator<Item = lyon_path::PathEvent>) -> Self {
+        use lyon_path::Event;
+
+        let mut events = crate::SharedVector::default();
+        let mut coordinates = crate::SharedVector::default();
+        for event in lyon_events {
+            match event {
+                Event::Begin { at } => {
+                    events.push(PathEvent::Begin);
+                    coordinates.push(at);
+                }
+                Event::Line { from, to } => {
+                    events.push(PathEvent::Line);
+                    coordinates.extend([from, to]);
+                }
+                Event::Quadratic { from, ctrl, to } => {
+                    events.push(PathEvent::Quadratic);
+                    coordinates.extend([from, ctrl, to]);
+                }
+                Event::Cubic { from, ctrl1, ctrl2, to } => {
+                    events.push(PathEvent::Cubic);
+                    coordinates.extend([from, ctrl1, ctrl2, to]);
+                }
+                Event::End { close, .. } => {
+                    events.push(if close { PathEvent::EndClosed } else { PathEvent::EndOpen });
+                }
+            }
+        }
+        if events.is_empty() {
+            PathData::None
+        } else {
+            PathData::Events(events, coordinates)
+        }
+    }
+
     /// This function returns an iterator that allows traversing the path by means of lyon events.
     pub fn iter(self) -> Option<PathDataIterator> {
         PathDataIterator {
@@ -315,6 +662,134 @@ pub fn iter(self) -> Option<PathDataIterator> {
         .into()
     }
 
+    /// Returns the smallest rectangle that contains the entire path, in the coordinate
+    /// system of the path. An empty path results in an empty rectangle at the origin.
+    pub fn bounding_box(&self) -> crate::graphics::Rect {
+        let bounding_box = match self.clone().iter() {
+            Some(it) => lyon_algorithms::aabb::bounding_box(it.iter()),
+            None => return Default::default(),
+        };
+        if bounding_box.is_negative() {
+            return Default::default();
+        }
+        bounding_box.to_rect().cast()
+    }
+
+    /// Returns the length of the path. Curves are approximated with line segments that deviate
+    /// from the curve by at most `tolerance`. Closed sub-paths include their closing segment.
+    pub fn length(&self, tolerance: f32) -> f32 {
+        self.flattened_segments(tolerance).map(|(from, to)| (to - from).length()).sum()
+    }
+
+    /// Returns the point at the given distance along the path, together with the direction of the
+    /// path at that point as a unit vector. Curves are approximated as in [`Self::length`].
+    ///
+    /// Returns None if the distance is negative or larger than the length of the path.
+    pub fn point_at_length(
+        &self,
+        distance: f32,
+        tolerance: f32,
+    ) -> Option<(crate::graphics::Point, euclid::default::Vector2D<f32>)> {
+        if distance < 0. {
+            return None;
+        }
+        let mut remaining = distance;
+        let mut end = None;
+        for (from, to) in self.flattened_segments(tolerance) {
+            let length = (to - from).length();
+            if length == 0. {
+                continue;
+            }
+            let tangent = (to - from) / length;
+            if remaining <= length {
+                return Some(((from + tangent * remaining).cast(), tangent));
+            }
+            remaining -= length;
+            end = Some((to.cast(), tangent));
+        }
+        // Accept the end of the path despite rounding errors when summing up the segments.
+        end.filter(|_| remaining <= tolerance)
+    }
+
+    /// Returns true if the point is inside the area of the path, as decided by the given
+    /// fill rule. Open sub-paths are treated as if they were closed, and points that lie on
+    /// the outline of the path are considered to be inside.
+    pub fn contains(
+        &self,
+        point: crate::graphics::Point,
+        fill_rule: crate::items::FillRule,
+    ) -> bool {
+        // Curves are flattened with a tolerance well below the size of a physical pixel.
+        const TOLERANCE: f32 = 0.01;
+        const ON_EDGE_DISTANCE: f32 = 0.001;
+
+        let p: lyon_path::math::Point = point.cast();
+        let mut winding = 0;
+        for (from, to) in self.segments(TOLERANCE, true) {
+            let segment = lyon_geom::LineSegment { from, to };
+            if segment.distance_to_point(p) <= ON_EDGE_DISTANCE {
+                return true;
+            }
+            // Count the crossings of a horizontal ray from p towards positive x, with the
+            // segment's direction and the side of p relative to it giving the sign.
+            let side = (to - from).cross(p - from);
+            if from.y <= p.y {
+                if to.y > p.y && side > 0. {
+                    winding += 1;
+                }
+            } else if to.y <= p.y && side < 0. {
+                winding -= 1;
+            }
+        }
+        match fill_rule {
+            crate::items::FillRule::Nonzero => winding != 0,
+            crate::items::FillRule::Evenodd => winding % 2 != 0,
+        }
+    }
+
+    /// Returns the straight line segments, as `(from, to)` pairs, that approximate the path.
+    /// Curves and arcs are flattened with the given `tolerance`, as described in
+    /// [`PathDataIterator::flattened`]. Closed sub-paths include their closing segment.
+    ///
+    /// This is useful to measure a path or to draw it with custom code that only handles lines.
+    ///
+    /// ```
+    /// # use i_slint_core::graphics::PathData;
+    /// let triangle = PathData::from_svg("M 0 0 L 3 0 L 3 4 Z").unwrap();
+    /// let lengths: Vec<f32> =
+    ///     triangle.flattened_segments(0.1).map(|(from, to)| (to - from).length()).collect();
+    /// assert_eq!(lengths, [3., 4., 5.]);
+    /// ```
+    pub fn flattened_segments(
+        &self,
+        tolerance: f32,
+    ) -> impl Iterator<Item = (lyon_path::math::Point, lyon_path::math::Point)> {
+        self.segments(tolerance, false).into_iter()
+    }
+
+    /// Approximates the path with straight line segments, including the ones closing sub-paths.
+    /// If `close_open_subpaths` is true, open sub-paths are closed, too.
+    fn segments(
+        &self,
+        tolerance: f32,
+        close_open_subpaths: bool,
+    ) -> Vec<(lyon_path::math::Point, lyon_path::math::Point)> {
+        use lyon_path::Event;
+
+        let it = match self.clone().iter() {
+            Some(it) => it,
+            None => return Vec::new(),
+        };
+        let segments = it.flattened(tolerance).filter_map(|event| match event {
+            Event::Line { from, to } => Some((from, to)),
+            Event::End { last, first, close } if close || close_open_subpaths => {
+                Some((last, first))
+            }
+            _ => None,
+        });
+        segments.collect()
+    }
+
     fn build_path(element_it: core::slice::Iter<PathElement>) -> lyon_path::Path {
         use lyon_geom::SvgArc;
         use lyon_path::math::{Angle, Point, Vector};
@@ -427,3 +902,474 @@ pub(crate) mod ffi {
         core::ptr::write(out_coordinates as *mut crate::SharedVector<Point>, coordinates);
     }
 }
+
+#[test]
+fn test_bounding_box() {
+    use crate::graphics::Rect;
+    use euclid::{point2, size2};
+
+    assert_eq!(PathData::None.bounding_box(), Rect::default());
+    assert_eq!(PathData::Commands("".into()).bounding_box(), Rect::default());
+
+    let elements = PathData::Elements(crate::SharedVector::from_slice(&[
+        PathMoveTo { x: 10., y: 20. }.into(),
+        PathLineTo { x: 30., y: 5. }.into(),
+        PathLineTo { x: -5., y: 15. }.into(),
+        PathElement::Close,
+    ]));
+    let expected = Rect::new(point2(-5., 5.), size2(35., 15.));
+    assert_eq!(elements.bounding_box(), expected);
+
+    let events = PathData::Events(
+        crate::SharedVector::from_slice(&[
+            PathEvent::Begin,
+            PathEvent::Line,
+            PathEvent::Line,
+            PathEvent::EndClosed,
+        ]),
+        crate::SharedVector::from_slice(&[
+            point2(10., 20.),
+            point2(10., 20.),
+            point2(30., 5.),
+            point2(30., 5.),
+            point2(-5., 15.),
+        ]),
+    );
+    assert_eq!(events.bounding_box(), expected);
+
+    assert_eq!(PathData::Commands("M 10 20 L 30 5 L -5 15 Z".into()).bounding_box(), expected);
+
+    // The bounding box of a curve is tight and doesn't include the control points
+    let curve = PathData::Commands("M 0 0 Q 50 100 100 0".into());
+    assert_eq!(curve.bounding_box(), Rect::new(point2(0., 0.), size2(100., 50.)));
+}
+
+#[test]
+fn test_from_svg() {
+    use euclid::point2;
+    use lyon_path::Event;
+
+    let events = |path: PathData| path.iter().unwrap().iter().collect::<Vec<_>>();
+
+    // Relative commands, horizontal/vertical lines and implicit line-tos after a move-to
+    assert_eq!(
+        events(PathData::from_svg("m 10 10 20 0 v 10 h -20 z M 50,50 L 60 60").unwrap()),
+        events(PathData::from_svg("M 10 10 L 30 10 L 30 20 L 10 20 Z M 50 50 L 60 60").unwrap())
+    );
+
+    let path = PathData::from_svg("M 0 0 L 10 0 L 10 10 Z M 20 20 L 30 20 L 30 30 Z").unwrap();
+    assert!(matches!(path, PathData::Events(..)));
+    let ends = events(path)
+        .into_iter()
+        .filter_map(|event| match event {
+            Event::End { first, close, .. } => Some((first, close)),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(ends, [(point2(0., 0.), true), (point2(20., 20.), true)]);
+
+    // The smooth variants reflect the control point of the previous curve
+    assert_eq!(
+        events(PathData::from_svg("M 0 0 C 0 10 10 10 10 0 S 20 -10 20 0").unwrap()),
+        events(PathData::from_svg("M 0 0 C 0 10 10 10 10 0 C 10 -10 20 -10 20 0").unwrap())
+    );
+    assert_eq!(
+        events(PathData::from_svg("M 0 0 Q 5 10 10 0 T 20 0").unwrap()),
+        events(PathData::from_svg("M 0 0 Q 5 10 10 0 Q 15 -10 20 0").unwrap())
+    );
+    assert!(matches!(
+        PathData::from_svg("M 0 0 A 10 10 0 0 1 20 0").unwrap(),
+        PathData::Events(events, _) if events.contains(&PathEvent::Cubic) || events.contains(&PathEvent::Quadratic)
+    ));
+
+    assert_eq!(PathData::from_svg("").unwrap(), PathData::None);
+
+    let err = PathData::from_svg("M 0 0 L 10 X").unwrap_err();
+    assert_eq!(err.offset, 11);
+    let err = PathData::from_svg("M 0 0\n  L 10 10\n  A 5 5 0 2 1 0 0").unwrap_err();
+    assert_eq!(err.offset, 26);
+    let err = PathData::from_svg("M 0 0 K 10 10").unwrap_err();
+    assert_eq!(err.offset, 6);
+    assert_eq!(err.to_string(), "Invalid command 'K'. (at offset 6)");
+}
+
+#[test]
+fn test_elements_to_events() {
+    use euclid::point2;
+    use lyon_path::Event;
+
+    let path = PathData::Elements(crate::SharedVector::from_slice(&[
+        PathMoveTo { x: 0., y: 0. }.into(),
+        PathQuadraticTo { x: 10., y: 0., control_x: 5., control_y: 10. }.into(),
+        PathElement::Close,
+        PathMoveTo { x: 20., y: 20. }.into(),
+        PathCubicTo {
+            x: 30.,
+            y: 20.,
+            control_1_x: 20.,
+            control_1_y: 30.,
+            control_2_x: 30.,
+            control_2_y: 30.,
+        }
+        .into(),
+    ]));
+    let events = path.iter().unwrap().iter().collect::<Vec<_>>();
+    assert_eq!(
+        events,
+        [
+            Event::Begin { at: point2(0., 0.) },
+            Event::Quadratic { from: point2(0., 0.), ctrl: point2(5., 10.), to: point2(10., 0.) },
+            Event::End { last: point2(10., 0.), first: point2(0., 0.), close: true },
+            Event::Begin { at: point2(20., 20.) },
+            Event::Cubic {
+                from: point2(20., 20.),
+                ctrl1: point2(20., 30.),
+                ctrl2: point2(30., 30.),
+                to: point2(30., 20.),
+            },
+            Event::End { last: point2(30., 20.), first: point2(20., 20.), close: false },
+        ]
+    );
+}
+
+#[test]
+fn test_length() {
+    use euclid::{point2, vec2};
+
+    assert_eq!(PathData::None.length(0.1), 0.);
+    assert_eq!(PathData::None.point_at_length(0., 0.1), None);
+
+    let square = PathData::from_svg("M 0 0 h 10 v 10 h -10 z").unwrap();
+    assert_eq!(square.length(0.1), 40.);
+    assert_eq!(square.point_at_length(0., 0.1), Some((point2(0., 0.), vec2(1., 0.))));
+    assert_eq!(square.point_at_length(15., 0.1), Some((point2(10., 5.), vec2(0., 1.))));
+    // The closing segment is part of the path
+    assert_eq!(square.point_at_length(35., 0.1), Some((point2(0., 5.), vec2(0., -1.))));
+    assert_eq!(square.point_at_length(40., 0.1), Some((point2(0., 0.), vec2(0., -1.))));
+    assert_eq!(square.point_at_length(40.5, 0.1), None);
+    assert_eq!(square.point_at_length(-1., 0.1), None);
+
+    let open = PathData::from_svg("M 0 0 h 10 v 10 h -10").unwrap();
+    assert_eq!(open.length(0.1), 30.);
+
+    let half_circle = PathData::from_svg("M 0 0 A 10 10 0 0 1 20 0").unwrap();
+    assert!((half_circle.length(0.01) - core::f32::consts::PI * 10.).abs() < 0.05);
+    let (top, tangent) = half_circle.point_at_length(core::f32::consts::PI * 5., 0.01).unwrap();
+    assert!((top - point2(10., -10.)).length() < 0.05, "{top:?}");
+    assert!((tangent - vec2(1., 0.)).length() < 0.05, "{tangent:?}");
+}
+
+#[test]
+fn test_reversed() {
+    use euclid::point2;
+    use lyon_path::Event;
+
+    let events = |path: PathData| path.iter().unwrap().iter().collect::<Vec<_>>();
+
+    assert_eq!(PathData::None.reversed(), PathData::None);
+
+    let open = PathData::from_svg("M 0 0 L 10 0 Q 20 0 20 10").unwrap();
+    assert_eq!(
+        events(open.reversed()),
+        [
+            Event::Begin { at: point2(20., 10.) },
+            Event::Quadratic { from: point2(20., 10.), ctrl: point2(20., 0.), to: point2(10., 0.) },
+            Event::Line { from: point2(10., 0.), to: point2(0., 0.) },
+            Event::End { last: point2(0., 0.), first: point2(20., 10.), close: false },
+        ]
+    );
+
+    let closed = PathData::from_svg("M 0 0 h 10 v 10 z").unwrap();
+    let reversed = events(closed.reversed());
+    assert_eq!(reversed.len(), 4);
+    assert!(matches!(reversed.last(), Some(Event::End { close: true, .. })));
+    assert_eq!(events(closed.reversed().reversed()), events(closed.clone()));
+    assert_eq!(closed.reversed().length(0.1), closed.length(0.1));
+
+    let elements = PathData::Elements(crate::SharedVector::from_slice(&[
+        PathMoveTo { x: 0., y: 0. }.into(),
+        PathLineTo { x: 10., y: 0. }.into(),
+    ]));
+    assert_eq!(
+        events(elements.reversed()),
+        [
+            Event::Begin { at: point2(10., 0.) },
+            Event::Line { from: point2(10., 0.), to: point2(0., 0.) },
+            Event::End { last: point2(0., 0.), first: point2(10., 0.), close: false },
+        ]
+    );
+}
+
+#[test]
+fn test_fit_with_style() {
+    use lyon_algorithms::fit::FitStyle;
+    use lyon_path::math::{point, Box2D};
+
+    let path = PathData::from_svg("M 0 0 L 10 20").unwrap();
+    let fitted_box = |style| {
+        let mut it = path.clone().iter().unwrap();
+        it.fit_with_style(100., 100., None, style);
+        lyon_algorithms::aabb::bounding_box(it.iter())
+    };
+    let mut it = path.clone().iter().unwrap();
+    it.fit(100., 100., None);
+    let default_box = lyon_algorithms::aabb::bounding_box(it.iter());
+
+    assert_eq!(fitted_box(FitStyle::Min), Box2D::new(point(25., 0.), point(75., 100.)));
+    assert_eq!(default_box, fitted_box(FitStyle::Min));
+    assert_eq!(fitted_box(FitStyle::Vertical), fitted_box(FitStyle::Min));
+    assert_eq!(fitted_box(FitStyle::Max), Box2D::new(point(0., -50.), point(100., 150.)));
+    assert_eq!(fitted_box(FitStyle::Horizontal), fitted_box(FitStyle::Max));
+    assert_eq!(fitted_box(FitStyle::Stretch), Box2D::new(point(0., 0.), point(100., 100.)));
+}
+
+#[test]
+fn test_transform() {
+    use lyon_path::math::{point, Angle, Box2D, Transform};
+
+    let path = PathData::from_svg("M 0 0 L 10 20").unwrap();
+    let transformed_box = |fit: bool, transform: Transform| {
+        let mut it = path.clone().iter().unwrap();
+        if fit {
+            it.fit(100., 100., None);
+        }
+        it.transform(transform);
+        lyon_algorithms::aabb::bounding_box(it.iter())
+    };
+
+    assert_eq!(
+        transformed_box(false, Transform::scale(-1., 1.)),
+        Box2D::new(point(-10., 0.), point(0., 20.))
+    );
+    let rotated = transformed_box(false, Transform::rotation(Angle::degrees(90.)));
+    assert!(rotated.min.distance_to(point(-20., 0.)) < 0.001, "{rotated:?}");
+    assert!(rotated.max.distance_to(point(0., 10.)) < 0.001, "{rotated:?}");
+    // The transformation is composed with the one from fitting the path
+    assert_eq!(
+        transformed_box(true, Transform::translation(5., 5.)),
+        Box2D::new(point(30., 5.), point(80., 105.))
+    );
+}
+
+#[test]
+fn test_contains() {
+    use crate::items::FillRule;
+    use euclid::point2;
+
+    let square = PathData::from_svg("M 0 0 h 10 v 10 h -10 z").unwrap();
+    for fill_rule in [FillRule::Nonzero, FillRule::Evenodd] {
+        assert!(square.contains(point2(5., 5.), fill_rule));
+        assert!(!square.contains(point2(15., 5.), fill_rule));
+        assert!(!square.contains(point2(5., -0.5), fill_rule));
+        // Points on the outline are inside
+        assert!(square.contains(point2(10., 5.), fill_rule));
+        assert!(square.contains(point2(0., 0.), fill_rule));
+    }
+
+    // Two nested squares with the same direction: the inner one is a hole only with evenodd
+    let nested = PathData::from_svg("M 0 0 h 10 v 10 h -10 z M 2 2 h 6 v 6 h -6 z").unwrap();
+    assert!(nested.contains(point2(5., 5.), FillRule::Nonzero));
+    assert!(!nested.contains(point2(5., 5.), FillRule::Evenodd));
+    assert!(nested.contains(point2(1., 5.), FillRule::Evenodd));
+    // With opposite directions, the inner one is a hole with both rules
+    let nested = PathData::from_svg("M 0 0 h 10 v 10 h -10 z M 2 2 v 6 h 6 v -6 z").unwrap();
+    assert!(!nested.contains(point2(5., 5.), FillRule::Nonzero));
+
+    // Open sub-paths are implicitly closed
+    let triangle = PathData::from_svg("M 0 0 L 10 0 L 0 10").unwrap();
+    assert!(triangle.contains(point2(2., 2.), FillRule::Nonzero));
+    assert!(!triangle.contains(point2(8., 8.), FillRule::Nonzero));
+
+    let circle = PathData::from_svg("M 0 10 A 10 10 0 0 1 20 10 A 10 10 0 0 1 0 10 Z").unwrap();
+    assert!(circle.contains(point2(10., 10.), FillRule::Nonzero));
+    assert!(circle.contains(point2(16.5, 16.5), FillRule::Nonzero));
+    assert!(!circle.contains(point2(17.5, 17.5), FillRule::Nonzero));
+
+    assert!(!PathData::None.contains(point2(0., 0.), FillRule::Nonzero));
+}
+
+#[test]
+fn test_flattened() {
+    use lyon_path::Event;
+
+    let it = PathData::from_svg("M 0 10 A 10 10 0 0 1 20 10 L 20 20").unwrap().iter().unwrap();
+    let line_count = |tolerance| {
+        it.flattened(tolerance)
+            .map(|event| {
+                assert!(matches!(
+                    event,
+                    Event::Begin { .. } | Event::Line { .. } | Event::End { .. }
+                ));
+                matches!(event, Event::Line { .. }) as usize
+            })
+            .sum::<usize>()
+    };
+    let coarse = line_count(1.);
+    let fine = line_count(PathDataIterator::DEFAULT_TOLERANCE / 4.);
+    assert!(coarse > 2);
+    assert!(fine > coarse);
+}
+
+#[test]
+fn test_flattened_segments() {
+    use euclid::point2;
+
+    assert_eq!(PathData::None.flattened_segments(0.1).count(), 0);
+
+    let closed = PathData::from_svg("M 0 0 h 10 v 10 z").unwrap();
+    assert_eq!(
+        closed.flattened_segments(0.1).collect::<Vec<_>>(),
+        [
+            (point2(0., 0.), point2(10., 0.)),
+            (point2(10., 0.), point2(10., 10.)),
+            (point2(10., 10.), point2(0., 0.)),
+        ]
+    );
+    // Open sub-paths are not closed
+    let open = PathData::from_svg("M 0 0 h 10 v 10").unwrap();
+    assert_eq!(open.flattened_segments(0.1).count(), 2);
+
+    let half_circle = PathData::from_svg("M 0 0 A 10 10 0 0 1 20 0").unwrap();
+    let segments = half_circle.flattened_segments(0.01).collect::<Vec<_>>();
+    assert!(segments.len() > half_circle.flattened_segments(1.).count());
+    assert_eq!(segments.first().unwrap().0, point2(0., 0.));
+    assert!((segments.last().unwrap().1 - point2(20., 0.)).length() < 0.001);
+    assert!(segments.windows(2).all(|w| w[0].1 == w[1].0));
+    for (from, to) in segments {
+        // All points lie close to the circle, which is approximated with Bézier curves
+        for p in [from, to, from.lerp(to, 0.5)] {
+            assert!(((p - point2(10., 0.)).length() - 10.).abs() < 0.05, "{p:?}");
+        }
+    }
+}
+
+#[test]
+fn test_validate() {
+    use euclid::point2;
+
+    let events = |events: &[PathEvent], coordinate_count: usize| {
+        PathData::Events(events.into(), vec![point2(0., 0.); coordinate_count].as_slice().into())
+    };
+    let triangle = [PathEvent::Begin, PathEvent::Line, PathEvent::Quadratic, PathEvent::EndClosed];
+    assert_eq!(events(&triangle, 6).validate(), Ok(()));
+    assert!(events(&triangle, 6).iter().is_some());
+    assert_eq!(
+        events(&triangle, 5).validate(),
+        Err(PathError::MissingCoordinates { event_index: 2 })
+    );
+    // The iteration stops at the invalid event
+    assert_eq!(events(&triangle, 5).iter().unwrap().iter().count(), 2);
+    assert_eq!(
+        events(&[PathEvent::Cubic], 0).validate(),
+        Err(PathError::MissingCoordinates { event_index: 0 })
+    );
+    assert_eq!(events(&triangle, 8).validate(), Err(PathError::UnusedCoordinates { count: 2 }));
+    assert_eq!(events(&[], 0).validate(), Ok(()));
+
+    assert_eq!(PathData::None.validate(), Ok(()));
+    assert_eq!(PathData::from_svg("M 0 0 C 1 1 2 2 3 3 z").unwrap().validate(), Ok(()));
+}
+
+#[test]
+fn test_append() {
+    use crate::items::FillRule;
+    use euclid::point2;
+
+    let events = |path: PathData| path.iter().unwrap().iter().collect::<Vec<_>>();
+
+    let mut path = PathData::None;
+    path.append(&PathData::None);
+    assert_eq!(path, PathData::None);
+
+    path.append(&PathData::Elements(crate::SharedVector::from_slice(&[
+        PathMoveTo { x: 0., y: 0. }.into(),
+        PathLineTo { x: 10., y: 0. }.into(),
+        PathLineTo { x: 10., y: 10. }.into(),
+    ])));
+    path.append(&PathData::Commands("M 20 0 h 10 v 10 z".into()));
+    assert_eq!(
+        events(path.clone()),
+        events(PathData::from_svg("M 0 0 L 10 0 L 10 10 M 20 0 h 10 v 10 z").unwrap())
+    );
+    // Each sub-path is closed separately for filling
+    assert!(!path.contains(point2(15., 1.), FillRule::Nonzero));
+    assert!(path.contains(point2(29., 1.), FillRule::Nonzero));
+
+    let merged: PathData = [
+        PathData::from_svg("M 0 0 L 10 0").unwrap(),
+        PathData::None,
+        PathData::from_svg("M 0 5 L 10 5").unwrap(),
+    ]
+    .into_iter()
+    .collect();
+    assert_eq!(events(merged), events(PathData::from_svg("M 0 0 L 10 0 M 0 5 L 10 5").unwrap()));
+}
+
+#[test]
+fn test_dashed() {
+    use lyon_path::math::{point, Point};
+    use lyon_path::Event;
+
+    fn dashes(events: &[lyon_path::PathEvent]) -> Vec<(Point, Point)> {
+        let mut result = Vec::new();
+        let mut start = None;
+        for event in events {
+            match event {
+                Event::Begin { at } => start = Some(*at),
+                Event::End { last, first, close } => {
+                    assert!(!close);
+                    assert_eq!(Some(*first), start.take());
+                    result.push((*first, *last));
+                }
+                _ => {}
+            }
+        }
+        result
+    }
+
+    let line = PathData::from_svg("M 0 0 L 10 0").unwrap().iter().unwrap();
+    assert_eq!(line.dashed(&[], 0., 0.1), line.iter().collect::<Vec<_>>());
+    assert_eq!(line.dashed(&[0., 0.], 0., 0.1), line.iter().collect::<Vec<_>>());
+    assert_eq!(line.dashed(&[2., -3.], 0., 0.1), line.iter().collect::<Vec<_>>());
+
+    assert_eq!(
+        dashes(&line.dashed(&[2., 3.], 0., 0.1)),
+        [(point(0., 0.), point(2., 0.)), (point(5., 0.), point(7., 0.))]
+    );
+    assert_eq!(
+        dashes(&line.dashed(&[2., 3.], 1., 0.1)),
+        [
+            (point(0., 0.), point(1., 0.)),
+            (point(4., 0.), point(6., 0.)),
+            (point(9., 0.), point(10., 0.))
+        ]
+    );
+    // A negative offset shifts the pattern the other way
+    assert_eq!(line.dashed(&[2., 3.], -4., 0.1), line.dashed(&[2., 3.], 1., 0.1));
+    assert_eq!(
+        dashes(&line.dashed(&[2., 3.], -1., 0.1)),
+        [(point(1., 0.), point(3., 0.)), (point(6., 0.), point(8., 0.))]
+    );
+    // Odd arrays are repeated: dash 4, gap 1, dash 1, gap 4
+    assert_eq!(
+        dashes(&line.dashed(&[4., 1., 1.], 0., 0.1)),
+        [(point(0., 0.), point(4., 0.)), (point(5., 0.), point(6., 0.))]
+    );
+
+    // Dashes continue around corners and along the closing segment
+    let square = PathData::from_svg("M 0 0 h 10 v 10 h -10 z").unwrap().iter().unwrap();
+    let events = square.dashed(&[15., 5.], 0., 0.1);
+    assert_eq!(
+        dashes(&events),
+        [(point(0., 0.), point(10., 5.)), (point(10., 10.), point(0., 5.))]
+    );
+    assert!(events.contains(&Event::Line { from: point(0., 0.), to: point(10., 0.) }));
+    assert!(events.contains(&Event::Line { from: point(10., 0.), to: point(10., 5.) }));
+
+    // The pattern restarts with each sub-path
+    let two_lines = PathData::from_svg("M 0 0 h 3 M 0 5 h 3").unwrap().iter().unwrap();
+    assert_eq!(
+        dashes(&two_lines.dashed(&[2., 2.], 0., 0.1)),
+        [(point(0., 0.), point(2., 0.)), (point(0., 5.), point(2., 5.))]
+    );
+}
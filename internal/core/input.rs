@@ -7,6 +7,7 @@
 
 use crate::item_tree::{ItemRc, ItemWeak, VisitChildrenResult};
 pub use crate::items::PointerEventButton;
+pub use crate::items::TouchPhase;
 use crate::items::{ItemRef, TextCursorDirection};
 use crate::lengths::{LogicalPoint, LogicalVector};
 use crate::timers::Timer;
@@ -19,6 +20,12 @@
 use core::cell::Cell;
 use core::pin::Pin;
 
+/// The amount of logical pixels that one line of a mouse wheel scroll moves the content.
+///
+/// Backends use it to convert the wheel deltas that the windowing system reports in lines
+/// (or in notches of the wheel) to the pixel deltas of [`MouseEvent::Wheel`].
+pub const WHEEL_PIXELS_PER_LINE: f32 = 60.;
+
 /// A mouse or touch event
 ///
 /// The only difference with [`crate::platform::WindowEvent`] us that it uses untyped `Point`
@@ -38,7 +45,8 @@ pub enum MouseEvent {
     /// `click_count` represents the current number of clicks.
     Released { position: LogicalPoint, button: PointerEventButton, click_count: u8 },
     /// The position of the pointer has changed
-    Moved { position: LogicalPoint },
+    /// `buttons` are the buttons that are held down while the pointer moves, if any.
+    Moved { position: LogicalPoint, buttons: PointerEventButtons },
     /// Wheel was operated.
     /// `pos` is the position of the mouse when the event happens.
     /// `delta_x` is the amount of pixels to scroll in horizontal direction,
@@ -46,6 +54,10 @@ pub enum MouseEvent {
     Wheel { position: LogicalPoint, delta_x: f32, delta_y: f32 },
     /// The mouse exited the item or component
     Exit,
+    /// A finger touched, moved on, or left a touch screen.
+    /// `id` identifies the touch point among the ones that are down at the same time.
+    /// `phase` describes what happened to the touch point.
+    Touch { id: u64, phase: TouchPhase, position: LogicalPoint },
 }
 
 impl MouseEvent {
@@ -54,9 +66,10 @@ pub fn position(&self) -> Option<LogicalPoint> {
         match self {
             MouseEvent::Pressed { position, .. } => Some(*position),
             MouseEvent::Released { position, .. } => Some(*position),
-            MouseEvent::Moved { position } => Some(*position),
+            MouseEvent::Moved { position, .. } => Some(*position),
             MouseEvent::Wheel { position, .. } => Some(*position),
             MouseEvent::Exit => None,
+            MouseEvent::Touch { position, .. } => Some(*position),
         }
     }
 
@@ -65,9 +78,10 @@ pub fn translate(&mut self, vec: LogicalVector) {
         let pos = match self {
             MouseEvent::Pressed { position, .. } => Some(position),
             MouseEvent::Released { position, .. } => Some(position),
-            MouseEvent::Moved { position } => Some(position),
+            MouseEvent::Moved { position, .. } => Some(position),
             MouseEvent::Wheel { position, .. } => Some(position),
             MouseEvent::Exit => None,
+            MouseEvent::Touch { position, .. } => Some(position),
         };
         if let Some(pos) = pos {
             *pos += vec;
@@ -75,6 +89,43 @@ pub fn translate(&mut self, vec: LogicalVector) {
     }
 }
 
+/// The set of buttons that are held down while the pointer moves, reported with
+/// [`MouseEvent::Moved`]. All the buttons that are neither left, right nor middle share
+/// [`PointerEventButton::Other`].
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PointerEventButtons(u8);
+
+impl PointerEventButtons {
+    /// Returns this set with the `button` added.
+    #[must_use]
+    pub fn with(self, button: PointerEventButton) -> Self {
+        Self(self.0 | 1 << button as u8)
+    }
+
+    /// Returns this set with the `button` removed.
+    #[must_use]
+    pub fn without(self, button: PointerEventButton) -> Self {
+        Self(self.0 & !(1 << button as u8))
+    }
+
+    /// Returns true if the `button` is held down.
+    pub fn contains(self, button: PointerEventButton) -> bool {
+        self.0 & 1 << button as u8 != 0
+    }
+
+    /// Returns true if no button is held down.
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl FromIterator<PointerEventButton> for PointerEventButtons {
+    fn from_iter<T: IntoIterator<Item = PointerEventButton>>(iter: T) -> Self {
+        iter.into_iter().fold(Self::default(), Self::with)
+    }
+}
+
 /// This value is returned by the `input_event` function of an Item
 /// to notify the run-time about how the event was handled and
 /// what the next steps are.
@@ -88,7 +139,10 @@ pub enum InputEventResult {
     /// The event was ignored.
     #[default]
     EventIgnored,
-    /// All further mouse event need to be sent to this item or component
+    /// The event was accepted, and all further mouse events need to be sent to this item, even if
+    /// they happen outside of its geometry. This is how an item captures the pointer, typically
+    /// when it is pressed. The grab is released as soon as the item returns any other value for
+    /// one of these events, usually for the release event.
     GrabMouse,
 }
 
@@ -626,10 +680,14 @@ fn handle_mouse_grab(
     if input_result != InputEventResult::GrabMouse {
         mouse_input_state.grabbed = false;
         // Return a move event so that the new position can be registered properly
+        let buttons = match mouse_event {
+            MouseEvent::Moved { buttons, .. } => buttons,
+            _ => Default::default(),
+        };
         return Some(
             mouse_event
                 .position()
-                .map_or(MouseEvent::Exit, |position| MouseEvent::Moved { position }),
+                .map_or(MouseEvent::Exit, |position| MouseEvent::Moved { position, buttons }),
         );
     }
 
@@ -674,6 +732,18 @@ fn send_exit_events(
 /// Process the `mouse_event` on the `component`, the `mouse_grabber_stack` is the previous stack
 /// of mouse grabber.
 /// Returns a new mouse grabber stack.
+///
+/// Unless an item has the grab, the event goes through the item tree front to back. Each item
+/// whose geometry contains the position (or that clips its children) gets to filter the event with
+/// [`crate::items::ItemVTable::input_event_filter_before_children`], then the children are visited,
+/// and finally [`crate::items::ItemVTable::input_event`] is called, until an item accepts the event.
+///
+/// If that item returned [`InputEventResult::GrabMouse`], the following events are sent directly
+/// to it, translated to its coordinates, without hit testing, so that a drag keeps working when the
+/// pointer leaves the item. Its ancestors that returned [`InputEventFilterResult::ForwardAndInterceptGrab`]
+/// may still take the grab over by returning [`InputEventFilterResult::Intercept`], in which case the
+/// grabber receives a [`MouseEvent::Exit`]. Once the grabber returns anything other than
+/// `GrabMouse`, the grab is released and the items under the pointer are updated.
 pub fn process_mouse_input(
     component: ComponentRc,
     mouse_event: MouseEvent,
@@ -844,6 +914,128 @@ fn send_mouse_event_to_item(
     }
 }
 
+/// The state which a window should hold for the touch input: the touch points that are down,
+/// along with the item that accepted the [`TouchPhase::Started`] event of each of them
+#[derive(Default)]
+pub struct TouchInputState {
+    /// The ids of the touch points that are down, as reported by the platform. The index of a
+    /// touch point in this list is the id passed to the items, which stays small and stable
+    /// while the touch point is down.
+    points: Vec<Option<u64>>,
+    grabs: Vec<(u64, ItemWeak)>,
+}
+
+/// Process the [`MouseEvent::Touch`] event. The event of a new touch point is sent to the items
+/// under it, and the item that returns [`InputEventResult::GrabMouse`] receives the following
+/// events of that touch point, until it ends or is cancelled. The grabs of the different touch
+/// points are independent from each other and from the mouse grab.
+///
+/// The items receive the index of the touch point among the ones that are down as `id`, instead
+/// of the id reported by the platform.
+pub fn process_touch_input(
+    component: ComponentRc,
+    touch_event: MouseEvent,
+    window_adapter: &Rc<dyn WindowAdapter>,
+    mut touch_input_state: TouchInputState,
+) -> TouchInputState {
+    let MouseEvent::Touch { id, phase, position } = touch_event else {
+        return touch_input_state;
+    };
+
+    let points = &mut touch_input_state.points;
+    let slot = match points.iter().position(|point| *point == Some(id)) {
+        Some(slot) => slot,
+        None if phase == TouchPhase::Started => {
+            let slot = points.iter().position(Option::is_none).unwrap_or(points.len());
+            if slot == points.len() {
+                points.push(None);
+            }
+            points[slot] = Some(id);
+            slot
+        }
+        None => return touch_input_state,
+    };
+    if matches!(phase, TouchPhase::Ended | TouchPhase::Cancelled) {
+        points[slot] = None;
+        while points.last() == Some(&None) {
+            points.pop();
+        }
+    }
+    let touch_event = MouseEvent::Touch { id: slot as u64, phase, position };
+
+    if phase == TouchPhase::Started {
+        touch_input_state.grabs.retain(|(grab_id, _)| *grab_id != id);
+        let root = ItemRc::new(component, 0);
+        if let Some((InputEventResult::GrabMouse, item_rc)) =
+            send_touch_event_to_item(touch_event, root, window_adapter)
+        {
+            touch_input_state.grabs.push((id, item_rc.downgrade()));
+        }
+        return touch_input_state;
+    }
+
+    let Some(index) = touch_input_state.grabs.iter().position(|(grab_id, _)| *grab_id == id) else {
+        return touch_input_state;
+    };
+    let grabber = if matches!(phase, TouchPhase::Ended | TouchPhase::Cancelled) {
+        touch_input_state.grabs.remove(index).1
+    } else {
+        touch_input_state.grabs[index].1.clone()
+    };
+    if let Some(item_rc) = grabber.upgrade() {
+        let mut event = touch_event;
+        event.translate(-item_rc.map_to_window(item_rc.geometry().origin).to_vector());
+        item_rc.borrow().as_ref().input_event(event, window_adapter, &item_rc);
+    }
+    touch_input_state
+}
+
+/// Sends the touch event to the children of the item, front to back, and then to the item itself
+/// if the touch point is within it. Returns the item that accepted the event, if any.
+fn send_touch_event_to_item(
+    touch_event: MouseEvent,
+    item_rc: ItemRc,
+    window_adapter: &Rc<dyn WindowAdapter>,
+) -> Option<(InputEventResult, ItemRc)> {
+    let item = item_rc.borrow();
+    let geom = item_rc.geometry();
+    let contains = touch_event.position().map_or(false, |p| geom.contains(p));
+    if !contains && crate::item_rendering::is_clipping_item(item) {
+        return None;
+    }
+
+    let mut event = touch_event;
+    event.translate(-geom.origin.to_vector());
+
+    let mut accepted = None;
+    let mut actual_visitor = |component: &ComponentRc,
+                              index: usize,
+                              _: Pin<ItemRef>|
+     -> VisitChildrenResult {
+        accepted =
+            send_touch_event_to_item(event, ItemRc::new(component.clone(), index), window_adapter);
+        if accepted.is_some() {
+            VisitChildrenResult::abort(index, 0)
+        } else {
+            VisitChildrenResult::CONTINUE
+        }
+    };
+    vtable::new_vref!(let mut actual_visitor : VRefMut<crate::item_tree::ItemVisitorVTable> for crate::item_tree::ItemVisitor = &mut actual_visitor);
+    vtable::VRc::borrow_pin(&item_rc.component()).as_ref().visit_children_item(
+        item_rc.index() as isize,
+        crate::item_tree::TraversalOrder::FrontToBack,
+        actual_visitor,
+    );
+    if accepted.is_some() || !contains {
+        return accepted;
+    }
+
+    match item.as_ref().input_event(event, window_adapter, &item_rc) {
+        InputEventResult::EventIgnored => None,
+        result => Some((result, item_rc.clone())),
+    }
+}
+
 /// The TextCursorBlinker takes care of providing a toggled boolean property
 /// that can be used to animate a blinking cursor. It's typically stored in the
 /// Window using a Weak and set_binding() can be used to set up a binding on a given
@@ -24,6 +24,11 @@
 
 /// This structure must be present in items that are Rendered and contains information.
 /// Used by the backend.
+///
+/// It only locates the item's entry in a [`RenderingCache`]. Whether that entry is still valid
+/// is decided by the property tracker of its [`CachedGraphicsData`]: the properties read while
+/// computing the entry are recorded as dependencies, and changing any of them marks the tracker
+/// as dirty, so the entry is computed again the next time it is used.
 #[derive(Default, Debug)]
 #[repr(C)]
 pub struct CachedRenderingData {
@@ -403,12 +408,20 @@ fn metrics(&self) -> crate::graphics::rendering_metrics_collector::RenderingMetr
 }
 
 /// The cache that needs to be held by the Window for the partial rendering
+///
+/// It stores the geometry, relative to the parent item, with which each item was last rendered.
 pub type PartialRenderingCache = RenderingCache<LogicalRect>;
 
 /// FIXME: Should actually be a region and not just a rectangle
 pub type DirtyRegion = euclid::Box2D<Coord, LogicalPx>;
 
 /// Put this structure in the renderer to help with partial rendering
+///
+/// [`Self::compute_dirty_regions`] adds to the dirty region both the previous and the current
+/// geometry of every item whose cache entry was invalidated by a property change, as well as
+/// of the children of items that moved. Then only the items that intersect the dirty region are forwarded
+/// to the actual renderer. The software renderer uses it unless its
+/// [`crate::software_renderer::RepaintBufferType`] is `NewBuffer`.
 pub struct PartialRenderer<'a, T> {
     cache: &'a RefCell<PartialRenderingCache>,
     /// The region of the screen which is considered dirty and that should be repainted
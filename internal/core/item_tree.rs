@@ -9,6 +9,7 @@
 use crate::items::{ItemRef, ItemVTable};
 use crate::lengths::{LogicalPoint, LogicalRect};
 use crate::SharedString;
+use alloc::vec::Vec;
 use core::pin::Pin;
 use vtable::*;
 
@@ -197,6 +198,42 @@ pub fn map_to_window(&self, p: LogicalPoint) -> LogicalPoint {
         return result;
     }
 
+    /// Returns the geometry of this item in window coordinates, by adding the positions of all
+    /// its ancestors to the position returned by [`ItemVTable::geometry`], which is relative to
+    /// the parent item.
+    ///
+    /// The rectangle is not clipped: ancestors that clip their children (such as a `Flickable`
+    /// or a `Rectangle` with `clip: true`) are ignored, so parts of the returned rectangle may not
+    /// be visible. Item trees don't have transformations other than translations for now. Should
+    /// an ancestor ever be rotated or scaled, this function would have to map the rectangle through
+    /// that transformation and return its bounding box.
+    ///
+    /// ```
+    /// # i_slint_backend_testing::init();
+    /// use i_slint_core::item_tree::ItemRc;
+    /// use i_slint_core::lengths::LogicalRect;
+    ///
+    /// slint::slint! {
+    ///     export component App inherits Window {
+    ///         Rectangle {
+    ///             x: 10px; y: 20px; width: 100px; height: 100px;
+    ///             background: red;
+    ///             Rectangle { x: 5px; y: 6px; width: 7px; height: 8px; background: blue; }
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let app = App::new().unwrap();
+    /// let component = vtable::VRc::into_dyn(vtable::VRc::from(app));
+    ///
+    /// let inner = ItemRc::new(component, 2);
+    /// assert_eq!(inner.geometry_in_window(), LogicalRect::new((15., 26.).into(), (7., 8.).into()));
+    /// ```
+    pub fn geometry_in_window(&self) -> LogicalRect {
+        let geometry = self.geometry();
+        LogicalRect::new(self.map_to_window(geometry.origin), geometry.size)
+    }
+
     /// Return the index of the item within the component
     pub fn index(&self) -> usize {
         self.index
@@ -550,7 +587,7 @@ pub enum ItemTreeNode {
         /// number of children
         children_count: u32,
 
-        /// index of the first children within the item tree
+        /// index of the first child within the item tree
         children_index: u32,
 
         /// The index of the parent item (not valid for the root)
@@ -718,6 +755,200 @@ pub fn visit_items<State>(
     visit_internal(component, order, &mut visitor, -1, &state)
 }
 
+/// Visits the root item of the component, without visiting its children. This is the same as
+/// calling `visit_children_item` with `-1` as index. See [`visit_children`] for an example.
+pub fn visit_root(
+    component: &ComponentRc,
+    order: TraversalOrder,
+    mut visitor: impl FnMut(&ComponentRc, usize, Pin<ItemRef>) -> VisitChildrenResult,
+) -> VisitChildrenResult {
+    vtable::new_vref!(let mut visitor : VRefMut<ItemVisitorVTable> for ItemVisitor = &mut visitor);
+    VRc::borrow_pin(component).as_ref().visit_children_item(-1, order, visitor)
+}
+
+/// Visits the children of the item at `parent_index`, without visiting their children. The root
+/// item is at index 0, so its children are visited by passing 0, while [`visit_root`] visits the
+/// root item itself.
+///
+/// ```
+/// # i_slint_backend_testing::init();
+/// use i_slint_core::item_tree::{visit_children, visit_root, TraversalOrder, VisitChildrenResult};
+///
+/// slint::slint! {
+///     export component App inherits Window {
+///         Rectangle {
+///             background: red;
+///             Rectangle { background: blue; }
+///         }
+///         Rectangle { background: green; }
+///     }
+/// }
+///
+/// let app = App::new().unwrap();
+/// let component = vtable::VRc::into_dyn(vtable::VRc::from(app));
+///
+/// let mut indices = Vec::new();
+/// visit_root(&component, TraversalOrder::BackToFront, |_, index, _| {
+///     indices.push(index);
+///     VisitChildrenResult::CONTINUE
+/// });
+/// assert_eq!(indices, [0]);
+///
+/// indices.clear();
+/// visit_children(&component, 0, TraversalOrder::BackToFront, |_, index, _| {
+///     indices.push(index);
+///     VisitChildrenResult::CONTINUE
+/// });
+/// assert_eq!(indices, [1, 2]);
+/// ```
+pub fn visit_children(
+    component: &ComponentRc,
+    parent_index: usize,
+    order: TraversalOrder,
+    mut visitor: impl FnMut(&ComponentRc, usize, Pin<ItemRef>) -> VisitChildrenResult,
+) -> VisitChildrenResult {
+    vtable::new_vref!(let mut visitor : VRefMut<ItemVisitorVTable> for ItemVisitor = &mut visitor);
+    VRc::borrow_pin(component).as_ref().visit_children_item(parent_index as isize, order, visitor)
+}
+
+/// An iterator over the items of a component tree, in depth-first order.
+///
+/// The items are yielded as [`ItemRc`], from which the index and a `Pin<ItemRef>` can be
+/// obtained with [`ItemRc::index()`] and [`ItemRc::borrow()`]. The iterator is driven by
+/// [`visit_children`]: the children of an item are only visited once that item was yielded,
+/// so repeaters and other dynamic nodes are expanded lazily, as the iteration reaches them.
+///
+/// ```
+/// # i_slint_backend_testing::init();
+/// use i_slint_core::item_tree::{ItemTreeIterator, TraversalOrder};
+///
+/// slint::slint! {
+///     export component App inherits Window {
+///         Rectangle {
+///             background: red;
+///             for color in [Colors.blue, Colors.yellow] : Rectangle { background: color; }
+///         }
+///         TouchArea { }
+///     }
+/// }
+///
+/// let app = App::new().unwrap();
+/// let component = vtable::VRc::into_dyn(vtable::VRc::from(app));
+///
+/// let rectangles = ItemTreeIterator::new(&component, TraversalOrder::BackToFront)
+///     .filter(|item| item.downcast::<i_slint_core::items::Rectangle>().is_some())
+///     .count();
+/// assert_eq!(rectangles, 3);
+///
+/// let touch_area = ItemTreeIterator::new(&component, TraversalOrder::BackToFront)
+///     .find(|item| item.downcast::<i_slint_core::items::TouchArea>().is_some());
+/// assert!(touch_area.is_some());
+///
+/// // The repeated rectangles are yielded before the TouchArea, right after their parent
+/// let last = ItemTreeIterator::new(&component, TraversalOrder::BackToFront).last().unwrap();
+/// assert_eq!(last, touch_area.unwrap());
+/// ```
+pub struct ItemTreeIterator {
+    order: TraversalOrder,
+    /// The items that still need to be yielded, the next one being at the end.
+    stack: Vec<ItemRc>,
+}
+
+impl ItemTreeIterator {
+    /// Creates an iterator over the root item of `component` and all its descendants.
+    /// The `order` is the order in which the children of each item are yielded.
+    pub fn new(component: &ComponentRc, order: TraversalOrder) -> Self {
+        let mut stack = Vec::new();
+        visit_root(component, order, |component, index, _| {
+            stack.push(ItemRc::new(component.clone(), index));
+            VisitChildrenResult::CONTINUE
+        });
+        Self { order, stack }
+    }
+}
+
+impl Iterator for ItemTreeIterator {
+    type Item = ItemRc;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.stack.pop()?;
+        let first_child = self.stack.len();
+        let stack = &mut self.stack;
+        visit_children(&item.component, item.index, self.order, |component, index, _| {
+            stack.push(ItemRc::new(component.clone(), index));
+            VisitChildrenResult::CONTINUE
+        });
+        // The children must be popped in the order they were visited
+        self.stack[first_child..].reverse();
+        Some(item)
+    }
+}
+
+/// Returns the topmost item of the tree of `component` whose geometry contains `position`, in
+/// window coordinates.
+///
+/// The children are tested front to back before their parent, so the item that is drawn on top
+/// wins. The children of clipping items are only considered if `position` is within the clipping
+/// item. `Empty` items, which are generated for elements that neither draw nor handle input
+/// (such as layouts), are never returned, but their children are.
+///
+/// Mouse events are not dispatched with this function, since items can filter or intercept
+/// events before their children see them (see `crate::input::process_mouse_input`).
+///
+/// ```
+/// # i_slint_backend_testing::init();
+/// use i_slint_core::item_tree::hit_test;
+/// use i_slint_core::items::{Rectangle, TouchArea};
+///
+/// slint::slint! {
+///     export component App inherits Window {
+///         width: 100px; height: 100px;
+///         TouchArea { x: 0px; y: 0px; width: 50px; height: 50px; }
+///         Rectangle { x: 25px; y: 25px; width: 50px; height: 50px; background: red; }
+///     }
+/// }
+///
+/// let app = App::new().unwrap();
+/// let component = vtable::VRc::into_dyn(vtable::VRc::from(app));
+///
+/// let item = hit_test(&component, (10., 10.).into()).unwrap();
+/// assert!(item.downcast::<TouchArea>().is_some());
+/// let item = hit_test(&component, (30., 30.).into()).unwrap();
+/// assert!(item.downcast::<Rectangle>().is_some());
+/// let item = hit_test(&component, (90., 90.).into()).unwrap();
+/// assert_eq!(item.index(), 0);
+/// assert!(hit_test(&component, (110., 10.).into()).is_none());
+/// ```
+pub fn hit_test(component: &ComponentRc, position: LogicalPoint) -> Option<ItemRc> {
+    hit_test_item(&ItemRc::new(component.clone(), 0), position)
+}
+
+/// `position` is in the coordinates of the parent of `item_rc`
+fn hit_test_item(item_rc: &ItemRc, position: LogicalPoint) -> Option<ItemRc> {
+    let item = item_rc.borrow();
+    let geometry = item.as_ref().geometry();
+    let contains = geometry.contains(position);
+    if !contains && crate::item_rendering::is_clipping_item(item) {
+        return None;
+    }
+
+    let position_for_children = position - geometry.origin.to_vector();
+    let mut result = None;
+    visit_children(&item_rc.component, item_rc.index, TraversalOrder::FrontToBack, |c, i, _| {
+        result = hit_test_item(&ItemRc::new(c.clone(), i), position_for_children);
+        if result.is_some() {
+            VisitChildrenResult::abort(i, 0)
+        } else {
+            VisitChildrenResult::CONTINUE
+        }
+    });
+
+    result.or_else(|| {
+        (contains && ItemRef::downcast_pin::<crate::items::Empty>(item).is_none())
+            .then(|| item_rc.clone())
+    })
+}
+
 fn visit_internal<State>(
     component: &ComponentRc,
     order: TraversalOrder,
@@ -744,6 +975,9 @@ fn visit_internal<State>(
 /// The dynamic visitor is called for the dynamic nodes, its signature is
 /// `fn(base: &Base, visitor: vtable::VRefMut<ItemVisitorVTable>, dyn_index: usize)`
 ///
+/// Indices of nodes and children out of the range of `item_tree` trigger an assertion in debug builds,
+/// and are skipped in release builds. The index of dynamic nodes is for `visit_dynamic` to validate.
+///
 /// FIXME: the design of this use lots of indirection and stack frame in recursive functions
 /// Need to check if the compiler is able to optimize away some of it.
 /// Possibly we should generate code that directly call the visitor instead
@@ -761,8 +995,18 @@ pub fn visit_item_tree<Base>(
         usize,
     ) -> VisitChildrenResult,
 ) -> VisitChildrenResult {
+    let node_at = |idx: usize| {
+        debug_assert!(
+            idx < item_tree.len(),
+            "item tree index {} is out of range, the item tree has {} nodes",
+            idx,
+            item_tree.len()
+        );
+        item_tree.get(idx)
+    };
     let mut visit_at_index = |idx: usize| -> VisitChildrenResult {
-        match &item_tree[idx] {
+        let Some(node) = node_at(idx) else { return VisitChildrenResult::CONTINUE };
+        match node {
             ItemTreeNode::Item { .. } => {
                 let item = crate::items::ItemRc::new(component.clone(), idx);
                 visitor.visit_item(component, idx, item.borrow())
@@ -781,13 +1025,25 @@ pub fn visit_item_tree<Base>(
     if index == -1 {
         visit_at_index(0)
     } else {
-        match &item_tree[index as usize] {
+        let Some(node) = node_at(index as usize) else { return VisitChildrenResult::CONTINUE };
+        match node {
             ItemTreeNode::Item { children_index, children_count, .. } => {
-                for c in 0..*children_count {
+                let children_start = *children_index as usize;
+                let children_end = children_start + *children_count as usize;
+                debug_assert!(
+                    children_end <= item_tree.len(),
+                    "the children {}..{} of item {} are out of range, the item tree has {} nodes",
+                    children_start,
+                    children_end,
+                    index,
+                    item_tree.len()
+                );
+                let children = children_start..children_end.min(item_tree.len());
+                for c in 0..children.len() {
                     let idx = match order {
-                        TraversalOrder::BackToFront => *children_index + c,
-                        TraversalOrder::FrontToBack => *children_index + *children_count - c - 1,
-                    } as usize;
+                        TraversalOrder::BackToFront => children.start + c,
+                        TraversalOrder::FrontToBack => children.end - c - 1,
+                    };
                     let maybe_abort_index = visit_at_index(idx);
                     if maybe_abort_index.has_aborted() {
                         return maybe_abort_index;
@@ -940,6 +1196,34 @@ fn create_one_node_component() -> VRc<ComponentVTable, vtable::Dyn> {
         VRc::into_dyn(component)
     }
 
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "the children 1..3 of item 0 are out of range")]
+    fn test_visit_item_tree_children_out_of_range() {
+        let component = create_one_node_component();
+        let item_tree = [ItemTreeNode::Item {
+            is_accessible: false,
+            children_count: 2,
+            children_index: 1,
+            parent_index: 0,
+            item_array_index: 0,
+        }];
+
+        let mut visitor = |_: &ComponentRc, _: usize, _: Pin<ItemRef>| -> VisitChildrenResult {
+            unreachable!("There are no valid children to visit")
+        };
+        vtable::new_vref!(let mut visitor : VRefMut<ItemVisitorVTable> for ItemVisitor = &mut visitor);
+        visit_item_tree(
+            Pin::new(&()),
+            &component,
+            &item_tree,
+            0,
+            TraversalOrder::BackToFront,
+            visitor,
+            |_, _, _, _| unreachable!("There are no dynamic nodes"),
+        );
+    }
+
     #[test]
     fn test_tree_traversal_one_node_structure() {
         let component = create_one_node_component();
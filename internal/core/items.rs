@@ -61,6 +61,7 @@
 pub type VoidArg = ();
 pub type KeyEventArg = (KeyEvent,);
 type PointerEventArg = (PointerEvent,);
+type TouchEventArg = (TouchEvent,);
 type PointArg = (Point,);
 
 #[cfg(all(feature = "ffi", windows))]
@@ -463,6 +464,7 @@ pub struct TouchArea {
     pub clicked: Callback<VoidArg>,
     pub moved: Callback<VoidArg>,
     pub pointer_event: Callback<PointerEventArg>,
+    pub touch_event: Callback<TouchEventArg>,
     /// FIXME: remove this
     pub cached_rendering_data: CachedRenderingData,
     /// true when we are currently grabbing the mouse
@@ -583,6 +585,18 @@ fn input_event(
                     InputEventResult::EventAccepted
                 }
             }
+            MouseEvent::Touch { id, phase, position } => {
+                Self::FIELD_OFFSETS.touch_event.apply_pin(self).call(&(TouchEvent {
+                    id: id as i32,
+                    phase,
+                    x: position.x,
+                    y: position.y,
+                },));
+                // Keep receiving the events of this touch point until it ends
+                if phase != TouchPhase::Started {
+                    return InputEventResult::EventAccepted;
+                }
+            }
         };
         result
     }
@@ -1438,3 +1452,14 @@ pub struct PointerEvent {
     pub button: PointerEventButton,
     pub kind: PointerEventKind,
 }
+
+/// Represents a touch event sent by the windowing system, for one of the touch points.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[repr(C)]
+pub struct TouchEvent {
+    /// Identifies the touch point among the ones that are down at the same time
+    pub id: i32,
+    pub phase: TouchPhase,
+    pub x: Coord,
+    pub y: Coord,
+}
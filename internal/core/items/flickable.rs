@@ -234,7 +234,7 @@ pub fn handle_mouse_filter(
                     InputEventFilterResult::ForwardEvent
                 }
             }
-            MouseEvent::Moved { position } => {
+            MouseEvent::Moved { position, .. } => {
                 let do_intercept = inner.capture_events
                     || inner.pressed_time.map_or(false, |pressed_time| {
                         if crate::animations::current_tick() - pressed_time > DURATION_THRESHOLD {
@@ -270,12 +270,17 @@ pub fn handle_mouse_filter(
                 }
             }
             MouseEvent::Wheel { position, .. } => {
-                InputEventFilterResult::InterceptAndDispatch(MouseEvent::Moved { position })
+                InputEventFilterResult::InterceptAndDispatch(MouseEvent::Moved {
+                    position,
+                    buttons: Default::default(),
+                })
             }
             // Not the left button
             MouseEvent::Pressed { .. } | MouseEvent::Released { .. } => {
                 InputEventFilterResult::ForwardAndIgnore
             }
+            // The flickable is moved by the pointer, which the first touch point also drives
+            MouseEvent::Touch { .. } => InputEventFilterResult::ForwardEvent,
         }
     }
 
@@ -300,7 +305,7 @@ pub fn handle_mouse(
                     InputEventResult::EventIgnored
                 }
             }
-            MouseEvent::Moved { position } => {
+            MouseEvent::Moved { position, .. } => {
                 if inner.pressed_time.is_some() {
                     let new_pos = inner.pressed_viewport_pos + (position - inner.pressed_pos);
                     let x = (Flickable::FIELD_OFFSETS.viewport + Empty::FIELD_OFFSETS.x)
@@ -363,6 +368,7 @@ pub fn handle_mouse(
                     .set(new_pos.y_length());
                 InputEventResult::EventAccepted
             }
+            MouseEvent::Touch { .. } => InputEventResult::EventIgnored,
         }
     }
 
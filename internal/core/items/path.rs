@@ -23,7 +23,7 @@
 #[cfg(feature = "rtti")]
 use crate::rtti::*;
 use crate::window::WindowAdapter;
-use crate::{Coord, Property};
+use crate::{Coord, Property, SharedVector};
 use alloc::rc::Rc;
 use const_field_offset::FieldOffsets;
 use core::pin::Pin;
@@ -44,6 +44,9 @@ pub struct Path {
     pub fill_rule: Property<FillRule>,
     pub stroke: Property<Brush>,
     pub stroke_width: Property<LogicalLength>,
+    /// The lengths of the `stroke-dash-array` model, set by the compiler
+    pub dash_array: Property<SharedVector<Coord>>,
+    pub stroke_dash_offset: Property<LogicalLength>,
     pub viewbox_x: Property<f32>,
     pub viewbox_y: Property<f32>,
     pub viewbox_width: Property<f32>,
@@ -163,6 +166,29 @@ pub fn fitted_path_events(self: Pin<&Self>) -> Option<(LogicalVector, PathDataIt
         elements_iter.fit(bounds_width.get() as _, bounds_height.get() as _, maybe_viewbox);
         (offset, elements_iter).into()
     }
+
+    /// Returns the lengths of the dashes and gaps to stroke the path with, in logical pixels, or
+    /// None if the stroke is solid because `stroke-dash-array` is empty, only zeros, or has
+    /// negative lengths. Renderers that dash strokes themselves start the pattern at
+    /// `stroke-dash-offset`, and restart it with each sub-path.
+    pub fn stroke_dash_pattern(self: Pin<&Self>) -> Option<alloc::vec::Vec<f32>> {
+        let dash_array = self.dash_array();
+        (dash_array.iter().all(|length| *length >= 0 as Coord)
+            && dash_array.iter().any(|length| *length > 0 as Coord))
+        .then(|| dash_array.iter().map(|length| *length as _).collect())
+    }
+
+    /// Returns the events of the dashes to stroke instead of the fitted `path_events`, or None
+    /// if the stroke is solid. The dashes are made of line segments, see
+    /// [`PathDataIterator::dashed`].
+    pub fn dashed_stroke_events(
+        self: Pin<&Self>,
+        path_events: &PathDataIterator,
+        tolerance: f32,
+    ) -> Option<alloc::vec::Vec<lyon_path::PathEvent>> {
+        let dash_array = self.stroke_dash_pattern()?;
+        Some(path_events.dashed(&dash_array, self.stroke_dash_offset().get() as _, tolerance))
+    }
 }
 
 impl ItemConsts for Path {
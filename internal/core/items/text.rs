@@ -8,11 +8,11 @@
 Lookup the [`crate::items`] module documentation.
 */
 use super::{
-    InputType, Item, ItemConsts, ItemRc, KeyEventResult, KeyEventType, PointArg,
+    FontStyle, InputType, Item, ItemConsts, ItemRc, KeyEventResult, KeyEventType, PointArg,
     PointerEventButton, RenderingResult, TextHorizontalAlignment, TextOverflow,
     TextVerticalAlignment, TextWrap, VoidArg,
 };
-use crate::graphics::{Brush, Color, FontRequest};
+use crate::graphics::{Brush, Color, FontFeature, FontRequest, FontVariation};
 use crate::input::{
     key_codes, FocusEvent, FocusEventResult, InputEventFilterResult, InputEventResult, KeyEvent,
     KeyboardModifiers, MouseEvent, StandardShortcut, TextShortcut,
@@ -43,12 +43,16 @@ pub struct Text {
     pub font_family: Property<SharedString>,
     pub font_size: Property<LogicalLength>,
     pub font_weight: Property<i32>,
+    pub font_style: Property<FontStyle>,
+    pub font_features: Property<SharedString>,
+    pub font_variations: Property<SharedString>,
     pub color: Property<Brush>,
     pub horizontal_alignment: Property<TextHorizontalAlignment>,
     pub vertical_alignment: Property<TextVerticalAlignment>,
     pub wrap: Property<TextWrap>,
     pub overflow: Property<TextOverflow>,
     pub letter_spacing: Property<LogicalLength>,
+    pub line_height: Property<LogicalLength>,
     pub x: Property<LogicalLength>,
     pub y: Property<LogicalLength>,
     pub width: Property<LogicalLength>,
@@ -202,7 +206,14 @@ pub fn font_request(self: Pin<&Self>, window: &WindowInner) -> FontRequest {
                     Some(font_size)
                 }
             },
+            style: self.font_style(),
             letter_spacing: Some(self.letter_spacing()),
+            line_height: {
+                let line_height = self.line_height();
+                (line_height.get() > 0 as Coord).then_some(line_height)
+            },
+            features: FontFeature::parse_list(&self.font_features()).collect(),
+            variations: FontVariation::parse_list(&self.font_variations()).collect(),
         }
     }
 }
@@ -216,6 +227,9 @@ pub struct TextInput {
     pub font_family: Property<SharedString>,
     pub font_size: Property<LogicalLength>,
     pub font_weight: Property<i32>,
+    pub font_style: Property<FontStyle>,
+    pub font_features: Property<SharedString>,
+    pub font_variations: Property<SharedString>,
     pub color: Property<Brush>,
     pub selection_foreground_color: Property<Color>,
     pub selection_background_color: Property<Color>,
@@ -365,7 +379,7 @@ fn input_event(
                 window_adapter.set_mouse_cursor(super::MouseCursor::Default);
                 self.as_ref().pressed.set(0)
             }
-            MouseEvent::Moved { position } => {
+            MouseEvent::Moved { position, .. } => {
                 window_adapter.set_mouse_cursor(super::MouseCursor::Text);
                 let pressed = self.as_ref().pressed.get();
                 if pressed > 0 {
@@ -1063,7 +1077,11 @@ pub fn font_request(self: Pin<&Self>, window_adapter: &Rc<dyn WindowAdapter>) ->
                     Some(font_size)
                 }
             },
+            style: self.font_style(),
             letter_spacing: Some(self.letter_spacing()),
+            line_height: None,
+            features: FontFeature::parse_list(&self.font_features()).collect(),
+            variations: FontVariation::parse_list(&self.font_variations()).collect(),
         }
     }
 
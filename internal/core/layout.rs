@@ -50,6 +50,8 @@ fn default() -> Self {
 }
 
 impl LayoutInfo {
+    /// Returns the intersection of both constraints, for an item that is constrained by both:
+    /// The larger minimum and preferred size, and the smaller maximum and stretch.
     // Note: This "logic" is duplicated in the cpp generator's generated code for merging layout infos.
     #[must_use]
     pub fn merge(&self, other: &LayoutInfo) -> Self {
@@ -63,6 +65,33 @@ pub fn merge(&self, other: &LayoutInfo) -> Self {
         }
     }
 
+    /// Returns the constraint of two items stacked in the direction of the constraint, as in a
+    /// box layout: The minimum, maximum and preferred sizes and the stretch factors add up. The
+    /// percentages don't apply to the stack and are reset.
+    #[must_use]
+    pub fn merge_max(&self, other: &LayoutInfo) -> Self {
+        let min = self.min + other.min;
+        Self {
+            min,
+            max: Saturating::add(self.max, other.max).max(min),
+            min_percent: 0 as _,
+            max_percent: 100 as _,
+            preferred: self.preferred_bounded() + other.preferred_bounded(),
+            stretch: self.stretch + other.stretch,
+        }
+    }
+
+    /// Returns the constraint of two items overlaid on each other, like [`Self::merge`], but the
+    /// maximum is raised to the minimum when the minimum of one item exceeds the maximum of the
+    /// other, and the preferred size is kept within these bounds.
+    #[must_use]
+    pub fn merge_intersect(&self, other: &LayoutInfo) -> Self {
+        let mut merged = self.merge(other);
+        merged.max = merged.max.max(merged.min);
+        merged.preferred = merged.preferred.clamp(merged.min, merged.max);
+        merged
+    }
+
     /// Helper function to return a preferred size which is within the min/max constraints
     #[must_use]
     pub fn preferred_bounded(&self) -> Coord {
@@ -110,6 +139,125 @@ pub fn min_max_size_for_layout_constraints(
     (min_size, max_size)
 }
 
+#[test]
+fn test_merge_layout_info() {
+    let a = LayoutInfo {
+        min: 10 as Coord,
+        max: 200 as Coord,
+        preferred: 50 as Coord,
+        stretch: 1.,
+        ..Default::default()
+    };
+    let b = LayoutInfo {
+        min: 20 as Coord,
+        max: 100 as Coord,
+        min_percent: 10 as Coord,
+        preferred: 30 as Coord,
+        stretch: 0.5,
+        ..Default::default()
+    };
+    let expected = LayoutInfo {
+        min: 20 as Coord,
+        max: 100 as Coord,
+        min_percent: 10 as Coord,
+        max_percent: 100 as Coord,
+        preferred: 50 as Coord,
+        stretch: 0.5,
+    };
+    assert_eq!(a.merge(&b), expected);
+    assert_eq!(b.merge(&a), expected);
+    assert_eq!(a + b, expected);
+    // The default constraint doesn't constrain anything
+    assert_eq!(a.merge(&LayoutInfo { stretch: 1., ..Default::default() }), a);
+}
+
+#[test]
+fn test_merge_max_and_intersect_layout_info() {
+    let a = LayoutInfo {
+        min: 10 as Coord,
+        max: 20 as Coord,
+        preferred: 30 as Coord,
+        stretch: 1.,
+        ..Default::default()
+    };
+    let b = LayoutInfo {
+        min: 40 as Coord,
+        max: 100 as Coord,
+        max_percent: 50 as Coord,
+        preferred: 50 as Coord,
+        stretch: 2.,
+        ..Default::default()
+    };
+
+    assert_eq!(
+        a.merge_max(&b),
+        LayoutInfo {
+            min: 50 as Coord,
+            max: 120 as Coord,
+            min_percent: 0 as Coord,
+            max_percent: 100 as Coord,
+            // The preferred size of a is bounded by its maximum
+            preferred: 70 as Coord,
+            stretch: 3.,
+        }
+    );
+    // Unbounded maximums don't overflow
+    assert_eq!(a.merge_max(&LayoutInfo::default()).max, Coord::MAX);
+
+    // The minimum of b exceeds the maximum of a
+    assert_eq!(
+        a.merge_intersect(&b),
+        LayoutInfo {
+            min: 40 as Coord,
+            max: 40 as Coord,
+            min_percent: 0 as Coord,
+            max_percent: 50 as Coord,
+            preferred: 40 as Coord,
+            stretch: 1.,
+        }
+    );
+    assert_eq!(a.merge_intersect(&b), b.merge_intersect(&a));
+}
+
+#[test]
+fn test_min_max_size_for_layout_constraints() {
+    let constraint = |min: Coord, max: Coord| LayoutInfo { min, max, ..Default::default() };
+    let size = |w: Coord, h: Coord| Some(crate::lengths::LogicalSize::new(w, h));
+
+    // No constraints at all
+    assert_eq!(
+        min_max_size_for_layout_constraints(LayoutInfo::default(), LayoutInfo::default()),
+        (None, None)
+    );
+
+    // Only a minimum: the window can grow without limit
+    assert_eq!(
+        min_max_size_for_layout_constraints(
+            constraint(100 as Coord, Coord::MAX),
+            constraint(50 as Coord, Coord::MAX)
+        ),
+        (size(100 as Coord, 50 as Coord), None)
+    );
+
+    // Minimum and maximum, with a maximum in one direction only being capped
+    assert_eq!(
+        min_max_size_for_layout_constraints(
+            constraint(100 as Coord, 200 as Coord),
+            constraint(50 as Coord, Coord::MAX)
+        ),
+        (size(100 as Coord, 50 as Coord), size(200 as Coord, 16_777_215 as Coord))
+    );
+
+    // A maximum smaller than the minimum: the two bounds are swapped
+    assert_eq!(
+        min_max_size_for_layout_constraints(
+            constraint(300 as Coord, 200 as Coord),
+            constraint(50 as Coord, 100 as Coord)
+        ),
+        (size(200 as Coord, 50 as Coord), size(300 as Coord, 100 as Coord))
+    );
+}
+
 /// Implement a saturating_add version for both possible value of Coord.
 /// So that adding the max value does not overflow
 trait Saturating {
@@ -250,7 +398,7 @@ fn adjust_items<A: Adjust>(data: &mut [LayoutData], size_without_spacing: Coord)
                 if let Some(it) = data
                     .iter_mut()
                     .filter(|it| A::can_grow(it) > 0 as _)
-                    .max_by(|a, b| actual_stretch(a.stretch).total_cmp(&b.stretch))
+                    .max_by(|a, b| actual_stretch(a.stretch).total_cmp(&actual_stretch(b.stretch)))
                 {
                     A::distribute(it, to_distribute as Coord);
                 }
@@ -305,6 +453,59 @@ fn test_layout_items() {
         assert_eq!(my_items[2].size, 100.);
     }
 
+    #[test]
+    #[allow(clippy::float_cmp)] // We want bit-wise equality here
+    fn test_layout_items_preferred() {
+        let my_items = &mut [
+            LayoutData { min: 10., max: 200., pref: 50., stretch: 1., ..Default::default() },
+            LayoutData { min: 10., max: 200., pref: 150., stretch: 1., ..Default::default() },
+        ];
+
+        // Exactly the preferred size: every item gets its preferred size
+        layout_items(my_items, 0., 200., 0.);
+        assert_eq!(my_items[0].size, 50.);
+        assert_eq!(my_items[1].size, 150.);
+
+        // The extra space is distributed on top of the preferred sizes, until the maximum
+        layout_items(my_items, 0., 300., 0.);
+        assert_eq!(my_items[0].size, 100.);
+        assert_eq!(my_items[1].size, 200.);
+        layout_items(my_items, 0., 350., 0.);
+        assert_eq!(my_items[0].size, 150.);
+        assert_eq!(my_items[1].size, 200.);
+
+        // The missing space is taken from the preferred sizes, until the minimum
+        layout_items(my_items, 0., 100., 0.);
+        assert_eq!(my_items[0].size, 10.);
+        assert_eq!(my_items[1].size, 90.);
+        assert_eq!(my_items[1].pos, 10.);
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)] // We want bit-wise equality here
+    fn test_layout_items_stretch() {
+        let my_items = &mut [
+            LayoutData { min: 0., max: 1000., pref: 50., stretch: 2., ..Default::default() },
+            LayoutData { min: 0., max: 1000., pref: 50., stretch: 1., ..Default::default() },
+            LayoutData { min: 0., max: 1000., pref: 50., stretch: 0., ..Default::default() },
+        ];
+
+        // The extra space is split according to the stretch factors, and an item with a
+        // stretch of 0 keeps its preferred size
+        layout_items(my_items, 0., 450., 0.);
+        assert_eq!(my_items[0].size, 250.);
+        assert_eq!(my_items[1].size, 150.);
+        assert_eq!(my_items[2].size, 50.);
+
+        // Once the other items reached their maximum, the item with a stretch of 0 grows
+        my_items[0].max = 100.;
+        my_items[1].max = 100.;
+        layout_items(my_items, 0., 450., 0.);
+        assert_eq!(my_items[0].size, 100.);
+        assert_eq!(my_items[1].size, 100.);
+        assert_eq!(my_items[2].size, 250.);
+    }
+
     /// Create a vector of LayoutData for an array of GridLayoutCellData
     pub fn to_layout_data(
         data: &[GridLayoutCellData],
@@ -439,6 +640,8 @@ pub struct GridLayoutCellData {
 }
 
 /// return, an array which is of size `data.cells.len() * 2` which for each cell we give the pos, size
+///
+/// The geometry is only computed, the generated code applies it to the properties of the items.
 pub fn solve_grid_layout(data: &GridLayoutData) -> SharedVector<Coord> {
     let mut layout_data =
         grid_internal::to_layout_data(data.cells.as_slice(), data.spacing, Some(data.size));
@@ -504,6 +707,10 @@ pub struct BoxLayoutCellData {
 }
 
 /// Solve a BoxLayout
+///
+/// Like [`solve_grid_layout`], this only computes the geometry of the cells and doesn't set any
+/// property, so the result can be inspected or cached before it is applied. It contains the
+/// position and the size of each cell, in the direction of the layout.
 pub fn solve_box_layout(data: &BoxLayoutData, repeater_indexes: Slice<u32>) -> SharedVector<Coord> {
     let mut result = SharedVector::<Coord>::default();
     result.resize(data.cells.len() * 2 + repeater_indexes.len(), 0 as _);
@@ -209,6 +209,7 @@ pub fn duration_until_next_timer_update() -> Option<core::time::Duration> {
 // reexport key enum to the public api
 pub use crate::input::key_codes::Key;
 pub use crate::input::PointerEventButton;
+pub use crate::input::TouchPhase;
 
 /// A event that describes user input or windowing system events.
 ///
@@ -247,6 +248,17 @@ pub enum WindowEvent {
     },
     /// The pointer exited the window.
     PointerExited,
+    /// A finger touched, moved on, or left a touch screen.
+    ///
+    /// Platform implementations typically also dispatch pointer events for the first finger, so
+    /// that the elements that only handle the pointer can be used on a touch screen.
+    Touch {
+        /// Identifies the touch point among the ones that are down at the same time.
+        id: u64,
+        /// What happened to the touch point.
+        phase: TouchPhase,
+        position: LogicalPosition,
+    },
     /// A key was pressed.
     KeyPressed {
         /// The unicode representation of the key pressed.
@@ -288,6 +300,7 @@ pub fn position(&self) -> Option<LogicalPosition> {
             WindowEvent::PointerReleased { position, .. } => Some(*position),
             WindowEvent::PointerMoved { position } => Some(*position),
             WindowEvent::PointerScrolled { position, .. } => Some(*position),
+            WindowEvent::Touch { position, .. } => Some(*position),
             _ => None,
         }
     }
@@ -41,6 +41,16 @@ fn text_input_cursor_rect_for_byte_offset(
         scale_factor: ScaleFactor,
     ) -> LogicalRect;
 
+    /// Returns the vertical metrics of the font that text with the given font request is
+    /// rendered with, or None if the renderer doesn't provide them.
+    fn font_metrics(
+        &self,
+        _font_request: crate::graphics::FontRequest,
+        _scale_factor: ScaleFactor,
+    ) -> Option<crate::graphics::FontVerticalMetrics<LogicalLength>> {
+        None
+    }
+
     /// Clear the caches for the items that are being removed
     fn free_graphics_resources(
         &self,
@@ -77,6 +87,17 @@ fn register_font_from_path(
         Err("This renderer does not support registering custom fonts.".into())
     }
 
+    #[cfg(feature = "std")]
+    /// Removes the fonts registered with [`Self::register_font_from_memory`] and
+    /// [`Self::register_font_from_path`], keeping the fonts of the system. This makes
+    /// it possible to reload custom fonts.
+    ///
+    /// Note that the FemtoVG renderer can't release the memory of the fonts that it
+    /// already used for rendering text.
+    fn reset_registered_fonts(&self) -> Result<(), Box<dyn std::error::Error>> {
+        Err("This renderer does not support registering custom fonts.".into())
+    }
+
     fn register_bitmap_font(&self, _font_data: &'static crate::graphics::BitmapFont) {
         crate::debug_log!("Internal error: The current renderer cannot load fonts build with the `EmbedForSoftwareRenderer` option. Please use the software Renderer, or disable that option when building your slint files");
     }
@@ -91,4 +112,15 @@ fn set_rendering_notifier(
     }
 
     fn default_font_size(&self) -> LogicalLength;
+
+    /// Renders the current state of the window into a new buffer and returns it.
+    /// This is used to implement [`crate::api::Window::grab_frame()`].
+    fn grab_frame(
+        &self,
+    ) -> Result<
+        crate::graphics::SharedPixelBuffer<crate::graphics::Rgba8Pixel>,
+        crate::platform::PlatformError,
+    > {
+        Err("This renderer does not support grabbing the window contents".into())
+    }
 }
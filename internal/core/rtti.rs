@@ -33,6 +33,7 @@ macro_rules! declare_ValueType_2 {
             crate::graphics::Image,
             crate::Color,
             crate::PathData,
+            crate::SharedVector<crate::Coord>,
             crate::animations::EasingCurve,
             crate::model::StandardListViewItem,
             crate::model::TableColumn,
@@ -40,6 +41,7 @@ macro_rules! declare_ValueType_2 {
             crate::Brush,
             crate::graphics::Point,
             crate::items::PointerEvent,
+            crate::items::TouchEvent,
             crate::lengths::LogicalLength,
             $(crate::items::$Name,)*
         ];
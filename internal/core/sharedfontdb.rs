@@ -1,6 +1,13 @@
 // Copyright © SixtyFPS GmbH <info@slint-ui.com>
 // SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-commercial
 
+//! The database of the fonts of the system and of the fonts registered by the application.
+//!
+//! The database is per thread: Fonts registered on one thread aren't available on other threads.
+//! To render on another thread with the same fonts, hand them over with [`export_registered_fonts`]
+//! on the thread that registered them and [`import_registered_fonts`] on the other thread. The
+//! font data is shared between the threads, not copied.
+
 use std::cell::RefCell;
 
 pub use fontdb;
@@ -17,6 +24,80 @@ pub struct FontDatabase {
         target_arch = "wasm32"
     )))]
     pub fontconfig_fallback_families: Vec<crate::SharedString>,
+    /// The faces loaded with `register_font_from_memory` and `register_font_from_path`
+    registered_faces: Vec<fontdb::ID>,
+}
+
+impl FontDatabase {
+    /// Calls `load` to load fonts into the database, and returns its result together with
+    /// the ids of the faces that were added.
+    pub fn load_faces<R>(
+        &mut self,
+        load: impl FnOnce(&mut fontdb::Database) -> R,
+    ) -> (R, Vec<fontdb::ID>) {
+        let existing_faces =
+            self.db.faces().map(|face_info| face_info.id).collect::<std::collections::HashSet<_>>();
+        let result = load(&mut self.db);
+        let added_faces = self
+            .db
+            .faces()
+            .map(|face_info| face_info.id)
+            .filter(|id| !existing_faces.contains(id))
+            .collect();
+        (result, added_faces)
+    }
+
+    fn register_faces<R>(
+        &mut self,
+        load: impl FnOnce(&mut fontdb::Database) -> R,
+    ) -> (R, Vec<fontdb::ID>) {
+        let (result, added_faces) = self.load_faces(load);
+        self.registered_faces.extend_from_slice(&added_faces);
+        (result, added_faces)
+    }
+
+    /// Returns the ids of the faces loaded from the given source.
+    fn faces_of_source(&self, source: &fontdb::Source) -> Vec<fontdb::ID> {
+        self.db
+            .faces()
+            .filter(|face_info| same_source(&face_info.source, source))
+            .map(|face_info| face_info.id)
+            .collect()
+    }
+
+    /// Registers the fonts of the source, unless they were loaded already. Returns the ids of
+    /// their faces.
+    fn register_source(&mut self, source: fontdb::Source) -> Vec<fontdb::ID> {
+        let loaded_faces = self.faces_of_source(&source);
+        if !loaded_faces.is_empty() {
+            return loaded_faces;
+        }
+        self.register_faces(|db| db.load_font_source(source)).1
+    }
+}
+
+/// Returns true if both sources are the same file, or have the same data. The data is usually
+/// the same static slice, but the contents are compared for copies of it.
+fn same_source(a: &fontdb::Source, b: &fontdb::Source) -> bool {
+    match (a, b) {
+        (fontdb::Source::Binary(a), fontdb::Source::Binary(b)) => {
+            let (a, b) = ((**a).as_ref(), (**b).as_ref());
+            core::ptr::eq(a, b) || a == b
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        (
+            fontdb::Source::File(a) | fontdb::Source::SharedFile(a, _),
+            fontdb::Source::File(b) | fontdb::Source::SharedFile(b, _),
+        ) => a == b,
+        _ => false,
+    }
+}
+
+/// The fonts registered on a thread, to register them on other threads with
+/// [`import_registered_fonts`].
+#[derive(Clone, Default)]
+pub struct RegisteredFonts {
+    sources: Vec<fontdb::Source>,
 }
 
 thread_local! {
@@ -81,43 +162,188 @@ fn init_fontdb() -> FontDatabase {
             target_arch = "wasm32"
         )))]
         fontconfig_fallback_families,
+        registered_faces: Vec::new(),
     }
 }
 
 /// This function can be used to register a custom TrueType font with Slint,
 /// for use with the `font-family` property. The provided slice must be a valid TrueType
-/// font.
-pub fn register_font_from_memory(data: &'static [u8]) -> Result<(), Box<dyn std::error::Error>> {
+/// font. Returns the ids of the loaded faces, which are several for a font collection. Registering
+/// the same data again doesn't load it again, but returns the ids of its faces.
+pub fn register_font_from_memory(
+    data: &'static [u8],
+) -> Result<Vec<fontdb::ID>, Box<dyn std::error::Error>> {
+    Ok(FONT_DB.with(|db| {
+        db.borrow_mut().register_source(fontdb::Source::Binary(std::sync::Arc::new(data)))
+    }))
+}
+
+/// Returns the fonts registered on this thread with [`register_font_from_memory`] and
+/// [`register_font_from_path`], to register them on another thread.
+pub fn export_registered_fonts() -> RegisteredFonts {
     FONT_DB.with(|db| {
-        db.borrow_mut().load_font_source(fontdb::Source::Binary(std::sync::Arc::new(data)))
+        let db = db.borrow();
+        let mut sources: Vec<fontdb::Source> = Vec::new();
+        for face_info in db.registered_faces.iter().filter_map(|id| db.face(*id)) {
+            if !sources.iter().any(|source| same_source(source, &face_info.source)) {
+                sources.push(face_info.source.clone());
+            }
+        }
+        RegisteredFonts { sources }
+    })
+}
+
+/// Registers the fonts exported on another thread with [`export_registered_fonts`] on this
+/// thread, skipping the ones that are registered already. Returns the ids of their faces.
+pub fn import_registered_fonts(fonts: &RegisteredFonts) -> Vec<fontdb::ID> {
+    FONT_DB.with(|db| {
+        let mut db = db.borrow_mut();
+        fonts.sources.iter().flat_map(|source| db.register_source(source.clone())).collect()
+    })
+}
+
+/// Removes the fonts registered with [`register_font_from_memory`] and [`register_font_from_path`],
+/// while keeping the fonts of the system. Returns the ids of the faces that were removed, for
+/// the caches of loaded faces to evict them.
+pub fn reset_registered_fonts() -> Vec<fontdb::ID> {
+    FONT_DB.with(|db| {
+        let mut db = db.borrow_mut();
+        let registered_faces = core::mem::take(&mut db.registered_faces);
+        for id in &registered_faces {
+            db.remove_face(*id);
+        }
+        registered_faces
+    })
+}
+
+/// Returns the names of the font families that can be used with the `font-family` property: The
+/// families of the fonts installed on the system and of the registered fonts, sorted by name.
+pub fn available_families() -> Vec<crate::SharedString> {
+    let mut families = FONT_DB.with(|db| {
+        db.borrow()
+            .faces()
+            .filter_map(|face_info| face_info.families.first().map(|(family, _)| family.clone()))
+            .collect::<Vec<_>>()
     });
-    Ok(())
+    families.sort();
+    families.dedup();
+    families.into_iter().map(Into::into).collect()
+}
+
+/// Returns the weights and styles that the faces of the given font family are available in,
+/// sorted by weight. Returns an empty list if the family isn't available.
+pub fn available_faces(family: &str) -> Vec<(i32, crate::items::FontStyle)> {
+    use crate::items::FontStyle;
+    let mut faces = FONT_DB.with(|db| {
+        db.borrow()
+            .faces()
+            .filter(|face_info| {
+                face_info.families.first().map_or(false, |(name, _)| name.as_str() == family)
+            })
+            .map(|face_info| {
+                let style = match face_info.style {
+                    fontdb::Style::Normal => FontStyle::Normal,
+                    fontdb::Style::Italic => FontStyle::Italic,
+                    fontdb::Style::Oblique => FontStyle::Oblique,
+                };
+                (face_info.weight.0 as i32, style)
+            })
+            .collect::<Vec<_>>()
+    });
+    faces.sort_by_key(|(weight, style)| (*weight, *style as u8));
+    faces.dedup();
+    faces
 }
 
 #[cfg(not(target_arch = "wasm32"))]
-pub fn register_font_from_path(path: &std::path::Path) -> Result<(), Box<dyn std::error::Error>> {
+/// Registers the font file at the given path, like [`register_font_from_memory`]. Returns the ids of
+/// the faces of the file, also when it was loaded already.
+pub fn register_font_from_path(
+    path: &std::path::Path,
+) -> Result<Vec<fontdb::ID>, Box<dyn std::error::Error>> {
     let requested_path = path.canonicalize().unwrap_or_else(|_| path.to_owned());
     FONT_DB.with(|db| {
-        for face_info in db.borrow().faces() {
-            match &face_info.source {
-                fontdb::Source::Binary(_) => {}
-                fontdb::Source::File(loaded_path) | fontdb::Source::SharedFile(loaded_path, ..) => {
-                    if *loaded_path == requested_path {
-                        return Ok(());
-                    }
-                }
-            }
+        let loaded_faces =
+            db.borrow().faces_of_source(&fontdb::Source::File(requested_path.clone()));
+        if !loaded_faces.is_empty() {
+            return Ok(loaded_faces);
         }
 
-        db.borrow_mut().load_font_file(requested_path).map_err(|e| e.into())
+        let (result, added_faces) =
+            db.borrow_mut().register_faces(|db| db.load_font_file(requested_path));
+        result.map(|()| added_faces).map_err(|e| e.into())
     })
 }
 
 #[cfg(target_arch = "wasm32")]
-pub fn register_font_from_path(_path: &std::path::Path) -> Result<(), Box<dyn std::error::Error>> {
+pub fn register_font_from_path(
+    _path: &std::path::Path,
+) -> Result<Vec<fontdb::ID>, Box<dyn std::error::Error>> {
     return Err(std::io::Error::new(
         std::io::ErrorKind::Other,
         "Registering fonts from paths is not supported in WASM builds",
     )
     .into());
 }
+
+#[test]
+fn test_available_families_and_faces() {
+    register_font_from_memory(include_bytes!("sharedfontdb/DejaVuSans.ttf")).unwrap();
+
+    let families = available_families();
+    assert!(families.iter().any(|family| family == "DejaVu Sans"));
+    assert!(families.windows(2).all(|pair| pair[0] < pair[1]));
+
+    assert!(available_faces("DejaVu Sans").contains(&(400, crate::items::FontStyle::Normal)));
+    assert!(available_faces("Not a font family").is_empty());
+}
+
+#[test]
+fn test_reset_registered_fonts() {
+    let face_count = FONT_DB.with(|db| db.borrow().len());
+    let registered_faces =
+        register_font_from_memory(include_bytes!("sharedfontdb/DejaVuSans.ttf")).unwrap();
+    assert_eq!(registered_faces.len(), 1);
+    assert_eq!(FONT_DB.with(|db| db.borrow().len()), face_count + 1);
+    assert!(FONT_DB.with(|db| db.borrow().face(registered_faces[0]).is_some()));
+
+    assert_eq!(reset_registered_fonts(), registered_faces);
+    assert_eq!(FONT_DB.with(|db| db.borrow().len()), face_count);
+    assert!(reset_registered_fonts().is_empty());
+}
+
+#[test]
+fn test_register_font_from_memory_twice() {
+    let data = include_bytes!("sharedfontdb/DejaVuSans.ttf");
+    let registered_faces = register_font_from_memory(data).unwrap();
+    let face_count = FONT_DB.with(|db| db.borrow().len());
+
+    assert_eq!(register_font_from_memory(data).unwrap(), registered_faces);
+    // A copy of the data is recognized too
+    let copy: &'static [u8] = Box::leak(data.to_vec().into_boxed_slice());
+    assert_eq!(register_font_from_memory(copy).unwrap(), registered_faces);
+    assert_eq!(FONT_DB.with(|db| db.borrow().len()), face_count);
+}
+
+#[test]
+fn test_import_registered_fonts() {
+    let registered_faces =
+        register_font_from_memory(include_bytes!("sharedfontdb/DejaVuSans.ttf")).unwrap();
+    let exported_fonts = export_registered_fonts();
+    assert_eq!(exported_fonts.sources.len(), 1);
+
+    std::thread::spawn(move || {
+        let face_count = FONT_DB.with(|db| db.borrow().len());
+        let imported_faces = import_registered_fonts(&exported_fonts);
+        assert_eq!(imported_faces.len(), 1);
+        assert_eq!(FONT_DB.with(|db| db.borrow().len()), face_count + 1);
+        // Importing again doesn't load the fonts twice
+        assert_eq!(import_registered_fonts(&exported_fonts), imported_faces);
+        // The imported fonts are registered, so they are removed by a reset
+        assert_eq!(reset_registered_fonts(), imported_faces);
+    })
+    .join()
+    .unwrap();
+
+    assert_eq!(reset_registered_fonts(), registered_faces);
+}
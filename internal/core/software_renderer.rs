@@ -7,11 +7,13 @@
 
 mod draw_functions;
 mod fonts;
+#[cfg(feature = "std")]
+mod path;
 
 use crate::api::Window;
-use crate::graphics::{IntRect, PixelFormat, SharedImageBuffer, SharedPixelBuffer};
+use crate::graphics::{ImageLoadState, IntRect, PixelFormat, SharedImageBuffer, SharedPixelBuffer};
 use crate::item_rendering::ItemRenderer;
-use crate::items::{ImageFit, ItemRc, TextOverflow};
+use crate::items::{ImageFit, ImageRendering, ItemRc, TextOverflow};
 use crate::lengths::{
     LogicalLength, LogicalPoint, LogicalRect, LogicalSize, LogicalVector, PhysicalPx, PointLengths,
     RectLengths, ScaleFactor, SizeLengths,
@@ -121,6 +123,8 @@ pub struct SoftwareRenderer {
     /// This is the area which was dirty on the previous frame.
     /// Only used if repaint_buffer_type == RepaintBufferType::SwappedBuffers
     prev_frame_dirty: Cell<DirtyRegion>,
+    path_antialiasing: Cell<bool>,
+    box_shadow_cache: RefCell<draw_functions::BoxShadowCache>,
     window: Weak<dyn crate::window::WindowAdapter>,
 }
 
@@ -142,6 +146,19 @@ pub fn new(
             force_dirty: Default::default(),
             force_screen_refresh: Default::default(),
             prev_frame_dirty: Default::default(),
+            path_antialiasing: Cell::new(true),
+            box_shadow_cache: Default::default(),
+        }
+    }
+
+    /// Sets whether the edges of `Path` elements are anti-aliased, which is the default.
+    ///
+    /// Anti-aliasing samples each line of pixels several times, so disabling it makes the rendering
+    /// of paths faster on constrained hardware, at the cost of jagged edges. The whole window is
+    /// redrawn in the next frame.
+    pub fn set_path_antialiasing(&self, enabled: bool) {
+        if self.path_antialiasing.replace(enabled) != enabled {
+            self.force_screen_refresh.set(true);
         }
     }
 
@@ -200,6 +217,7 @@ pub fn render(&self, buffer: &mut [impl TargetPixel], pixel_stride: usize) -> Ph
             factor,
             window_inner,
             RenderToBuffer { buffer, stride: pixel_stride },
+            self,
         );
         let mut renderer = crate::item_rendering::PartialRenderer::new(
             &self.partial_cache,
@@ -222,13 +240,7 @@ pub fn render(&self, buffer: &mut [impl TargetPixel], pixel_stride: usize) -> Ph
                 LogicalLength::zero(),
             );
 
-            if !background.is_transparent() {
-                // FIXME: gradient
-                renderer
-                    .actual_renderer
-                    .processor
-                    .process_rectangle(to_draw, background.color().into());
-            }
+            renderer.actual_renderer.draw_window_background(&background, size, to_draw);
             for (component, origin) in components {
                 crate::item_rendering::render_component_items(component, &mut renderer, *origin);
             }
@@ -307,6 +319,14 @@ fn text_size(
         fonts::text_size(font_request, text, max_width, scale_factor)
     }
 
+    fn font_metrics(
+        &self,
+        font_request: crate::graphics::FontRequest,
+        scale_factor: ScaleFactor,
+    ) -> Option<crate::graphics::FontVerticalMetrics<LogicalLength>> {
+        fonts::vertical_metrics(&font_request, scale_factor)
+    }
+
     fn text_input_byte_offset_for_position(
         &self,
         text_input: Pin<&crate::items::TextInput>,
@@ -316,7 +336,11 @@ fn text_input_byte_offset_for_position(
     ) -> usize {
         let visual_representation = text_input.visual_representation(None);
 
-        let font = fonts::match_font(&font_request, scale_factor);
+        let Some(font) =
+            fonts::match_font(&font_request, scale_factor, &visual_representation.text)
+        else {
+            return 0;
+        };
 
         let width = (text_input.width().cast() * scale_factor).cast();
         let height = (text_input.height().cast() * scale_factor).cast();
@@ -375,7 +399,11 @@ fn text_input_cursor_rect_for_byte_offset(
     ) -> LogicalRect {
         let visual_representation = text_input.visual_representation(None);
 
-        let font = fonts::match_font(&font_request, scale_factor);
+        let Some(font) =
+            fonts::match_font(&font_request, scale_factor, &visual_representation.text)
+        else {
+            return LogicalRect::default();
+        };
 
         let width = (text_input.width().cast() * scale_factor).cast();
         let height = (text_input.height().cast() * scale_factor).cast();
@@ -457,7 +485,7 @@ fn register_font_from_memory(
         &self,
         data: &'static [u8],
     ) -> Result<(), Box<dyn std::error::Error>> {
-        self::fonts::systemfonts::register_font_from_memory(data)
+        self::fonts::systemfonts::register_font_from_memory(data).map(|_| ())
     }
 
     #[cfg(feature = "software-renderer-systemfonts")]
@@ -465,12 +493,68 @@ fn register_font_from_path(
         &self,
         path: &std::path::Path,
     ) -> Result<(), Box<dyn std::error::Error>> {
-        self::fonts::systemfonts::register_font_from_path(path)
+        self::fonts::systemfonts::register_font_from_path(path).map(|_| ())
+    }
+
+    #[cfg(feature = "software-renderer-systemfonts")]
+    fn reset_registered_fonts(&self) -> Result<(), Box<dyn std::error::Error>> {
+        self::fonts::systemfonts::reset_registered_fonts()
     }
 
     fn default_font_size(&self) -> LogicalLength {
         self::fonts::DEFAULT_FONT_SIZE
     }
+
+    fn grab_frame(
+        &self,
+    ) -> Result<SharedPixelBuffer<crate::graphics::Rgba8Pixel>, crate::platform::PlatformError>
+    {
+        let window = self.window.upgrade().ok_or("grab_frame() called on a destroyed Window")?;
+        let window_inner = WindowInner::from_pub(window.window());
+        let factor = ScaleFactor::new(window_inner.scale_factor());
+        let size = window.window().size();
+        let (width, height) = (size.width as usize, size.height as usize);
+        let mut buffer = vec![PremultipliedRgbaColor::default(); width * height];
+
+        // Render everything into a separate buffer. This doesn't go through the partial renderer
+        // so that the dirty tracking of the next frame on screen is not affected.
+        let physical_size = euclid::size2(width as _, height as _);
+        let mut renderer = SceneBuilder::new(
+            physical_size,
+            factor,
+            window_inner,
+            RenderToBuffer { buffer: buffer.as_mut_slice(), stride: width },
+            self,
+        );
+        window_inner.draw_contents(|components| {
+            let background = window_inner
+                .window_item()
+                .map(|item| item.as_pin_ref().background())
+                .unwrap_or_default();
+            renderer.draw_window_background(
+                &background,
+                physical_size,
+                euclid::Rect::from_size(physical_size),
+            );
+            for (component, origin) in components {
+                crate::item_rendering::render_component_items(component, &mut renderer, *origin);
+            }
+        });
+        drop(renderer);
+
+        let mut result = SharedPixelBuffer::new(width as u32, height as u32);
+        for (dst, src) in result.make_mut_slice().iter_mut().zip(buffer) {
+            let color =
+                Color::from_argb_u8(src.alpha, src.red, src.green, src.blue).unpremultiplied();
+            *dst = crate::graphics::Rgba8Pixel::new(
+                color.red(),
+                color.green(),
+                color.blue(),
+                color.alpha(),
+            );
+        }
+        Ok(result)
+    }
 }
 
 fn render_window_frame_by_line(
@@ -480,14 +564,17 @@ fn render_window_frame_by_line(
     renderer: &SoftwareRenderer,
     mut line_buffer: impl LineBufferProvider,
 ) -> PhysicalRegion {
-    let mut scene = prepare_scene(window, size, renderer);
+    let mut scene = prepare_scene(window, size, &background, renderer);
 
     let dirty_region = scene.dirty_region;
 
     debug_assert!(scene.current_line >= dirty_region.origin.y_length());
 
-    // FIXME gradient
-    let background_color = background.color().into();
+    // Gradients are drawn as part of the scene, plain colors are blended into each line
+    let background_color = match background {
+        Brush::LinearGradient(_) => PremultipliedRgbaColor::default(),
+        _ => background.color().into(),
+    };
 
     while scene.current_line < dirty_region.origin.y_length() + dirty_region.size.height_length() {
         line_buffer.process_line(
@@ -789,6 +876,25 @@ struct SceneTexture<'a> {
     /// The alpha of this color is ignored. (it is supposed to be mixed in `Self::alpha`)
     color: Color,
     alpha: u8,
+    /// When set, the texture is scaled with a bilinear interpolation of the source pixels, and
+    /// `data` and `source_size` cover the whole source instead of only the part that is drawn.
+    smooth: Option<SmoothScaling>,
+}
+
+/// Maps the geometry of a texture that is scaled smoothly to its source.
+/// The values are in 1/65536th of a source pixel.
+#[derive(Clone, Copy, Debug)]
+struct SmoothScaling {
+    /// The position in the source of the top left corner of the geometry
+    origin: euclid::default::Point2D<i32>,
+    /// The size in the source of one pixel of the geometry
+    step: euclid::default::Size2D<i32>,
+}
+
+impl SmoothScaling {
+    fn new(origin: euclid::default::Point2D<f32>, step: euclid::default::Size2D<f32>) -> Self {
+        Self { origin: (origin * 65536.).round().cast(), step: (step * 65536.).round().cast() }
+    }
 }
 
 enum SharedBufferData {
@@ -811,6 +917,7 @@ struct SharedBufferCommand {
     source_rect: PhysicalRect,
     colorize: Color,
     alpha: u8,
+    smooth: Option<SmoothScaling>,
 }
 
 impl SharedBufferCommand {
@@ -826,6 +933,7 @@ fn as_texture(&self) -> SceneTexture<'_> {
                 source_size: self.source_rect.size,
                 color: self.colorize,
                 alpha: self.alpha,
+                smooth: self.smooth,
             },
             SharedBufferData::SharedImage(SharedImageBuffer::RGBA8(b)) => SceneTexture {
                 data: &b.as_bytes()[begin * 4..],
@@ -834,6 +942,7 @@ fn as_texture(&self) -> SceneTexture<'_> {
                 source_size: self.source_rect.size,
                 color: self.colorize,
                 alpha: self.alpha,
+                smooth: self.smooth,
             },
             SharedBufferData::SharedImage(SharedImageBuffer::RGBA8Premultiplied(b)) => {
                 SceneTexture {
@@ -843,6 +952,7 @@ fn as_texture(&self) -> SceneTexture<'_> {
                     source_size: self.source_rect.size,
                     color: self.colorize,
                     alpha: self.alpha,
+                    smooth: self.smooth,
                 }
             }
             SharedBufferData::AlphaMap { data, width } => SceneTexture {
@@ -852,6 +962,7 @@ fn as_texture(&self) -> SceneTexture<'_> {
                 source_size: self.source_rect.size,
                 color: self.colorize,
                 alpha: self.alpha,
+                smooth: self.smooth,
             },
         }
     }
@@ -898,10 +1009,12 @@ struct GradientCommand {
 fn prepare_scene(
     window: &WindowInner,
     size: PhysicalSize,
+    background: &Brush,
     software_renderer: &SoftwareRenderer,
 ) -> Scene {
     let factor = ScaleFactor::new(window.scale_factor());
-    let prepare_scene = SceneBuilder::new(size, factor, window, PrepareScene::default());
+    let prepare_scene =
+        SceneBuilder::new(size, factor, window, PrepareScene::default(), software_renderer);
     let mut renderer = crate::item_rendering::PartialRenderer::new(
         &software_renderer.partial_cache,
         software_renderer.force_dirty.take(),
@@ -922,13 +1035,20 @@ fn prepare_scene(
             LogicalLength::zero(),
             LogicalLength::zero(),
         );
+        if let Brush::LinearGradient(_) = background {
+            renderer.actual_renderer.draw_window_background(background, size, dirty_region);
+        }
         for (component, origin) in components {
             crate::item_rendering::render_component_items(component, &mut renderer, *origin);
         }
     });
 
     let prepare_scene = renderer.into_inner();
-    Scene::new(prepare_scene.processor.items, prepare_scene.processor.vectors, dirty_region)
+    Scene::new(
+        prepare_scene.processor.target.items,
+        prepare_scene.processor.target.vectors,
+        dirty_region,
+    )
 }
 
 trait ProcessScene {
@@ -1074,12 +1194,104 @@ fn process_gradient(&mut self, geometry: PhysicalRect, gradient: GradientCommand
     }
 }
 
+/// An intermediate buffer into which the children of an `Opacity` item are rendered when
+/// they overlap, so that they are blended with the rest of the scene as a whole.
+///
+/// Rendering into a layer costs an allocation of the size of the visible part of the children
+/// and an extra blending pass for each of these pixels, so it is only used when needed.
+struct RenderToLayer {
+    geometry: PhysicalRect,
+    buffer: Vec<PremultipliedRgbaColor>,
+    /// The alpha with which the layer is blended once it is complete
+    alpha: f32,
+    /// The size of the state stack of the SceneBuilder when the layer was started.
+    /// The layer is complete when the state stack becomes smaller again.
+    state_depth: usize,
+}
+
+impl RenderToLayer {
+    fn with_target(
+        &mut self,
+        geometry: PhysicalRect,
+        f: impl FnOnce(&mut RenderToBuffer<PremultipliedRgbaColor>, PhysicalRect),
+    ) {
+        let stride = self.geometry.width() as usize;
+        let geometry = geometry.translate(-self.geometry.origin.to_vector());
+        f(&mut RenderToBuffer { buffer: &mut self.buffer, stride }, geometry)
+    }
+}
+
+impl ProcessScene for RenderToLayer {
+    fn process_texture(&mut self, geometry: PhysicalRect, texture: SceneTexture<'static>) {
+        self.with_target(geometry, |target, geometry| target.process_texture(geometry, texture))
+    }
+
+    fn process_rectangle(&mut self, geometry: PhysicalRect, color: PremultipliedRgbaColor) {
+        self.with_target(geometry, |target, geometry| target.process_rectangle(geometry, color))
+    }
+
+    fn process_rounded_rectangle(&mut self, geometry: PhysicalRect, data: RoundedRectangle) {
+        self.with_target(geometry, |target, geometry| {
+            target.process_rounded_rectangle(geometry, data)
+        })
+    }
+
+    fn process_shared_image_buffer(&mut self, geometry: PhysicalRect, buffer: SharedBufferCommand) {
+        self.with_target(geometry, |target, geometry| {
+            target.process_shared_image_buffer(geometry, buffer)
+        })
+    }
+
+    fn process_gradient(&mut self, geometry: PhysicalRect, gradient: GradientCommand) {
+        self.with_target(geometry, |target, geometry| target.process_gradient(geometry, gradient))
+    }
+}
+
+/// Forwards the scene to the innermost layer that is being rendered, or to the target if there is none
+struct LayeredProcessor<T> {
+    target: T,
+    /// The layers that are currently being rendered, the innermost last
+    layers: Vec<RenderToLayer>,
+}
+
+impl<T: ProcessScene> LayeredProcessor<T> {
+    fn current(&mut self) -> &mut dyn ProcessScene {
+        match self.layers.last_mut() {
+            Some(layer) => layer,
+            None => &mut self.target,
+        }
+    }
+}
+
+impl<T: ProcessScene> ProcessScene for LayeredProcessor<T> {
+    fn process_texture(&mut self, geometry: PhysicalRect, texture: SceneTexture<'static>) {
+        self.current().process_texture(geometry, texture)
+    }
+
+    fn process_rectangle(&mut self, geometry: PhysicalRect, color: PremultipliedRgbaColor) {
+        self.current().process_rectangle(geometry, color)
+    }
+
+    fn process_rounded_rectangle(&mut self, geometry: PhysicalRect, data: RoundedRectangle) {
+        self.current().process_rounded_rectangle(geometry, data)
+    }
+
+    fn process_shared_image_buffer(&mut self, geometry: PhysicalRect, buffer: SharedBufferCommand) {
+        self.current().process_shared_image_buffer(geometry, buffer)
+    }
+
+    fn process_gradient(&mut self, geometry: PhysicalRect, gradient: GradientCommand) {
+        self.current().process_gradient(geometry, gradient)
+    }
+}
+
 struct SceneBuilder<'a, T> {
-    processor: T,
+    processor: LayeredProcessor<T>,
     state_stack: Vec<RenderState>,
     current_state: RenderState,
     scale_factor: ScaleFactor,
     window: &'a WindowInner,
+    software_renderer: &'a SoftwareRenderer,
 }
 
 impl<'a, T: ProcessScene> SceneBuilder<'a, T> {
@@ -1088,9 +1300,10 @@ fn new(
         scale_factor: ScaleFactor,
         window: &'a WindowInner,
         processor: T,
+        software_renderer: &'a SoftwareRenderer,
     ) -> Self {
         Self {
-            processor,
+            processor: LayeredProcessor { target: processor, layers: vec![] },
             state_stack: vec![],
             current_state: RenderState {
                 alpha: 1.,
@@ -1102,15 +1315,122 @@ fn new(
             },
             scale_factor,
             window,
+            software_renderer,
         }
     }
 
+    /// Start rendering the children of an `Opacity` item into a layer. The layer is blended
+    /// once the state of the item is restored.
+    fn start_layer(&mut self, opacity: f32, self_rc: &ItemRc, size: LogicalSize) -> bool {
+        let current_clip = self.current_state.clip;
+        let children_rect = crate::properties::evaluate_no_tracking(|| {
+            LogicalRect::from(size).union(&crate::item_rendering::item_children_bounding_rect(
+                &self_rc.component(),
+                self_rc.index() as isize,
+                &current_clip,
+            ))
+        });
+        let layer_rect = match children_rect.intersection(&current_clip) {
+            Some(rect) if self.should_draw(&rect) => rect,
+            _ => return false,
+        };
+        let geometry: PhysicalRect =
+            (layer_rect.translate(self.current_state.offset.to_vector()).cast()
+                * self.scale_factor)
+                .round_out()
+                .cast();
+        self.processor.layers.push(RenderToLayer {
+            geometry,
+            buffer: vec![Default::default(); geometry.area() as usize],
+            alpha: self.current_state.alpha * opacity,
+            state_depth: self.state_stack.len(),
+        });
+        self.current_state.alpha = 1.;
+        self.current_state.clip = (geometry.cast() / self.scale_factor)
+            .cast()
+            .translate(-self.current_state.offset.to_vector())
+            .intersection(&layer_rect)
+            .unwrap_or_default();
+        true
+    }
+
+    /// Blend the innermost layer into its parent layer or the scene
+    fn finish_layer(&mut self) {
+        let layer = self.processor.layers.pop().unwrap();
+        let size = layer.geometry.size;
+        let mut buffer = SharedPixelBuffer::new(size.width as u32, size.height as u32);
+        for (dst, src) in buffer.make_mut_slice().iter_mut().zip(layer.buffer.iter()) {
+            *dst = crate::graphics::Rgba8Pixel::new(src.red, src.green, src.blue, src.alpha);
+        }
+        self.processor.process_shared_image_buffer(
+            layer.geometry,
+            SharedBufferCommand {
+                buffer: SharedBufferData::SharedImage(SharedImageBuffer::RGBA8Premultiplied(
+                    buffer,
+                )),
+                source_rect: PhysicalRect::from_size(size),
+                colorize: Default::default(),
+                alpha: (layer.alpha * 255.) as u8,
+                smooth: None,
+            },
+        );
+    }
+
     fn should_draw(&self, rect: &LogicalRect) -> bool {
         !rect.size.is_empty()
             && self.current_state.alpha > 0.01
             && self.current_state.clip.intersects(rect)
     }
 
+    /// Draws an alpha map computed by the path rasterizer, at its position in physical pixels.
+    #[cfg(feature = "std")]
+    fn draw_alpha_map(
+        &mut self,
+        alpha_map: Option<(euclid::default::Rect<i32>, Vec<u8>)>,
+        color: Color,
+    ) {
+        let Some((rect, data)) = alpha_map else { return };
+        let geometry = PhysicalRect::from_untyped(&rect.cast());
+        self.processor.process_shared_image_buffer(
+            geometry,
+            SharedBufferCommand {
+                buffer: SharedBufferData::AlphaMap {
+                    data: data.into(),
+                    width: rect.width() as u16,
+                },
+                source_rect: PhysicalRect::from_size(geometry.size),
+                colorize: color,
+                // color already is mixed with global alpha
+                alpha: color.alpha(),
+                smooth: None,
+            },
+        );
+    }
+
+    /// Draws the placeholder for images that failed to load: a gray frame on a light background.
+    fn draw_broken_image(&mut self, geom: LogicalRect) {
+        let background =
+            PremultipliedRgbaColor::from(self.alpha_color(Color::from_rgb_u8(0xf0, 0xf0, 0xf0)));
+        let frame =
+            PremultipliedRgbaColor::from(self.alpha_color(Color::from_rgb_u8(0xa0, 0xa0, 0xa0)));
+        let b = 1 as Coord;
+        let mut fill = |r: LogicalRect, color: PremultipliedRgbaColor| {
+            if let Some(r) = r.intersection(&self.current_state.clip) {
+                self.processor.process_rectangle(
+                    (r.translate(self.current_state.offset.to_vector()).cast() * self.scale_factor)
+                        .round()
+                        .cast(),
+                    color,
+                );
+            }
+        };
+        fill(geom.inflate(-b, -b), background);
+        fill(euclid::rect(0 as _, 0 as _, geom.width(), b), frame);
+        fill(euclid::rect(0 as _, geom.height() - b, geom.width(), b), frame);
+        fill(euclid::rect(0 as _, b, b, geom.height() - b - b), frame);
+        fill(euclid::rect(geom.width() - b, b, b, geom.height() - b - b), frame);
+    }
+
     fn draw_image_impl(
         &mut self,
         geom: LogicalRect,
@@ -1118,6 +1438,7 @@ fn draw_image_impl(
         mut source_rect: IntRect,
         image_fit: ImageFit,
         colorize: Color,
+        rendering: ImageRendering,
     ) {
         let global_alpha_u16 = (self.current_state.alpha * 255.) as u16;
         let image_inner: &ImageInner = source.into();
@@ -1188,6 +1509,20 @@ fn draw_image_impl(
                             + source_rect.origin.y as usize
                             - t.rect.origin.y as usize;
                         let stride = t.rect.width() as u16 * t.format.bpp() as u16;
+                        let smooth = (rendering == ImageRendering::Smooth
+                            && (source_to_target_x != 1. || source_to_target_y != 1.))
+                            .then(|| {
+                                let origin = target_rect.origin - offset;
+                                SmoothScaling::new(
+                                    euclid::point2(
+                                        origin.x / source_to_target_x + source_rect.origin.x as f32
+                                            - t.rect.origin.x as f32,
+                                        origin.y / source_to_target_y + source_rect.origin.y as f32
+                                            - t.rect.origin.y as f32,
+                                    ),
+                                    euclid::size2(1. / source_to_target_x, 1. / source_to_target_y),
+                                )
+                            });
                         let color = if colorize.alpha() > 0 { colorize } else { t.color };
                         let alpha = if colorize.alpha() > 0 || t.format == PixelFormat::AlphaMap {
                             color.alpha() as u16 * global_alpha_u16 / 255
@@ -1198,14 +1533,23 @@ fn draw_image_impl(
                         self.processor.process_texture(
                             target_rect.cast(),
                             SceneTexture {
-                                data: &data.as_slice()[(t.index
-                                    + (stride as usize) * actual_y
-                                    + (t.format.bpp()) * actual_x)..],
+                                data: if smooth.is_some() {
+                                    &data.as_slice()[t.index..]
+                                } else {
+                                    &data.as_slice()[(t.index
+                                        + (stride as usize) * actual_y
+                                        + (t.format.bpp()) * actual_x)..]
+                                },
                                 stride,
-                                source_size: clipped_relative_source_rect.size.ceil().cast(),
+                                source_size: if smooth.is_some() {
+                                    PhysicalSize::from_untyped(t.rect.size.cast())
+                                } else {
+                                    clipped_relative_source_rect.size.ceil().cast()
+                                },
                                 format: t.format,
                                 color,
                                 alpha,
+                                smooth,
                             },
                         );
                     }
@@ -1229,6 +1573,26 @@ fn draw_image_impl(
                             .translate(offset)
                             .round();
                         let buf_size = buffer.size().cast::<f32>();
+                        let buf_scale_x = buf_size.width / img_src_size.width as f32;
+                        let buf_scale_y = buf_size.height / img_src_size.height as f32;
+                        let step_x = buf_scale_x / source_to_target_x;
+                        let step_y = buf_scale_y / source_to_target_y;
+                        let smooth = (rendering == ImageRendering::Smooth
+                            && (step_x != 1. || step_y != 1.))
+                            .then(|| {
+                                let origin = target_rect.origin - offset;
+                                SmoothScaling::new(
+                                    euclid::point2(
+                                        (origin.x / source_to_target_x
+                                            + source_rect.origin.x as f32)
+                                            * buf_scale_x,
+                                        (origin.y / source_to_target_y
+                                            + source_rect.origin.y as f32)
+                                            * buf_scale_y,
+                                    ),
+                                    euclid::size2(step_x, step_y),
+                                )
+                            });
 
                         let alpha = if colorize.alpha() > 0 {
                             colorize.alpha() as u16 * global_alpha_u16 / 255
@@ -1239,19 +1603,25 @@ fn draw_image_impl(
                         self.processor.process_shared_image_buffer(
                             target_rect.cast(),
                             SharedBufferCommand {
-                                buffer: SharedBufferData::SharedImage(buffer),
-                                source_rect: clipped_relative_source_rect
-                                    .translate(
-                                        euclid::Point2D::from_untyped(source_rect.origin.cast())
+                                source_rect: if smooth.is_some() {
+                                    PhysicalRect::from_size(PhysicalSize::from_untyped(
+                                        buf_size.cast(),
+                                    ))
+                                } else {
+                                    clipped_relative_source_rect
+                                        .translate(
+                                            euclid::Point2D::from_untyped(
+                                                source_rect.origin.cast(),
+                                            )
                                             .to_vector(),
-                                    )
-                                    .scale(
-                                        buf_size.width / img_src_size.width as f32,
-                                        buf_size.height / img_src_size.height as f32,
-                                    )
-                                    .cast(),
+                                        )
+                                        .scale(buf_scale_x, buf_scale_y)
+                                        .cast()
+                                },
+                                buffer: SharedBufferData::SharedImage(buffer),
                                 colorize,
                                 alpha,
+                                smooth,
                             },
                         );
                     }
@@ -1276,7 +1646,10 @@ fn draw_text_paragraph<'b, Font: AbstractFont>(
             .layout_lines::<()>(|glyphs, line_x, line_y, _| {
                 let baseline_y = line_y + paragraph.layout.font.ascent();
                 while let Some(positioned_glyph) = glyphs.next() {
-                    let glyph = paragraph.layout.font.render_glyph(positioned_glyph.glyph_id);
+                    let glyph = paragraph
+                        .layout
+                        .font
+                        .render_glyph(positioned_glyph.glyph_id, positioned_glyph.font_index);
 
                     let src_rect = PhysicalRect::new(
                         PhysicalPoint::from_lengths(
@@ -1307,6 +1680,7 @@ fn draw_text_paragraph<'b, Font: AbstractFont>(
                                         color,
                                         // color already is mixed with global alpha
                                         alpha: color.alpha(),
+                                        smooth: None,
                                     },
                                 );
                             }
@@ -1325,6 +1699,22 @@ fn draw_text_paragraph<'b, Font: AbstractFont>(
                                         colorize: color,
                                         // color already is mixed with global alpha
                                         alpha: color.alpha(),
+                                        smooth: None,
+                                    },
+                                );
+                            }
+                            fonts::GlyphAlphaMap::Color(image) => {
+                                self.processor.process_shared_image_buffer(
+                                    geometry,
+                                    SharedBufferCommand {
+                                        buffer: SharedBufferData::SharedImage(image.clone()),
+                                        source_rect: PhysicalRect::new(
+                                            PhysicalPoint::new(actual_x as _, actual_y as _),
+                                            geometry.size,
+                                        ),
+                                        colorize: Default::default(),
+                                        alpha: color.alpha(),
+                                        smooth: None,
                                     },
                                 );
                             }
@@ -1336,6 +1726,123 @@ fn draw_text_paragraph<'b, Font: AbstractFont>(
             .ok();
     }
 
+    /// Fills the `region` of a window of the given `size` with its `background` brush.
+    /// Radial gradients are not supported and are filled with their first color.
+    fn draw_window_background(
+        &mut self,
+        background: &Brush,
+        size: PhysicalSize,
+        region: PhysicalRect,
+    ) {
+        match background {
+            Brush::LinearGradient(g) => {
+                let geom = (euclid::Rect::from_size(size).cast() / self.scale_factor).cast();
+                let clipped = (region.cast() / self.scale_factor).cast();
+                self.draw_linear_gradient(g, geom, clipped);
+            }
+            _ if background.is_transparent() => {}
+            _ => self.processor.process_rectangle(region, background.color().into()),
+        }
+    }
+
+    /// Draws the part of a rectangle of geometry `geom` filled with a linear gradient that
+    /// is within `clipped`.
+    fn draw_linear_gradient(
+        &mut self,
+        g: &crate::graphics::LinearGradientBrush,
+        geom: LogicalRect,
+        clipped: LogicalRect,
+    ) {
+        let geom2 = geom.cast() * self.scale_factor;
+        let clipped2 = clipped.cast() * self.scale_factor;
+        let act_rect = (clipped.translate(self.current_state.offset.to_vector()).cast()
+            * self.scale_factor)
+            .round()
+            .cast();
+
+        let angle = g.angle();
+
+        let tan = angle.to_radians().tan().abs();
+        let start = if !tan.is_finite() {
+            255.
+        } else {
+            let h = tan * geom2.width();
+            255. * h / (h + geom2.height())
+        } as u8;
+        let mut angle = angle as i32 % 360;
+        if angle < 0 {
+            angle += 360;
+        }
+        let mut stops = g.stops().copied().peekable();
+        let mut idx = 0;
+        let stop_count = g.stops().count();
+        while let (Some(mut s1), Some(mut s2)) = (stops.next(), stops.peek().copied()) {
+            let mut flags = 0;
+            if (angle % 180) > 90 {
+                flags |= 0b1;
+            }
+            if angle <= 90 || angle > 270 {
+                core::mem::swap(&mut s1, &mut s2);
+                s1.position = 1. - s1.position;
+                s2.position = 1. - s2.position;
+                if idx == 0 {
+                    flags |= 0b100;
+                }
+                if idx == stop_count - 2 {
+                    flags |= 0b010;
+                }
+            } else {
+                if idx == 0 {
+                    flags |= 0b010;
+                }
+                if idx == stop_count - 2 {
+                    flags |= 0b100;
+                }
+            }
+
+            idx += 1;
+
+            let (adjust_left, adjust_right) = if (angle % 180) > 90 {
+                (
+                    (geom2.width() * s1.position).floor() as i16,
+                    (geom2.width() * (1. - s2.position)).ceil() as i16,
+                )
+            } else {
+                (
+                    (geom2.width() * (1. - s2.position)).ceil() as i16,
+                    (geom2.width() * s1.position).floor() as i16,
+                )
+            };
+
+            let gr = GradientCommand {
+                color1: self.alpha_color(s1.color).into(),
+                color2: self.alpha_color(s2.color).into(),
+                start,
+                flags,
+                top_clip: Length::new(
+                    (clipped2.min_y() - geom2.min_y()) as i16
+                        - (geom2.height() * s1.position).floor() as i16,
+                ),
+                bottom_clip: Length::new(
+                    (geom2.max_y() - clipped2.max_y()) as i16
+                        - (geom2.height() * (1. - s2.position)).ceil() as i16,
+                ),
+                left_clip: Length::new((clipped2.min_x() - geom2.min_x()) as i16 - adjust_left),
+                right_clip: Length::new((geom2.max_x() - clipped2.max_x()) as i16 - adjust_right),
+            };
+
+            let size_y = act_rect.height_length() + gr.top_clip + gr.bottom_clip;
+            let size_x = act_rect.width_length() + gr.left_clip + gr.right_clip;
+            if size_x.get() == 0 || size_y.get() == 0 {
+                // the position are too close to each other
+                // FIXME: For the first or the last, we should draw a plain color to the end
+                continue;
+            }
+
+            self.processor.process_gradient(act_rect, gr);
+        }
+    }
+
     /// Returns the color, mixed with the current_state's alpha
     fn alpha_color(&self, color: Color) -> Color {
         if self.current_state.alpha < 1.0 {
@@ -1374,98 +1881,7 @@ fn draw_rectangle(
 
             let background = rect.background();
             if let Brush::LinearGradient(g) = background {
-                let geom2 = geom.cast() * self.scale_factor;
-                let clipped2 = clipped.cast() * self.scale_factor;
-                let act_rect = (clipped.translate(self.current_state.offset.to_vector()).cast()
-                    * self.scale_factor)
-                    .round()
-                    .cast();
-
-                let angle = g.angle();
-
-                let tan = angle.to_radians().tan().abs();
-                let start = if !tan.is_finite() {
-                    255.
-                } else {
-                    let h = tan * geom.width() as f32;
-                    255. * h / (h + geom.height() as f32)
-                } as u8;
-                let mut angle = angle as i32 % 360;
-                if angle < 0 {
-                    angle += 360;
-                }
-                let mut stops = g.stops().copied().peekable();
-                let mut idx = 0;
-                let stop_count = g.stops().count();
-                while let (Some(mut s1), Some(mut s2)) = (stops.next(), stops.peek().copied()) {
-                    let mut flags = 0;
-                    if (angle % 180) > 90 {
-                        flags |= 0b1;
-                    }
-                    if angle <= 90 || angle > 270 {
-                        core::mem::swap(&mut s1, &mut s2);
-                        s1.position = 1. - s1.position;
-                        s2.position = 1. - s2.position;
-                        if idx == 0 {
-                            flags |= 0b100;
-                        }
-                        if idx == stop_count - 2 {
-                            flags |= 0b010;
-                        }
-                    } else {
-                        if idx == 0 {
-                            flags |= 0b010;
-                        }
-                        if idx == stop_count - 2 {
-                            flags |= 0b100;
-                        }
-                    }
-
-                    idx += 1;
-
-                    let (adjust_left, adjust_right) = if (angle % 180) > 90 {
-                        (
-                            (geom2.width() * s1.position).floor() as i16,
-                            (geom2.width() * (1. - s2.position)).ceil() as i16,
-                        )
-                    } else {
-                        (
-                            (geom2.width() * (1. - s2.position)).ceil() as i16,
-                            (geom2.width() * s1.position).floor() as i16,
-                        )
-                    };
-
-                    let gr = GradientCommand {
-                        color1: self.alpha_color(s1.color).into(),
-                        color2: self.alpha_color(s2.color).into(),
-                        start,
-                        flags,
-                        top_clip: Length::new(
-                            (clipped2.min_y() - geom2.min_y()) as i16
-                                - (geom2.height() * s1.position).floor() as i16,
-                        ),
-                        bottom_clip: Length::new(
-                            (geom2.max_y() - clipped2.max_y()) as i16
-                                - (geom2.height() * (1. - s2.position)).ceil() as i16,
-                        ),
-                        left_clip: Length::new(
-                            (clipped2.min_x() - geom2.min_x()) as i16 - adjust_left,
-                        ),
-                        right_clip: Length::new(
-                            (geom2.max_x() - clipped2.max_x()) as i16 - adjust_right,
-                        ),
-                    };
-
-                    let size_y = act_rect.height_length() + gr.top_clip + gr.bottom_clip;
-                    let size_x = act_rect.width_length() + gr.left_clip + gr.right_clip;
-                    if size_x.get() == 0 || size_y.get() == 0 {
-                        // the position are too close to each other
-                        // FIXME: For the first or the last, we should draw a plain color to the end
-                        continue;
-                    }
-
-                    self.processor.process_gradient(act_rect, gr);
-                }
+                self.draw_linear_gradient(&g, geom, clipped);
                 return;
             }
 
@@ -1495,8 +1911,9 @@ fn draw_border_rectangle(
         if self.should_draw(&geom) {
             let mut border = rect.border_width();
             let radius = rect.border_radius();
-            // FIXME: gradients
-            let color = self.alpha_color(rect.background().color());
+            // FIXME: gradients with a radius, and for the border
+            let background = rect.background();
+            let color = self.alpha_color(background.color());
             let border_color = if border.get() as f32 > 0.01 {
                 self.alpha_color(rect.border_color().color())
             } else {
@@ -1564,11 +1981,14 @@ fn draw_border_rectangle(
                 return;
             }
 
-            if color.alpha > 0 {
-                if let Some(r) = geom
-                    .inflate(-border.get(), -border.get())
-                    .intersection(&self.current_state.clip)
-                {
+            let inner = geom.inflate(-border.get(), -border.get());
+            if let Brush::LinearGradient(g) = background {
+                // The gradient is laid out on the whole rectangle, but only drawn within the border
+                if let Some(r) = inner.intersection(&self.current_state.clip) {
+                    self.draw_linear_gradient(&g, geom, r);
+                }
+            } else if color.alpha > 0 {
+                if let Some(r) = inner.intersection(&self.current_state.clip) {
                     self.processor.process_rectangle(
                         (r.translate(self.current_state.offset.to_vector()).cast()
                             * self.scale_factor)
@@ -1605,12 +2025,17 @@ fn draw_image(&mut self, image: Pin<&crate::items::ImageItem>, _: &ItemRc, size:
         let geom = LogicalRect::from(size);
         if self.should_draw(&geom) {
             let source = image.source();
+            if source.load_state() == ImageLoadState::Error {
+                self.draw_broken_image(geom);
+                return;
+            }
             self.draw_image_impl(
                 geom,
                 &source,
                 euclid::Rect::new(Default::default(), source.size().cast()),
                 image.image_fit(),
                 image.colorize().color(),
+                image.image_rendering(),
             );
         }
     }
@@ -1624,6 +2049,10 @@ fn draw_clipped_image(
         let geom = LogicalRect::from(size);
         if self.should_draw(&geom) {
             let source = image.source();
+            if source.load_state() == ImageLoadState::Error {
+                self.draw_broken_image(geom);
+                return;
+            }
 
             let source_clip_x = image.source_clip_x();
             let source_clip_y = image.source_clip_y();
@@ -1638,12 +2067,22 @@ fn draw_clipped_image(
                 source_clip_height = source_size.height as i32 - source_clip_y;
             }
 
+            // The source clip is clamped to the bounds of the image
+            let source_clip =
+                euclid::rect(source_clip_x, source_clip_y, source_clip_width, source_clip_height)
+                    .intersection(&IntRect::from_size(source_size.cast()));
+            let source_clip = match source_clip {
+                Some(source_clip) => source_clip,
+                None => return,
+            };
+
             self.draw_image_impl(
                 geom,
                 &source,
-                euclid::rect(source_clip_x, source_clip_y, source_clip_width, source_clip_height),
+                source_clip,
                 image.image_fit(),
                 image.colorize().color(),
+                image.image_rendering(),
             );
         }
     }
@@ -1674,7 +2113,9 @@ fn draw_text(&mut self, text: Pin<&crate::items::Text>, _: &ItemRc, size: Logica
         };
         let offset = self.current_state.offset.to_vector().cast() * self.scale_factor;
 
-        let font = fonts::match_font(&font_request, self.scale_factor);
+        let Some(font) = fonts::match_font(&font_request, self.scale_factor, &string) else {
+            return;
+        };
 
         match font {
             fonts::Font::PixelFont(pf) => {
@@ -1742,10 +2183,14 @@ fn draw_text_input(
         };
         let offset = self.current_state.offset.to_vector().cast() * self.scale_factor;
 
-        let font = fonts::match_font(&font_request, self.scale_factor);
-
         let text_visual_representation = text_input.visual_representation(None);
 
+        let Some(font) =
+            fonts::match_font(&font_request, self.scale_factor, &text_visual_representation.text)
+        else {
+            return;
+        };
+
         let cursor_pos_and_height = match font {
             fonts::Font::PixelFont(pf) => {
                 let paragraph = TextParagraphLayout {
@@ -1805,17 +2250,123 @@ fn draw_text_input(
     }
 
     #[cfg(feature = "std")]
-    fn draw_path(&mut self, _path: Pin<&crate::items::Path>, _: &ItemRc, _size: LogicalSize) {
-        // TODO
+    fn draw_path(&mut self, path: Pin<&crate::items::Path>, _: &ItemRc, size: LogicalSize) {
+        let geom = LogicalRect::from(size);
+        if !self.should_draw(&geom) {
+            return;
+        }
+        let Some((offset, path_events)) = path.fitted_path_events() else { return };
+
+        let origin = (self.current_state.offset.to_vector() + offset).cast::<f32>();
+        let scale = self.scale_factor.get();
+        let transform =
+            lyon_path::math::Transform::translation(origin.x, origin.y).then_scale(scale, scale);
+        // The path is flattened in logical coordinates, before scaling it to physical pixels
+        let tolerance = crate::graphics::PathDataIterator::DEFAULT_TOLERANCE / scale;
+        let clip =
+            (self.current_state.clip.translate(self.current_state.offset.to_vector()).cast()
+                * self.scale_factor)
+                .round_out()
+                .cast::<i32>()
+                .to_untyped();
+
+        // FIXME: gradients
+        let fill_color = self.alpha_color(path.fill().color());
+        if fill_color.alpha() > 0 {
+            let edges = path::Edges::fill(path_events.flattened(tolerance), &transform);
+            self.draw_alpha_map(
+                edges.rasterize(
+                    path.fill_rule(),
+                    self.software_renderer.path_antialiasing.get(),
+                    clip,
+                ),
+                fill_color,
+            );
+        }
+        let stroke_color = self.alpha_color(path.stroke().color());
+        let stroke_width = (path.stroke_width().cast() * self.scale_factor).get();
+        if stroke_color.alpha() > 0 && stroke_width > 0. {
+            let edges = match path.dashed_stroke_events(&path_events, tolerance) {
+                Some(dashes) => path::Edges::stroke(dashes.into_iter(), &transform, stroke_width),
+                None => {
+                    path::Edges::stroke(path_events.flattened(tolerance), &transform, stroke_width)
+                }
+            };
+            self.draw_alpha_map(
+                edges.rasterize(
+                    crate::items::FillRule::Nonzero,
+                    self.software_renderer.path_antialiasing.get(),
+                    clip,
+                ),
+                stroke_color,
+            );
+        }
     }
 
     fn draw_box_shadow(
         &mut self,
-        _box_shadow: Pin<&crate::items::BoxShadow>,
+        box_shadow: Pin<&crate::items::BoxShadow>,
         _: &ItemRc,
         _size: LogicalSize,
     ) {
-        // TODO
+        let color = self.alpha_color(box_shadow.color());
+        if color.alpha() == 0 {
+            return;
+        }
+        let blur = box_shadow.blur().max(LogicalLength::zero());
+        let geom = LogicalRect::new(
+            LogicalPoint::from_lengths(box_shadow.offset_x() - blur, box_shadow.offset_y() - blur),
+            LogicalSize::from_lengths(
+                box_shadow.width() + blur + blur,
+                box_shadow.height() + blur + blur,
+            ),
+        );
+        if !self.should_draw(&geom) {
+            return;
+        }
+
+        let to_physical = |l: LogicalLength| {
+            PhysicalLength::new((l.cast() * self.scale_factor).get().round() as i16)
+        };
+        let (map_size, data) = self.software_renderer.box_shadow_cache.borrow_mut().alpha_map(
+            PhysicalSize::from_lengths(
+                to_physical(box_shadow.width()),
+                to_physical(box_shadow.height()),
+            ),
+            to_physical(box_shadow.border_radius()),
+            to_physical(blur),
+        );
+        // The map is positioned from its rounded origin, so that it keeps its exact size
+        let target = PhysicalRect::new(
+            ((geom.origin + self.current_state.offset.to_vector()).cast() * self.scale_factor)
+                .round()
+                .cast(),
+            map_size,
+        );
+        let clip =
+            (self.current_state.clip.translate(self.current_state.offset.to_vector()).cast()
+                * self.scale_factor)
+                .round()
+                .cast();
+        let clipped = match target.intersection(&clip) {
+            Some(clipped) => clipped,
+            None => return,
+        };
+
+        self.processor.process_shared_image_buffer(
+            clipped,
+            SharedBufferCommand {
+                buffer: SharedBufferData::AlphaMap { data, width: map_size.width as u16 },
+                source_rect: PhysicalRect::new(
+                    (clipped.origin - target.origin).to_point(),
+                    clipped.size,
+                ),
+                colorize: color,
+                // color already is mixed with global alpha
+                alpha: color.alpha(),
+                smooth: None,
+            },
+        );
     }
 
     fn combine_clip(
@@ -1854,12 +2405,36 @@ fn apply_opacity(&mut self, opacity: f32) {
         self.current_state.alpha *= opacity;
     }
 
+    fn visit_opacity(
+        &mut self,
+        opacity_item: Pin<&crate::items::Opacity>,
+        self_rc: &ItemRc,
+        size: LogicalSize,
+    ) -> crate::items::RenderingResult {
+        let opacity = opacity_item.opacity();
+        if !crate::items::Opacity::need_layer(self_rc, opacity) {
+            // Children that don't overlap can be drawn directly with the opacity applied
+            self.apply_opacity(opacity);
+        } else if !self.start_layer(opacity, self_rc, size) {
+            return crate::items::RenderingResult::ContinueRenderingWithoutChildren;
+        }
+        crate::items::RenderingResult::ContinueRenderingChildren
+    }
+
     fn save_state(&mut self) {
         self.state_stack.push(self.current_state);
     }
 
     fn restore_state(&mut self) {
         self.current_state = self.state_stack.pop().unwrap();
+        if self
+            .processor
+            .layers
+            .last()
+            .map_or(false, |layer| self.state_stack.len() < layer.state_depth)
+        {
+            self.finish_layer();
+        }
     }
 
     fn scale_factor(&self) -> f32 {
@@ -1898,6 +2473,7 @@ fn draw_cached_pixmap(
                         ),
                         colorize: Default::default(),
                         alpha: (self.current_state.alpha * 255.) as u8,
+                        smooth: None,
                     },
                 );
             }
@@ -1919,6 +2495,28 @@ fn as_any(&mut self) -> Option<&mut dyn core::any::Any> {
 
 /// This is a minimal adapter for a Window that doesn't have any other feature than rendering
 /// using the software renderer.
+///
+/// It doesn't need a windowing system nor an event loop, so it can also be used to render a
+/// component into an image in memory, for example for tests or to generate thumbnails:
+///
+/// ```rust
+/// # use i_slint_core::software_renderer::MinimalSoftwareWindow;
+/// # use i_slint_core::graphics::{Rgb8Pixel, SharedPixelBuffer};
+/// # use i_slint_core::platform::{WindowAdapter, WindowEvent};
+/// fn render_to_buffer(
+///     window: &MinimalSoftwareWindow,
+///     scale_factor: f32,
+/// ) -> SharedPixelBuffer<Rgb8Pixel> {
+///     window.window().dispatch_event(WindowEvent::ScaleFactorChanged { scale_factor });
+///     let size = window.window().size();
+///     let mut buffer = SharedPixelBuffer::<Rgb8Pixel>::new(size.width, size.height);
+///     window.window().request_redraw();
+///     window.draw_if_needed(|renderer| {
+///         renderer.render(buffer.make_mut_slice(), size.width as usize);
+///     });
+///     buffer
+/// }
+/// ```
 pub struct MinimalSoftwareWindow {
     window: Window,
     renderer: SoftwareRenderer,
@@ -4,14 +4,19 @@
 //! This is the module for the functions that are drawing the pixels
 //! on the line buffer
 
-use super::{PhysicalLength, PhysicalRect};
+use super::{PhysicalLength, PhysicalRect, PhysicalSize};
 use crate::graphics::{PixelFormat, Rgb8Pixel};
 use crate::lengths::{PointLengths, RectLengths, SizeLengths};
 use crate::Color;
+use alloc::collections::BTreeMap;
+use alloc::rc::Rc;
+use alloc::{vec, vec::Vec};
 use derive_more::{Add, Mul, Sub};
 #[cfg(feature = "embedded-graphics")]
 use embedded_graphics::prelude::RgbColor as _;
 use integer_sqrt::IntegerSquareRoot;
+#[allow(unused)]
+use num_traits::Float;
 
 /// Draw one line of the texture in the line buffer
 pub(super) fn draw_texture_line(
@@ -20,71 +25,111 @@ pub(super) fn draw_texture_line(
     texture: &super::SceneTexture,
     line_buffer: &mut [impl TargetPixel],
 ) {
-    let super::SceneTexture { data, format, stride, source_size, color, alpha } = *texture;
+    let super::SceneTexture { data, format, stride, source_size, alpha, smooth, .. } = *texture;
+    let y = (line - span.origin.y_length()).cast::<usize>();
+    let line_buffer = &mut line_buffer[span.origin.x as usize
+        ..(span.origin.x_length() + span.size.width_length()).get() as usize];
+
+    if let Some(smooth) = smooth {
+        // Bilinear interpolation between the four source pixels around the center of the target pixel
+        let source_size = source_size.cast::<usize>();
+        let bpp = format.bpp();
+        let (y0, y1, fy) =
+            source_position(smooth.origin.y, smooth.step.height, y.get(), source_size.height);
+        let (y0, y1) = (y0 * stride as usize, y1 * stride as usize);
+        for (x, pix) in line_buffer.iter_mut().enumerate() {
+            let (x0, x1, fx) =
+                source_position(smooth.origin.x, smooth.step.width, x, source_size.width);
+            let (x0, x1) = (x0 * bpp, x1 * bpp);
+            let top = interpolate_color(
+                fx,
+                texture_pixel(texture, y0 + x0),
+                texture_pixel(texture, y0 + x1),
+            );
+            let bottom = interpolate_color(
+                fx,
+                texture_pixel(texture, y1 + x0),
+                texture_pixel(texture, y1 + x1),
+            );
+            pix.blend(interpolate_color(fy, top, bottom));
+        }
+        return;
+    }
+
     let source_size = source_size.cast::<usize>();
     let span_size = span.size.cast::<usize>();
     let bpp = format.bpp();
-    let y = (line - span.origin.y_length()).cast::<usize>();
     let y_pos = (y.get() * source_size.height / span_size.height) * stride as usize;
-
-    for (x, pix) in line_buffer
-        [span.origin.x as usize..(span.origin.x_length() + span.size.width_length()).get() as usize]
-        .iter_mut()
-        .enumerate()
-    {
+    for (x, pix) in line_buffer.iter_mut().enumerate() {
         let pos = y_pos + (x * source_size.width / span_size.width) * bpp;
-        let c = match format {
-            PixelFormat::Rgb => {
-                let p = &data[pos..pos + 3];
-                if alpha == 0xff {
-                    *pix = TargetPixel::from_rgb(p[0], p[1], p[2]);
-                    continue;
-                } else {
-                    PremultipliedRgbaColor::premultiply(Color::from_argb_u8(
-                        alpha, p[0], p[1], p[2],
-                    ))
+        if format == PixelFormat::Rgb && alpha == 0xff {
+            *pix = TargetPixel::from_rgb(data[pos], data[pos + 1], data[pos + 2]);
+        } else {
+            pix.blend(texture_pixel(texture, pos));
+        }
+    }
+}
+
+/// Returns the two source pixels around the center of the target pixel `i`, and the weight of
+/// the second one, between 0 and 255. `origin` and `step` are in 1/65536th of a source pixel,
+/// see [`super::SmoothScaling`]. Pixels outside of the source are clamped to its edge.
+fn source_position(origin: i32, step: i32, i: usize, source_len: usize) -> (usize, usize, u32) {
+    // relative to the center of the first source pixel
+    let pos = origin as i64 + step as i64 * i as i64 + step as i64 / 2 - 0x8000;
+    let pos = pos.clamp(0, ((source_len as i64) - 1).max(0) << 16);
+    let p0 = (pos >> 16) as usize;
+    let p1 = (p0 + 1).min(source_len.max(1) - 1);
+    (p0, p1, ((pos >> 8) & 0xff) as u32)
+}
+
+/// Returns the color of the pixel of the texture which starts at the byte `pos`
+fn texture_pixel(texture: &super::SceneTexture, pos: usize) -> PremultipliedRgbaColor {
+    let super::SceneTexture { data, format, color, alpha, .. } = *texture;
+    match format {
+        PixelFormat::Rgb => PremultipliedRgbaColor::premultiply(Color::from_argb_u8(
+            alpha,
+            data[pos],
+            data[pos + 1],
+            data[pos + 2],
+        )),
+        PixelFormat::Rgba => {
+            let alpha = ((data[pos + 3] as u16 * alpha as u16) / 255) as u8;
+            PremultipliedRgbaColor::premultiply(if color.alpha() == 0 {
+                Color::from_argb_u8(alpha, data[pos], data[pos + 1], data[pos + 2])
+            } else {
+                Color::from_argb_u8(alpha, color.red(), color.green(), color.blue())
+            })
+        }
+        PixelFormat::RgbaPremultiplied => {
+            if color.alpha() > 0 {
+                PremultipliedRgbaColor::premultiply(Color::from_argb_u8(
+                    ((data[pos + 3] as u16 * alpha as u16) / 255) as u8,
+                    color.red(),
+                    color.green(),
+                    color.blue(),
+                ))
+            } else if alpha == 0xff {
+                PremultipliedRgbaColor {
+                    alpha: data[pos + 3],
+                    red: data[pos],
+                    green: data[pos + 1],
+                    blue: data[pos + 2],
                 }
-            }
-            PixelFormat::Rgba => {
-                let alpha = ((data[pos + 3] as u16 * alpha as u16) / 255) as u8;
-                PremultipliedRgbaColor::premultiply(if color.alpha() == 0 {
-                    Color::from_argb_u8(alpha, data[pos + 0], data[pos + 1], data[pos + 2])
-                } else {
-                    Color::from_argb_u8(alpha, color.red(), color.green(), color.blue())
-                })
-            }
-            PixelFormat::RgbaPremultiplied => {
-                if color.alpha() > 0 {
-                    PremultipliedRgbaColor::premultiply(Color::from_argb_u8(
-                        ((data[pos + 3] as u16 * alpha as u16) / 255) as u8,
-                        color.red(),
-                        color.green(),
-                        color.blue(),
-                    ))
-                } else if alpha == 0xff {
-                    PremultipliedRgbaColor {
-                        alpha: data[pos + 3],
-                        red: data[pos + 0],
-                        green: data[pos + 1],
-                        blue: data[pos + 2],
-                    }
-                } else {
-                    PremultipliedRgbaColor {
-                        alpha: (data[pos + 3] as u16 * alpha as u16 / 255) as u8,
-                        red: (data[pos + 0] as u16 * alpha as u16 / 255) as u8,
-                        green: (data[pos + 1] as u16 * alpha as u16 / 255) as u8,
-                        blue: (data[pos + 2] as u16 * alpha as u16 / 255) as u8,
-                    }
+            } else {
+                PremultipliedRgbaColor {
+                    alpha: (data[pos + 3] as u16 * alpha as u16 / 255) as u8,
+                    red: (data[pos] as u16 * alpha as u16 / 255) as u8,
+                    green: (data[pos + 1] as u16 * alpha as u16 / 255) as u8,
+                    blue: (data[pos + 2] as u16 * alpha as u16 / 255) as u8,
                 }
             }
-            PixelFormat::AlphaMap => PremultipliedRgbaColor::premultiply(Color::from_argb_u8(
-                ((data[pos] as u16 * alpha as u16) / 255) as u8,
-                color.red(),
-                color.green(),
-                color.blue(),
-            )),
-        };
-        pix.blend(c);
+        }
+        PixelFormat::AlphaMap => PremultipliedRgbaColor::premultiply(Color::from_argb_u8(
+            ((data[pos] as u16 * alpha as u16) / 255) as u8,
+            color.red(),
+            color.green(),
+            color.blue(),
+        )),
     }
 }
 
@@ -402,6 +447,133 @@ pub(super) fn draw_gradient_line(
     }
 }
 
+/// Computes the alpha map of a box shadow: a rounded rectangle of the given size and radius,
+/// blurred with a Gaussian which is approximated by three successive box blurs, applied on the
+/// rows and then on the columns.
+///
+/// The map is `blur` pixels larger than the rectangle on each side. Returns its size and data.
+pub(super) fn box_shadow_alpha_map(
+    size: PhysicalSize,
+    radius: PhysicalLength,
+    blur: PhysicalLength,
+) -> (PhysicalSize, Vec<u8>) {
+    let blur = blur.get().max(0) as usize;
+    let width = size.width.max(0) as usize;
+    let height = size.height.max(0) as usize;
+    let map_width = width + 2 * blur;
+    let map_height = height + 2 * blur;
+    let mut data = vec![0u8; map_width * map_height];
+
+    let radius = (radius.get().max(0) as usize).min(width / 2).min(height / 2);
+    for y in 0..height {
+        // distance to the closest horizontal edge, so that all corners are handled the same way
+        let ym = y.min(height - 1 - y);
+        let line = &mut data[(y + blur) * map_width + blur..][..width];
+        for (x, pix) in line.iter_mut().enumerate() {
+            let xm = x.min(width - 1 - x);
+            *pix = if xm < radius && ym < radius {
+                let dx = radius as f32 - xm as f32 - 0.5;
+                let dy = radius as f32 - ym as f32 - 0.5;
+                let coverage = radius as f32 + 0.5 - (dx * dx + dy * dy).sqrt();
+                (coverage.clamp(0., 1.) * 255.) as u8
+            } else {
+                255
+            };
+        }
+    }
+
+    if blur > 0 {
+        // Like the other renderers, the blur is twice the standard deviation of the Gaussian.
+        // Three box blurs of width w have a variance of (w² - 1) / 4.
+        let sigma = blur as f32 / 2.;
+        let box_radius = (((4. * sigma * sigma + 1.).sqrt() - 1.) / 2.).round() as usize;
+        let mut tmp = vec![0u8; map_width.max(map_height)];
+        for _ in 0..3 {
+            for y in 0..map_height {
+                box_blur_line(&mut data[y * map_width..], 1, map_width, box_radius, &mut tmp);
+            }
+            for x in 0..map_width {
+                box_blur_line(&mut data[x..], map_width, map_height, box_radius, &mut tmp);
+            }
+        }
+    }
+
+    (PhysicalSize::new(map_width as _, map_height as _), data)
+}
+
+/// Replaces `len` values of `data` which are `step` elements apart by the average of
+/// the `2 * radius + 1` values around them. Values outside of the line count as zero.
+fn box_blur_line(data: &mut [u8], step: usize, len: usize, radius: usize, tmp: &mut [u8]) {
+    let window = 2 * radius as u32 + 1;
+    let mut sum: u32 = (0..len.min(radius + 1)).map(|i| data[i * step] as u32).sum();
+    for (i, t) in tmp[..len].iter_mut().enumerate() {
+        *t = ((sum + window / 2) / window) as u8;
+        if i + radius + 1 < len {
+            sum += data[(i + radius + 1) * step] as u32;
+        }
+        if i >= radius {
+            sum -= data[(i - radius) * step] as u32;
+        }
+    }
+    for (i, t) in tmp[..len].iter().enumerate() {
+        data[i * step] = *t;
+    }
+}
+
+/// The alpha maps of the box shadows that were drawn recently, so that shadows which don't
+/// change are not blurred again on every frame.
+///
+/// The maps are keyed by the physical size, radius and blur of the shadow. When they use more
+/// than [`Self::MAX_BYTES`], the least recently used ones are dropped.
+#[derive(Default)]
+pub(super) struct BoxShadowCache {
+    maps: BTreeMap<(i16, i16, i16, i16), BoxShadowCacheEntry>,
+    /// Incremented on every lookup, to know which map was used last
+    generation: u64,
+    bytes: usize,
+}
+
+struct BoxShadowCacheEntry {
+    size: PhysicalSize,
+    data: Rc<[u8]>,
+    last_used: u64,
+}
+
+impl BoxShadowCache {
+    const MAX_BYTES: usize = 512 * 1024;
+
+    /// Returns the same as [`box_shadow_alpha_map`], computing the map only if it isn't cached.
+    pub(super) fn alpha_map(
+        &mut self,
+        size: PhysicalSize,
+        radius: PhysicalLength,
+        blur: PhysicalLength,
+    ) -> (PhysicalSize, Rc<[u8]>) {
+        self.generation += 1;
+        let key = (size.width, size.height, radius.get(), blur.get());
+        if let Some(entry) = self.maps.get_mut(&key) {
+            entry.last_used = self.generation;
+            return (entry.size, entry.data.clone());
+        }
+
+        let (map_size, data) = box_shadow_alpha_map(size, radius, blur);
+        let data: Rc<[u8]> = data.into();
+        self.bytes += data.len();
+        self.maps.insert(
+            key,
+            BoxShadowCacheEntry { size: map_size, data: data.clone(), last_used: self.generation },
+        );
+        // The new map is kept even if it alone exceeds the budget
+        while self.bytes > Self::MAX_BYTES && self.maps.len() > 1 {
+            let oldest = self.maps.iter().min_by_key(|(_, entry)| entry.last_used).map(|(k, _)| *k);
+            if let Some(entry) = oldest.and_then(|oldest| self.maps.remove(&oldest)) {
+                self.bytes -= entry.data.len();
+            }
+        }
+        (map_size, data)
+    }
+}
+
 /// A color whose component have been pre-multiplied by alpha
 ///
 /// The renderer operates faster on pre-multiplied color since it
@@ -596,3 +768,60 @@ fn rgb565() {
     let pix888: Rgb8Pixel = pix565.into();
     assert_eq!(pix565, pix888.into());
 }
+
+#[test]
+fn box_shadow_map() {
+    let (size, map) = box_shadow_alpha_map(
+        PhysicalSize::new(10, 8),
+        PhysicalLength::new(3),
+        PhysicalLength::new(0),
+    );
+    assert_eq!(size, PhysicalSize::new(10, 8));
+    assert_eq!(map[0], 0);
+    assert_eq!(map[9], 0);
+    assert_eq!(map[7 * 10 + 9], 0);
+    assert_eq!(map[3 * 10], 255);
+    assert_eq!(map[5], 255);
+    // The corners are symmetric
+    for y in 0..8 {
+        for x in 0..10 {
+            assert_eq!(map[y * 10 + x], map[(7 - y) * 10 + 9 - x]);
+        }
+    }
+
+    let (size, map) = box_shadow_alpha_map(
+        PhysicalSize::new(40, 40),
+        PhysicalLength::new(0),
+        PhysicalLength::new(8),
+    );
+    assert_eq!(size, PhysicalSize::new(56, 56));
+    let at = |x: usize, y: usize| map[y * 56 + x];
+    assert_eq!(at(0, 0), 0);
+    assert_eq!(at(28, 28), 255);
+    // Half of the shadow is at the edge of the rectangle, and the blur is monotonic
+    assert!((100..160).contains(&at(8, 28)), "{}", at(8, 28));
+    for x in 0..28 {
+        assert!(at(x, 28) <= at(x + 1, 28));
+        assert_eq!(at(x, 28), at(55 - x, 28));
+        assert_eq!(at(x, 28), at(28, x));
+    }
+}
+
+#[test]
+fn box_shadow_cache() {
+    let mut cache = BoxShadowCache::default();
+    let (size, a) =
+        cache.alpha_map(PhysicalSize::new(10, 8), PhysicalLength::new(3), PhysicalLength::new(2));
+    assert_eq!(size, PhysicalSize::new(14, 12));
+    let (_, b) =
+        cache.alpha_map(PhysicalSize::new(10, 8), PhysicalLength::new(3), PhysicalLength::new(2));
+    assert!(Rc::ptr_eq(&a, &b));
+    let (_, c) =
+        cache.alpha_map(PhysicalSize::new(10, 8), PhysicalLength::new(3), PhysicalLength::new(4));
+    assert!(!Rc::ptr_eq(&a, &c));
+
+    // A map larger than the budget evicts all the others
+    cache.alpha_map(PhysicalSize::new(1000, 1000), PhysicalLength::new(0), PhysicalLength::new(0));
+    assert_eq!(cache.maps.len(), 1);
+    assert_eq!(cache.bytes, 1000 * 1000);
+}
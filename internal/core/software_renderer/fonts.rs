@@ -9,7 +9,7 @@
 use crate::thread_local_ as thread_local;
 
 use super::{PhysicalLength, PhysicalSize};
-use crate::graphics::{BitmapFont, FontRequest};
+use crate::graphics::{BitmapFont, FontRequest, FontVerticalMetrics};
 use crate::lengths::{LogicalLength, LogicalSize, ScaleFactor};
 use crate::textlayout::TextLayout;
 use crate::Coord;
@@ -22,6 +22,9 @@
 pub enum GlyphAlphaMap {
     Static(&'static [u8]),
     Shared(Rc<[u8]>),
+    /// The pixels of a glyph of a color font, such as an emoji. These are drawn as they are,
+    /// instead of being filled with the text color.
+    Color(crate::graphics::SharedImageBuffer),
 }
 
 #[derive(Clone)]
@@ -40,7 +43,9 @@ pub fn size(&self) -> PhysicalSize {
 }
 
 pub trait GlyphRenderer {
-    fn render_glyph(&self, glyph_id: core::num::NonZeroU16) -> RenderableGlyph;
+    /// Renders the glyph with the given id. `font_index` is the index of the font providing the glyph
+    /// in a chain of fallback fonts, as set in the glyphs produced when shaping.
+    fn render_glyph(&self, glyph_id: core::num::NonZeroU16, font_index: usize) -> RenderableGlyph;
 }
 
 pub(super) const DEFAULT_FONT_SIZE: LogicalLength = LogicalLength::new(12 as Coord);
@@ -56,10 +61,18 @@ pub trait GlyphRenderer {
 pub enum Font {
     PixelFont(pixelfont::PixelFont),
     #[cfg(feature = "software-renderer-systemfonts")]
-    VectorFont(vectorfont::VectorFont),
+    VectorFont(vectorfont::FontFallbackChain),
 }
 
-pub fn match_font(request: &FontRequest, scale_factor: ScaleFactor) -> Font {
+/// Returns the font to render `reference_text` with. For system fonts, this includes
+/// the fonts to fall back to for the characters that the matched font has no glyph for.
+/// Returns None if there are no system fonts at all, in which case no text is drawn until
+/// a font is registered.
+pub fn match_font(
+    request: &FontRequest,
+    scale_factor: ScaleFactor,
+    #[cfg_attr(not(feature = "software-renderer-systemfonts"), allow(unused))] reference_text: &str,
+) -> Option<Font> {
     let bitmap_font = BITMAP_FONTS.with(|fonts| {
         let fonts = fonts.borrow();
 
@@ -78,8 +91,9 @@ pub fn match_font(request: &FontRequest, scale_factor: ScaleFactor) -> Font {
         Some(bitmap_font) => bitmap_font,
         None => {
             #[cfg(feature = "software-renderer-systemfonts")]
-            if let Some(vectorfont) = systemfonts::match_font(request, scale_factor) {
-                return vectorfont.into();
+            if let Some(vectorfont) = systemfonts::match_font(request, scale_factor, reference_text)
+            {
+                return Some(vectorfont.into());
             }
             if let Some(fallback_bitmap_font) =
                 BITMAP_FONTS.with(|fonts| fonts.borrow().first().cloned())
@@ -87,7 +101,8 @@ pub fn match_font(request: &FontRequest, scale_factor: ScaleFactor) -> Font {
                 fallback_bitmap_font
             } else {
                 #[cfg(feature = "software-renderer-systemfonts")]
-                return systemfonts::fallbackfont(request.pixel_size, scale_factor).into();
+                return systemfonts::fallbackfont(request, scale_factor, reference_text)
+                    .map(Font::from);
                 #[cfg(not(feature = "software-renderer-systemfonts"))]
                 panic!("No font fallback found. The software renderer requires enabling the `EmbedForSoftwareRenderer` option when compiling slint files.")
             }
@@ -104,7 +119,7 @@ pub fn match_font(request: &FontRequest, scale_factor: ScaleFactor) -> Font {
 
     let matching_glyphs = &font.glyphs[nearest_pixel_size];
 
-    pixelfont::PixelFont { bitmap_font: font, glyphs: matching_glyphs }.into()
+    Some(pixelfont::PixelFont { bitmap_font: font, glyphs: matching_glyphs }.into())
 }
 
 pub fn text_layout_for_font<'a, Font: crate::textlayout::AbstractFont>(
@@ -118,7 +133,23 @@ pub fn text_layout_for_font<'a, Font: crate::textlayout::AbstractFont>(
     let letter_spacing =
         font_request.letter_spacing.map(|spacing| (spacing.cast() * scale_factor).cast());
 
-    TextLayout { font, letter_spacing }
+    let line_height =
+        font_request.line_height.map(|line_height| (line_height.cast() * scale_factor).cast());
+
+    TextLayout { font, letter_spacing, line_height }
+}
+
+/// Returns the vertical metrics of the font matching the request, in logical pixels.
+pub fn vertical_metrics(
+    font_request: &FontRequest,
+    scale_factor: ScaleFactor,
+) -> Option<FontVerticalMetrics<LogicalLength>> {
+    let metrics = match match_font(font_request, scale_factor, "")? {
+        Font::PixelFont(pf) => pf.vertical_metrics(),
+        #[cfg(feature = "software-renderer-systemfonts")]
+        Font::VectorFont(vf) => vf.vertical_metrics(),
+    };
+    Some(metrics.map(|length| (length.cast() / scale_factor).cast()))
 }
 
 pub fn register_bitmap_font(font_data: &'static BitmapFont) {
@@ -131,7 +162,9 @@ pub fn text_size(
     max_width: Option<LogicalLength>,
     scale_factor: ScaleFactor,
 ) -> LogicalSize {
-    let font = match_font(&font_request, scale_factor);
+    let Some(font) = match_font(&font_request, scale_factor, text) else {
+        return LogicalSize::default();
+    };
     let (longest_line_width, height) = match font {
         Font::PixelFont(pf) => {
             let layout = text_layout_for_font(&pf, &font_request, scale_factor);
@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-commercial
 
 use crate::{
-    graphics::{BitmapFont, BitmapGlyphs},
+    graphics::{BitmapFont, BitmapGlyphs, FontVerticalMetrics},
     software_renderer::PhysicalLength,
     textlayout::{Glyph, TextShaper},
 };
@@ -38,6 +38,24 @@ impl PixelFont {
     pub fn pixel_size(&self) -> PhysicalLength {
         self.glyphs.pixel_size()
     }
+    /// Returns the vertical metrics of the font. Bitmap fonts have no line gap, and the cap
+    /// height is the top of the glyph of the H.
+    pub fn vertical_metrics(&self) -> FontVerticalMetrics<PhysicalLength> {
+        use crate::textlayout::FontMetrics;
+        let cap_height = self.glyph_for_char('H').and_then(|glyph| glyph.glyph_id).map_or_else(
+            || self.ascent(),
+            |glyph_id| {
+                let glyph = self.render_glyph(glyph_id, 0);
+                glyph.y + glyph.height
+            },
+        );
+        FontVerticalMetrics {
+            ascent: self.ascent(),
+            descent: self.descent(),
+            line_gap: PhysicalLength::default(),
+            cap_height,
+        }
+    }
     pub fn glyph_index_to_glyph_id(index: usize) -> core::num::NonZeroU16 {
         core::num::NonZeroU16::new(index as u16 + 1).unwrap()
     }
@@ -47,7 +65,7 @@ pub fn glyph_id_to_glyph_index(id: core::num::NonZeroU16) -> usize {
 }
 
 impl GlyphRenderer for PixelFont {
-    fn render_glyph(&self, glyph_id: core::num::NonZeroU16) -> RenderableGlyph {
+    fn render_glyph(&self, glyph_id: core::num::NonZeroU16, _font_index: usize) -> RenderableGlyph {
         let glyph_index = Self::glyph_id_to_glyph_index(glyph_id);
         let bitmap_glyph = &self.glyphs.glyph_data[glyph_index];
         RenderableGlyph {
@@ -4,27 +4,30 @@
 use core::cell::RefCell;
 
 use alloc::rc::Rc;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
-use crate::lengths::{LogicalLength, ScaleFactor};
+use unicode_script::UnicodeScript;
+
+use crate::graphics::FontVariation;
+use crate::items::FontStyle;
+use crate::lengths::ScaleFactor;
 use crate::sharedfontdb;
 
 use super::super::PhysicalLength;
-use super::vectorfont::VectorFont;
+use super::vectorfont::{FontFallbackChain, SyntheticStyle, VectorFont};
 
 thread_local! {
-    static FALLBACK_FONT_ID: once_cell::unsync::Lazy<fontdb::ID> = once_cell::unsync::Lazy::new(|| {
+    /// The font set with the `SLINT_DEFAULT_FONT` environment variable, if any. The value is either the
+    /// path of a font file, or the name of an installed font family.
+    static DEFAULT_FONT_ID: once_cell::unsync::Lazy<Option<fontdb::ID>> = once_cell::unsync::Lazy::new(|| {
         crate::sharedfontdb::FONT_DB.with(|db| {
             let mut db = db.borrow_mut();
             std::env::var_os("SLINT_DEFAULT_FONT").and_then(|maybe_font_path| {
                 let path = std::path::Path::new(&maybe_font_path);
                 if path.extension().is_some() {
-                    let face_count = db.len();
-                    match db.load_font_file(path) {
-                        Ok(()) => {
-                            db.faces().nth(face_count).map(|face_info| face_info.id)
-                        },
-                        Err(err) => {
+                    match db.load_faces(|db| db.load_font_file(path)) {
+                        (Ok(()), added_faces) => added_faces.first().copied(),
+                        (Err(err), _) => {
                             eprintln!(
                                 "Could not load the font set via `SLINT_DEFAULT_FONT`: {}: {}", path.display(), err,
                             );
@@ -32,40 +35,308 @@
                         },
                     }
                 } else {
-                    eprintln!(
-                        "The environment variable `SLINT_DEFAULT_FONT` is set, but its value is not referring to a file",
-                    );
-                    None
+                    let family = maybe_font_path.to_string_lossy();
+                    let id = db.query(&fontdb::Query {
+                        families: &[fontdb::Family::Name(&family)],
+                        ..Default::default()
+                    });
+                    if id.is_none() {
+                        eprintln!(
+                            "The environment variable `SLINT_DEFAULT_FONT` is set, but its value is neither referring to a file nor to an installed font family: {}", family,
+                        );
+                    }
+                    id
                 }
-            }).unwrap_or_else(|| {
-                let query = fontdb::Query { families: &[fontdb::Family::SansSerif], ..Default::default() };
-
-                db.query(&query).expect("fatal: fontdb could not locate a sans-serif font on the system")
             })
         })
     })
 }
 
+/// The id of the face, which is different for each weight and style of a family, and the scale
+/// that fontdue optimizes the geometry of the glyphs for.
+type FontdueCacheKey = (fontdb::ID, u32);
+/// None for the faces that fontdue can't parse, so that they are skipped without trying again.
+type FontdueCacheEntry = Option<Rc<fontdue::Font>>;
+
+thread_local! {
+    static FONTDUE_FONTS: RefCell<HashMap<FontdueCacheKey, FontdueCacheEntry>> = Default::default();
+}
+
 thread_local! {
-    static FONTDUE_FONTS: RefCell<HashMap<fontdb::ID, Rc<fontdue::Font>>> = Default::default();
+    /// The installed families that requested family names without an exact match resolved to,
+    /// keyed by the requested name.
+    static CLOSEST_FAMILIES: RefCell<HashMap<String, Option<String>>> = Default::default();
+}
+
+/// The smallest scale that fontdue fonts are created for.
+const MIN_FONTDUE_SCALE: u32 = 16;
+
+/// Returns the scale to create the fontdue font with for rendering glyphs at the given pixel
+/// size. fontdue optimizes the geometry of the glyphs for that scale, so sizes are grouped by
+/// powers of two, to keep large text crisp without creating a font for each size.
+fn fontdue_scale(pixel_size: PhysicalLength) -> u32 {
+    (pixel_size.get().max(0) as u32).next_power_of_two().max(MIN_FONTDUE_SCALE)
 }
 
-fn get_or_create_fontdue_font(fontdb: &fontdb::Database, id: fontdb::ID) -> Rc<fontdue::Font> {
+/// Returns the fontdue font for the face, or None if the face can't be loaded, for example
+/// because its file is corrupt. A warning is printed the first time that a face fails.
+fn get_or_create_fontdue_font(
+    fontdb: &fontdb::Database,
+    id: fontdb::ID,
+    pixel_size: PhysicalLength,
+) -> Option<Rc<fontdue::Font>> {
+    let scale = fontdue_scale(pixel_size);
     FONTDUE_FONTS.with(|font_cache| {
         font_cache
             .borrow_mut()
-            .entry(id)
+            .entry((id, scale))
             .or_insert_with(|| {
-                fontdb
+                let font = fontdb
                     .with_face_data(id, |face_data, font_index| {
                         fontdue::Font::from_bytes(
                             face_data,
-                            fontdue::FontSettings { collection_index: font_index, scale: 40. },
+                            fontdue::FontSettings {
+                                collection_index: font_index,
+                                scale: scale as f32,
+                            },
                         )
-                        .expect("fatal: fontdue is unable to parse truetype font")
-                        .into()
                     })
-                    .unwrap()
+                    .unwrap_or(Err("the font data could not be read"));
+                match font {
+                    Ok(font) => Some(font.into()),
+                    Err(err) => {
+                        let family = fontdb
+                            .face(id)
+                            .and_then(|face_info| face_info.families.first())
+                            .map_or("", |(family, _)| family.as_str());
+                        eprintln!("Could not load the font {:?}, skipping it: {}", family, err);
+                        None
+                    }
+                }
+            })
+            .clone()
+    })
+}
+
+#[derive(Default)]
+struct FallbackFaces {
+    /// The faces found to have glyphs for the characters of a script, in the order they were found
+    by_script: HashMap<unicode_script::Script, Vec<fontdb::ID>>,
+    /// The characters that no face has a glyph for
+    missing: HashSet<char>,
+    /// The faces to search for characters of scripts without a face yet, in the order of
+    /// [`fallback_face_candidates`]. None until a character is looked up.
+    candidates: Option<Vec<fontdb::ID>>,
+}
+
+impl FallbackFaces {
+    /// Forgets the characters that had no face and the order of the candidates, as fonts were
+    /// added to or removed from the database.
+    fn invalidate(&mut self) {
+        self.missing.clear();
+        self.candidates = None;
+    }
+}
+
+thread_local! {
+    static FALLBACK_FACES: RefCell<FallbackFaces> = Default::default();
+}
+
+fn face_has_glyph(fontdb: &fontdb::Database, id: fontdb::ID, ch: char) -> bool {
+    fontdb
+        .with_face_data(id, |face_data, font_index| {
+            rustybuzz::ttf_parser::Face::parse(face_data, font_index)
+                .map_or(false, |face| face.glyph_index(ch).is_some())
+        })
+        .unwrap_or(false)
+}
+
+/// Returns the faces to search for a glyph missing in the requested font, in order of preference:
+/// The faces of the fallback families configured in fontconfig, if available, of the generic
+/// sans-serif and serif families, and then all other faces. Within each family, the regular upright
+/// faces come first.
+fn fallback_face_candidates(fontdb: &sharedfontdb::FontDatabase) -> Vec<fontdb::ID> {
+    let mut preferred_families: Vec<&str> = Vec::new();
+    #[cfg(not(any(
+        target_family = "windows",
+        target_os = "macos",
+        target_os = "ios",
+        target_arch = "wasm32"
+    )))]
+    preferred_families
+        .extend(fontdb.fontconfig_fallback_families.iter().map(|family| family.as_str()));
+    preferred_families.push(fontdb.family_name(&fontdb::Family::SansSerif));
+    preferred_families.push(fontdb.family_name(&fontdb::Family::Serif));
+
+    let mut faces = fontdb
+        .faces()
+        .map(|face_info| {
+            let family_rank = preferred_families
+                .iter()
+                .position(|family| face_info.families.iter().any(|(name, _)| name == family))
+                .unwrap_or(preferred_families.len());
+            let is_regular = face_info.style == fontdb::Style::Normal
+                && face_info.weight == fontdb::Weight::NORMAL;
+            ((family_rank, !is_regular), face_info.id)
+        })
+        .collect::<Vec<_>>();
+    faces.sort_by_key(|(preference, _)| *preference);
+    faces.into_iter().map(|(_, id)| id).collect()
+}
+
+/// Finds a face with a glyph for the given character, trying the faces that were found for other
+/// characters of the same script first.
+fn fallback_face_for_char(fontdb: &sharedfontdb::FontDatabase, ch: char) -> Option<fontdb::ID> {
+    FALLBACK_FACES.with(|fallback_faces| {
+        let FallbackFaces { by_script, missing, candidates } = &mut *fallback_faces.borrow_mut();
+        if missing.contains(&ch) {
+            return None;
+        }
+
+        let script_faces = by_script.entry(ch.script()).or_default();
+        if let Some(id) = script_faces.iter().find(|id| face_has_glyph(fontdb, **id, ch)) {
+            return Some(*id);
+        }
+
+        match candidates
+            .get_or_insert_with(|| fallback_face_candidates(fontdb))
+            .iter()
+            .find(|id| !script_faces.contains(id) && face_has_glyph(fontdb, **id, ch))
+        {
+            Some(id) => {
+                script_faces.push(*id);
+                Some(*id)
+            }
+            None => {
+                missing.insert(ch);
+                None
+            }
+        }
+    })
+}
+
+/// Returns the emphasis to synthesize when rendering the given face for the query. fontdb picks
+/// the closest weight and style that a family has, which may be a regular face.
+fn synthetic_style(
+    fontdb: &fontdb::Database,
+    id: fontdb::ID,
+    query: &fontdb::Query,
+) -> SyntheticStyle {
+    fontdb.face(id).map_or_else(Default::default, |face_info| SyntheticStyle {
+        bold: query.weight.0 >= fontdb::Weight::SEMIBOLD.0
+            && face_info.weight.0 < fontdb::Weight::SEMIBOLD.0,
+        italic: query.style != fontdb::Style::Normal && face_info.style == fontdb::Style::Normal,
+    })
+}
+
+/// Creates the chain of fonts to render `reference_text` with: The given primary font, followed by
+/// a fallback font for each of the characters it has no glyph for.
+fn fallback_chain(
+    fontdb: &sharedfontdb::FontDatabase,
+    primary: VectorFont,
+    reference_text: &str,
+    pixel_size: PhysicalLength,
+    request: &super::FontRequest,
+    query: &fontdb::Query,
+) -> FontFallbackChain {
+    let mut fallbacks: Vec<VectorFont> = Vec::new();
+    for ch in reference_text.chars().filter(|ch| !ch.is_control() && !ch.is_whitespace()) {
+        if primary.has_glyph(ch) || fallbacks.iter().any(|font| font.has_glyph(ch)) {
+            continue;
+        }
+        if let Some(id) = fallback_face_for_char(fontdb, ch) {
+            if id != primary.id() && !fallbacks.iter().any(|font| font.id() == id) {
+                let Some(fontdue_font) = get_or_create_fontdue_font(fontdb, id, pixel_size) else {
+                    continue;
+                };
+                let synthetic_style = synthetic_style(fontdb, id, query);
+                fallbacks.push(VectorFont::new(
+                    id,
+                    fontdue_font,
+                    pixel_size,
+                    synthetic_style,
+                    &request.features,
+                    &font_variations(request),
+                ));
+            }
+        }
+    }
+    FontFallbackChain::new(primary, fallbacks)
+}
+
+/// Returns the values of the variation axes requested, with the weight applied to the
+/// `wght` axis unless it was requested explicitly.
+fn font_variations(request: &super::FontRequest) -> Vec<FontVariation> {
+    let mut variations = request.variations.clone();
+    if let Some(weight) = request.weight {
+        if !variations.iter().any(|variation| variation.tag == *b"wght") {
+            variations.push(FontVariation { tag: *b"wght", value: weight as f32 });
+        }
+    }
+    variations
+}
+
+fn font_query<'a>(
+    families: &'a [fontdb::Family<'a>],
+    request: &super::FontRequest,
+) -> fontdb::Query<'a> {
+    fontdb::Query {
+        families,
+        weight: request
+            .weight
+            .map_or(fontdb::Weight::NORMAL, |weight| fontdb::Weight(weight as u16)),
+        style: match request.style {
+            FontStyle::Normal => fontdb::Style::Normal,
+            FontStyle::Italic => fontdb::Style::Italic,
+            FontStyle::Oblique => fontdb::Style::Oblique,
+        },
+        ..Default::default()
+    }
+}
+
+/// Normalizes a family name for matching names that are written slightly differently.
+fn normalize_family_name(name: &str) -> String {
+    name.chars()
+        .filter(|ch| !ch.is_whitespace() && *ch != '-' && *ch != '_')
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// Returns the family that best matches a requested family name that has no exact match: The
+/// family with the same name when ignoring case, spaces, dashes and underscores, or else the
+/// family whose name contains the requested one or is contained in it with the fewest other
+/// characters, such as "Helvetica Neue" for "Helvetica".
+fn closest_family<'a>(
+    family: &str,
+    families: impl IntoIterator<Item = &'a str>,
+) -> Option<&'a str> {
+    let requested = normalize_family_name(family);
+    // Don't match very short names, which are contained in too many others
+    const MIN_SUBSTRING_LENGTH: usize = 3;
+    families
+        .into_iter()
+        .filter_map(|candidate| {
+            let normalized = normalize_family_name(candidate);
+            let is_match = normalized == requested
+                || (normalized.len().min(requested.len()) >= MIN_SUBSTRING_LENGTH
+                    && (normalized.contains(&requested) || requested.contains(&normalized)));
+            is_match.then(|| (normalized.len().abs_diff(requested.len()), candidate))
+        })
+        .min()
+        .map(|(_, candidate)| candidate)
+}
+
+/// Returns the installed family that a requested family name without an exact match resolves to.
+fn resolve_family_name(fontdb: &fontdb::Database, family: &str) -> Option<String> {
+    CLOSEST_FAMILIES.with(|closest_families| {
+        closest_families
+            .borrow_mut()
+            .entry(family.into())
+            .or_insert_with(|| {
+                let families = fontdb
+                    .faces()
+                    .flat_map(|face_info| face_info.families.iter().map(|(name, _)| name.as_str()));
+                closest_family(family, families).map(String::from)
             })
             .clone()
     })
@@ -74,62 +345,164 @@ fn get_or_create_fontdue_font(fontdb: &fontdb::Database, id: fontdb::ID) -> Rc<f
 pub fn match_font(
     request: &super::FontRequest,
     scale_factor: super::ScaleFactor,
-) -> Option<VectorFont> {
+    reference_text: &str,
+) -> Option<FontFallbackChain> {
     request.family.as_ref().and_then(|family_str| {
-        let family = fontdb::Family::Name(family_str);
-
-        let query = fontdb::Query { families: &[family], ..Default::default() };
-
         let requested_pixel_size: PhysicalLength =
             (request.pixel_size.unwrap_or(super::DEFAULT_FONT_SIZE).cast() * scale_factor).cast();
 
         sharedfontdb::FONT_DB.with(|fonts| {
             let borrowed_fontdb = fonts.borrow();
-            borrowed_fontdb.query(&query).map(|font_id| {
-                let fontdue_font = get_or_create_fontdue_font(&*borrowed_fontdb, font_id);
-                VectorFont::new(font_id, fontdue_font.clone(), requested_pixel_size)
-            })
+
+            // Prefer the exact family name, and only then look for a similar one
+            let mut families = [fontdb::Family::Name(family_str)];
+            let closest_family;
+            let mut query = font_query(&families, request);
+            let font_id = match borrowed_fontdb.query(&query) {
+                Some(font_id) => font_id,
+                None => {
+                    closest_family = resolve_family_name(&borrowed_fontdb, family_str)?;
+                    families = [fontdb::Family::Name(&closest_family)];
+                    query = font_query(&families, request);
+                    borrowed_fontdb.query(&query)?
+                }
+            };
+            let fontdue_font =
+                get_or_create_fontdue_font(&borrowed_fontdb, font_id, requested_pixel_size)?;
+            let synthetic_style = synthetic_style(&borrowed_fontdb, font_id, &query);
+            let font = VectorFont::new(
+                font_id,
+                fontdue_font,
+                requested_pixel_size,
+                synthetic_style,
+                &request.features,
+                &font_variations(request),
+            );
+            Some(fallback_chain(
+                &borrowed_fontdb,
+                font,
+                reference_text,
+                requested_pixel_size,
+                request,
+                &query,
+            ))
         })
     })
 }
 
-pub fn fallbackfont(pixel_size: Option<LogicalLength>, scale_factor: ScaleFactor) -> VectorFont {
+/// Returns the font set with `SLINT_DEFAULT_FONT`, or else a sans-serif font, or else any font
+/// that can be loaded.
+/// Returns None if there are no fonts at all, such as on minimal embedded systems where the
+/// application has yet to register a font.
+pub fn fallbackfont(
+    request: &super::FontRequest,
+    scale_factor: ScaleFactor,
+    reference_text: &str,
+) -> Option<FontFallbackChain> {
     let requested_pixel_size: PhysicalLength =
-        (pixel_size.unwrap_or(super::DEFAULT_FONT_SIZE).cast() * scale_factor).cast();
+        (request.pixel_size.unwrap_or(super::DEFAULT_FONT_SIZE).cast() * scale_factor).cast();
 
-    let fallback_font_id = FALLBACK_FONT_ID.with(|id| **id);
+    let default_font_id = DEFAULT_FONT_ID.with(|id| **id);
+    let families = [fontdb::Family::SansSerif];
+    let query = font_query(&families, request);
 
-    sharedfontdb::FONT_DB
-        .with(|fonts| {
-            let fonts_borrowed = fonts.borrow();
+    sharedfontdb::FONT_DB.with(|fonts| {
+        let fonts_borrowed = fonts.borrow();
 
-            let fontdue_font = get_or_create_fontdue_font(&*fonts_borrowed, fallback_font_id);
-            VectorFont::new(fallback_font_id, fontdue_font, requested_pixel_size)
-        })
-        .into()
+        // Skip the faces that can't be loaded
+        let (fallback_font_id, fontdue_font) = default_font_id
+            .into_iter()
+            .chain(fonts_borrowed.query(&query))
+            .chain(fonts_borrowed.faces().map(|face_info| face_info.id))
+            .find_map(|id| {
+                Some((id, get_or_create_fontdue_font(&fonts_borrowed, id, requested_pixel_size)?))
+            })?;
+        let synthetic_style = synthetic_style(&fonts_borrowed, fallback_font_id, &query);
+        let font = VectorFont::new(
+            fallback_font_id,
+            fontdue_font,
+            requested_pixel_size,
+            synthetic_style,
+            &request.features,
+            &font_variations(request),
+        );
+        Some(fallback_chain(
+            &fonts_borrowed,
+            font,
+            reference_text,
+            requested_pixel_size,
+            request,
+            &query,
+        ))
+    })
 }
 
-pub fn register_font_from_memory(data: &'static [u8]) -> Result<(), Box<dyn std::error::Error>> {
-    sharedfontdb::FONT_DB.with(|fonts| {
-        fonts.borrow_mut().load_font_source(fontdb::Source::Binary(std::sync::Arc::new(data)))
+pub fn register_font_from_memory(
+    data: &'static [u8],
+) -> Result<Vec<fontdb::ID>, Box<dyn std::error::Error>> {
+    // The registered fonts may match the requested families better, and have the glyphs
+    // that were missing
+    CLOSEST_FAMILIES.with(|closest_families| closest_families.borrow_mut().clear());
+    FALLBACK_FACES.with(|fallback_faces| fallback_faces.borrow_mut().invalidate());
+    sharedfontdb::register_font_from_memory(data)
+}
+
+pub fn register_font_from_path(
+    path: &std::path::Path,
+) -> Result<Vec<fontdb::ID>, Box<dyn std::error::Error>> {
+    CLOSEST_FAMILIES.with(|closest_families| closest_families.borrow_mut().clear());
+    FALLBACK_FACES.with(|fallback_faces| fallback_faces.borrow_mut().invalidate());
+    sharedfontdb::register_font_from_path(path)
+}
+
+/// Removes the registered fonts from the font database, and the fonts loaded from them from the caches.
+pub fn reset_registered_fonts() -> Result<(), Box<dyn std::error::Error>> {
+    let removed_faces = sharedfontdb::reset_registered_fonts();
+    FONTDUE_FONTS.with(|fonts| fonts.borrow_mut().retain(|(id, _), _| !removed_faces.contains(id)));
+    FALLBACK_FACES.with(|fallback_faces| {
+        let mut fallback_faces = fallback_faces.borrow_mut();
+        for faces in fallback_faces.by_script.values_mut() {
+            faces.retain(|id| !removed_faces.contains(id));
+        }
+        fallback_faces.invalidate();
     });
+    super::vectorfont::evict_glyphs(&removed_faces);
+    CLOSEST_FAMILIES.with(|closest_families| closest_families.borrow_mut().clear());
     Ok(())
 }
 
-pub fn register_font_from_path(path: &std::path::Path) -> Result<(), Box<dyn std::error::Error>> {
-    let requested_path = path.canonicalize().unwrap_or_else(|_| path.to_owned());
-    sharedfontdb::FONT_DB.with(|fonts| {
-        for face_info in fonts.borrow().faces() {
-            match &face_info.source {
-                fontdb::Source::Binary(_) => {}
-                fontdb::Source::File(loaded_path) | fontdb::Source::SharedFile(loaded_path, ..) => {
-                    if *loaded_path == requested_path {
-                        return Ok(());
-                    }
-                }
-            }
-        }
+#[test]
+fn test_fontdue_scale() {
+    assert_eq!(fontdue_scale(PhysicalLength::new(0)), MIN_FONTDUE_SCALE);
+    assert_eq!(fontdue_scale(PhysicalLength::new(12)), 16);
+    assert_eq!(fontdue_scale(PhysicalLength::new(17)), 32);
+    assert_eq!(fontdue_scale(PhysicalLength::new(64)), 64);
+    assert_eq!(fontdue_scale(PhysicalLength::new(100)), 128);
+}
 
-        fonts.borrow_mut().load_font_file(requested_path).map_err(|e| e.into())
-    })
+#[test]
+fn test_closest_family() {
+    let families = ["Helvetica Neue", "Noto Sans", "Noto Sans Mono", "DejaVu Sans", "Sans"];
+    assert_eq!(closest_family("helvetica", families), Some("Helvetica Neue"));
+    assert_eq!(closest_family("noto-sans", families), Some("Noto Sans"));
+    assert_eq!(closest_family("NotoSansMono", families), Some("Noto Sans Mono"));
+    assert_eq!(closest_family("DejaVu Sans Book", families), Some("DejaVu Sans"));
+    assert_eq!(closest_family("Arial", families), None);
+    // Short names are only matched when they are equal
+    assert_eq!(closest_family("no", families), None);
+}
+
+#[test]
+fn test_registering_font_forgets_missing_characters() {
+    FALLBACK_FACES.with(|fallback_faces| {
+        let mut fallback_faces = fallback_faces.borrow_mut();
+        fallback_faces.missing.insert('\u{e000}');
+        fallback_faces.candidates = Some(Vec::new());
+    });
+    register_font_from_memory(include_bytes!("../../sharedfontdb/DejaVuSans.ttf")).unwrap();
+    FALLBACK_FACES.with(|fallback_faces| {
+        let fallback_faces = fallback_faces.borrow();
+        assert!(fallback_faces.missing.is_empty());
+        assert!(fallback_faces.candidates.is_none());
+    });
 }
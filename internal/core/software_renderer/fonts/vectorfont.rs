@@ -2,7 +2,9 @@
 // SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-commercial
 
 use alloc::rc::Rc;
+use alloc::vec::Vec;
 
+use crate::graphics::{FontFeature, FontVariation, FontVerticalMetrics};
 use crate::lengths::PhysicalPx;
 use crate::sharedfontdb::{self};
 use crate::software_renderer::PhysicalLength;
@@ -15,7 +17,44 @@
 type FontLength = euclid::Length<i32, FontUnit>;
 type FontScaleFactor = euclid::Scale<f32, FontUnit, PhysicalPx>;
 
-type GlyphCacheKey = (fontdb::ID, PhysicalLength, core::num::NonZeroU16);
+type GlyphCacheKey =
+    (fontdb::ID, PhysicalLength, core::num::NonZeroU16, SyntheticStyle, AxisValues);
+
+/// The slope of synthetic italic glyphs, the tangent of about 11 degrees.
+const SYNTHETIC_ITALIC_SLANT: f32 = 0.2;
+
+/// The emphasis to synthesize when rendering with a face that doesn't have the requested
+/// weight or style.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash, Debug)]
+pub struct SyntheticStyle {
+    /// Embolden the glyphs, as a bold face was requested but the face is lighter.
+    pub bold: bool,
+    /// Slant the glyphs, as an italic or oblique face was requested but the face is upright.
+    pub italic: bool,
+}
+
+/// The values of the variation axes of a font. The values are stored as bits so that two
+/// instances of the same variable font have distinct glyph cache keys.
+#[derive(Clone, Default, PartialEq, Eq, Hash, Debug)]
+struct AxisValues(Rc<[(rustybuzz::Tag, u32)]>);
+
+impl AxisValues {
+    fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    fn variations(&self) -> impl Iterator<Item = rustybuzz::Variation> + '_ {
+        self.0
+            .iter()
+            .map(|(tag, value)| rustybuzz::Variation { tag: *tag, value: f32::from_bits(*value) })
+    }
+
+    fn apply(&self, face: &mut rustybuzz::ttf_parser::Face) {
+        for variation in self.variations() {
+            face.set_variation(variation.tag, variation.value);
+        }
+    }
+}
 
 struct RenderableGlyphWeightScale;
 
@@ -24,6 +63,9 @@ fn weight(&self, _: &GlyphCacheKey, value: &RenderableGlyph) -> usize {
         match &value.alpha_map {
             super::GlyphAlphaMap::Static(_) => 0,
             super::GlyphAlphaMap::Shared(data) => data.len(),
+            super::GlyphAlphaMap::Color(image) => {
+                image.width() as usize * image.height() as usize * 4
+            }
         }
     }
 }
@@ -44,14 +86,30 @@ fn weight(&self, _: &GlyphCacheKey, value: &RenderableGlyph) -> usize {
     )
 );
 
+/// Removes the rendered glyphs of the given faces from the cache.
+pub fn evict_glyphs(faces: &[fontdb::ID]) {
+    GLYPH_CACHE.with(|cache| cache.borrow_mut().retain(|(id, ..), _| !faces.contains(id)));
+}
+
 pub struct VectorFont {
     id: fontdb::ID,
     fontdue_font: Rc<fontdue::Font>,
     ascender: PhysicalLength,
     descender: PhysicalLength,
     height: PhysicalLength,
+    line_gap: PhysicalLength,
+    cap_height: PhysicalLength,
     scale: FontScaleFactor,
     pixel_size: PhysicalLength,
+    /// True if the font has color bitmaps for its glyphs, in the CBDT or sbix table, and the
+    /// image decoders needed to render them are enabled.
+    has_color_bitmaps: bool,
+    synthetic_style: SyntheticStyle,
+    /// The OpenType features applied when shaping, in addition to the default ones
+    features: Vec<rustybuzz::Feature>,
+    /// The values of the variation axes of the face. As fontdue can't vary fonts, the glyphs
+    /// of a varied font are rasterized from their outlines instead.
+    axis_values: AxisValues,
 }
 
 impl VectorFont {
@@ -59,6 +117,9 @@ pub fn new(
         id: fontdb::ID,
         fontdue_font: Rc<fontdue::Font>,
         pixel_size: PhysicalLength,
+        mut synthetic_style: SyntheticStyle,
+        features: &[FontFeature],
+        variations: &[FontVariation],
     ) -> Self {
         sharedfontdb::FONT_DB.with(|db| {
             db.borrow()
@@ -68,21 +129,263 @@ pub fn new(
                     let ascender = FontLength::new(face.ascender() as _);
                     let descender = FontLength::new(face.descender() as _);
                     let height = FontLength::new(face.height() as _);
+                    let line_gap = FontLength::new(face.line_gap() as _);
+                    // Fonts without a recent OS/2 table don't have the cap height, use the
+                    // height of the H instead.
+                    let cap_height = FontLength::new(
+                        face.capital_height()
+                            .or_else(|| {
+                                let glyph_id = face.glyph_index('H')?;
+                                Some(face.glyph_bounding_box(glyph_id)?.y_max)
+                            })
+                            .unwrap_or_else(|| face.ascender()) as _,
+                    );
                     let units_per_em = face.units_per_em();
                     let scale = FontScaleFactor::new(pixel_size.get() as f32 / units_per_em as f32);
+                    let has_color_bitmaps = cfg!(feature = "image-decoders")
+                        && (face.tables().cbdt.is_some() || face.tables().sbix.is_some());
+
+                    // Only keep the values of the axes that the face has, so that all the
+                    // instances of a static face share their glyphs.
+                    let axes = face.variation_axes();
+                    let axis_values: Rc<[_]> = variations
+                        .iter()
+                        .map(|variation| {
+                            (rustybuzz::Tag::from_bytes(&variation.tag), variation.value)
+                        })
+                        .filter_map(|(tag, value)| {
+                            let axis = axes.into_iter().find(|axis| axis.tag == tag)?;
+                            Some((tag, value.clamp(axis.min_value, axis.max_value).to_bits()))
+                        })
+                        .collect();
+                    // A face with a weight axis renders the requested weight itself.
+                    if axis_values.iter().any(|(tag, _)| tag.to_bytes() == *b"wght") {
+                        synthetic_style.bold = false;
+                    }
                     Self {
                         id,
                         fontdue_font,
                         ascender: (ascender.cast() * scale).cast(),
                         descender: (descender.cast() * scale).cast(),
                         height: (height.cast() * scale).cast(),
+                        line_gap: (line_gap.cast() * scale).cast(),
+                        cap_height: (cap_height.cast() * scale).cast(),
                         scale,
                         pixel_size,
+                        has_color_bitmaps,
+                        synthetic_style,
+                        features: features
+                            .iter()
+                            .map(|feature| {
+                                rustybuzz::Feature::new(
+                                    rustybuzz::Tag::from_bytes(&feature.tag),
+                                    feature.value,
+                                    ..,
+                                )
+                            })
+                            .collect(),
+                        axis_values: AxisValues(axis_values),
                     }
                 })
                 .unwrap()
         })
     }
+
+    pub fn id(&self) -> fontdb::ID {
+        self.id
+    }
+
+    /// Returns the vertical metrics of the font, from its hhea and OS/2 tables.
+    pub fn vertical_metrics(&self) -> FontVerticalMetrics<PhysicalLength> {
+        FontVerticalMetrics {
+            ascent: self.ascender,
+            descent: self.descender,
+            line_gap: self.line_gap,
+            cap_height: self.cap_height,
+        }
+    }
+
+    /// Returns true if the font has a glyph for the given character.
+    pub fn has_glyph(&self, ch: char) -> bool {
+        self.fontdue_font.lookup_glyph_index(ch) != 0
+    }
+
+    fn render_glyph(&self, glyph_id: core::num::NonZeroU16) -> RenderableGlyph {
+        GLYPH_CACHE.with(|cache| {
+            let mut cache = cache.borrow_mut();
+
+            let cache_key = (
+                self.id,
+                self.pixel_size,
+                glyph_id,
+                self.synthetic_style,
+                self.axis_values.clone(),
+            );
+
+            if let Some(entry) = cache.get(&cache_key) {
+                entry.clone()
+            } else if let Some(glyph) =
+                self.has_color_bitmaps.then(|| self.render_color_glyph(glyph_id)).flatten()
+            {
+                cache.put_with_weight(cache_key, glyph.clone()).ok();
+                glyph
+            } else {
+                let (mut alpha_map, mut x, y, mut width, mut height) =
+                    if self.axis_values.is_empty() {
+                        let (metrics, alpha_map) = self
+                            .fontdue_font
+                            .rasterize_indexed(glyph_id.get(), self.pixel_size.get() as _);
+                        (alpha_map, metrics.xmin, metrics.ymin, metrics.width, metrics.height)
+                    } else {
+                        self.rasterize_outline(glyph_id)
+                    };
+
+                if self.synthetic_style.bold {
+                    (alpha_map, width, height) =
+                        embolden(&alpha_map, width, height, self.synthetic_bold_strength());
+                }
+                if self.synthetic_style.italic {
+                    let left_shift;
+                    (alpha_map, width, left_shift) = slant(&alpha_map, width, height, y);
+                    x += left_shift;
+                }
+
+                let alpha_map: Rc<[u8]> = alpha_map.into();
+
+                let glyph = RenderableGlyph {
+                    x: PhysicalLength::new(x.try_into().unwrap()),
+                    y: PhysicalLength::new(y.try_into().unwrap()),
+                    width: PhysicalLength::new(width.try_into().unwrap()),
+                    height: PhysicalLength::new(height.try_into().unwrap()),
+                    alpha_map: alpha_map.into(),
+                };
+
+                cache.put_with_weight(cache_key, glyph.clone()).ok();
+                glyph
+            }
+        })
+    }
+}
+
+impl VectorFont {
+    /// The number of pixels by which synthetic bold glyphs are widened.
+    fn synthetic_bold_strength(&self) -> usize {
+        (self.pixel_size.get() as usize / 24).max(1)
+    }
+
+    /// The advance of a glyph, including the width added by synthetic bold.
+    fn glyph_advance(&self, advance: FontLength) -> PhysicalLength {
+        let advance: PhysicalLength = (advance.cast() * self.scale).cast();
+        if self.synthetic_style.bold {
+            advance + PhysicalLength::new(self.synthetic_bold_strength() as _)
+        } else {
+            advance
+        }
+    }
+
+    /// Rasterizes the outline of the glyph with the values of the variation axes applied.
+    /// Returns the coverage with the position of its bottom left corner relative to the
+    /// origin, and its width and height, like fontdue.
+    fn rasterize_outline(
+        &self,
+        glyph_id: core::num::NonZeroU16,
+    ) -> (Vec<u8>, i32, i32, usize, usize) {
+        let outline = sharedfontdb::FONT_DB.with(|db| {
+            db.borrow()
+                .with_face_data(self.id, |face_data, font_index| {
+                    let mut face =
+                        rustybuzz::ttf_parser::Face::parse(face_data, font_index).unwrap();
+                    self.axis_values.apply(&mut face);
+                    let mut outline = GlyphOutline::default();
+                    let bounding_box = face.outline_glyph(
+                        rustybuzz::ttf_parser::GlyphId(glyph_id.get()),
+                        &mut outline,
+                    )?;
+                    Some((outline, bounding_box))
+                })
+                .unwrap()
+        });
+        let Some((outline, bounding_box)) = outline else {
+            return (Vec::new(), 0, 0, 0, 0);
+        };
+
+        let scale = self.scale.get();
+        let xmin = (bounding_box.x_min as f32 * scale).floor() as i32;
+        let ymin = (bounding_box.y_min as f32 * scale).floor() as i32;
+        let xmax = (bounding_box.x_max as f32 * scale).ceil() as i32;
+        let ymax = (bounding_box.y_max as f32 * scale).ceil() as i32;
+        let width = (xmax - xmin).max(0) as usize;
+        let height = (ymax - ymin).max(0) as usize;
+
+        // Rows of the coverage go from the top to the bottom.
+        let to_pixel = |point: ab_glyph_rasterizer::Point| {
+            ab_glyph_rasterizer::point(point.x * scale - xmin as f32, ymax as f32 - point.y * scale)
+        };
+        let mut rasterizer = ab_glyph_rasterizer::Rasterizer::new(width, height);
+        for segment in outline.segments {
+            match segment {
+                OutlineSegment::Line(p0, p1) => rasterizer.draw_line(to_pixel(p0), to_pixel(p1)),
+                OutlineSegment::Quad(p0, p1, p2) => {
+                    rasterizer.draw_quad(to_pixel(p0), to_pixel(p1), to_pixel(p2))
+                }
+                OutlineSegment::Cubic(p0, p1, p2, p3) => {
+                    rasterizer.draw_cubic(to_pixel(p0), to_pixel(p1), to_pixel(p2), to_pixel(p3))
+                }
+            }
+        }
+
+        let mut alpha_map = vec![0; width * height];
+        rasterizer.for_each_pixel(|index, coverage| {
+            alpha_map[index] = (coverage.clamp(0., 1.) * 255.).round() as u8;
+        });
+        (alpha_map, xmin, ymin, width, height)
+    }
+
+    /// Renders the glyph from its PNG image in the color bitmap tables of the font, scaled
+    /// to the pixel size. Returns None if the font has no image for the glyph.
+    #[cfg(feature = "image-decoders")]
+    fn render_color_glyph(&self, glyph_id: core::num::NonZeroU16) -> Option<RenderableGlyph> {
+        let (image, x, y, scale) = sharedfontdb::FONT_DB.with(|db| {
+            db.borrow()
+                .with_face_data(self.id, |face_data, font_index| {
+                    let face = rustybuzz::ttf_parser::Face::parse(face_data, font_index).ok()?;
+                    let raster_image = face.glyph_raster_image(
+                        rustybuzz::ttf_parser::GlyphId(glyph_id.get()),
+                        self.pixel_size.get().max(1) as u16,
+                    )?;
+                    let image = image::load_from_memory_with_format(
+                        raster_image.data,
+                        image::ImageFormat::Png,
+                    )
+                    .ok()?
+                    .into_rgba8();
+                    let scale = self.pixel_size.get() as f32 / raster_image.pixels_per_em as f32;
+                    Some((image, raster_image.x, raster_image.y, scale))
+                })
+                .flatten()
+        })?;
+
+        let width = ((image.width() as f32 * scale).round() as u32).max(1);
+        let height = ((image.height() as f32 * scale).round() as u32).max(1);
+        let image =
+            image::imageops::resize(&image, width, height, image::imageops::FilterType::Triangle);
+
+        Some(RenderableGlyph {
+            x: PhysicalLength::new((x as f32 * scale).round() as _),
+            y: PhysicalLength::new((y as f32 * scale).round() as _),
+            width: PhysicalLength::new(width.try_into().ok()?),
+            height: PhysicalLength::new(height.try_into().ok()?),
+            alpha_map: crate::graphics::SharedImageBuffer::RGBA8(
+                crate::graphics::SharedPixelBuffer::clone_from_slice(image.as_raw(), width, height),
+            )
+            .into(),
+        })
+    }
+
+    #[cfg(not(feature = "image-decoders"))]
+    fn render_color_glyph(&self, _: core::num::NonZeroU16) -> Option<RenderableGlyph> {
+        None
+    }
 }
 
 impl TextShaper for VectorFont {
@@ -100,9 +403,12 @@ fn shape_text<GlyphStorage: core::iter::Extend<Glyph<PhysicalLength>>>(
             db.borrow()
                 .with_face_data(self.id, |face_data, font_index| {
                     let face = rustybuzz::ttf_parser::Face::parse(face_data, font_index).unwrap();
-                    let rb_face = rustybuzz::Face::from_face(face);
+                    let mut rb_face = rustybuzz::Face::from_face(face);
+                    if !self.axis_values.is_empty() {
+                        rb_face.set_variations(&self.axis_values.variations().collect::<Vec<_>>());
+                    }
 
-                    let glyph_buffer = rustybuzz::shape(&rb_face, &[], buffer);
+                    let glyph_buffer = rustybuzz::shape(&rb_face, &self.features, buffer);
 
                     let output_glyph_generator = glyph_buffer
                         .glyph_infos()
@@ -118,7 +424,7 @@ fn shape_text<GlyphStorage: core::iter::Extend<Glyph<PhysicalLength>>>(
                             out_glyph.offset_y =
                                 (FontLength::new(position.y_offset).cast() * self.scale).cast();
                             out_glyph.advance =
-                                (FontLength::new(position.x_advance).cast() * self.scale).cast();
+                                self.glyph_advance(FontLength::new(position.x_advance));
 
                             out_glyph.text_byte_offset = info.cluster as usize;
 
@@ -136,18 +442,17 @@ fn glyph_for_char(&self, ch: char) -> Option<Glyph<PhysicalLength>> {
         sharedfontdb::FONT_DB.with(|db| {
             db.borrow()
                 .with_face_data(self.id, |face_data, font_index| {
-                    let face = rustybuzz::ttf_parser::Face::parse(face_data, font_index).unwrap();
+                    let mut face =
+                        rustybuzz::ttf_parser::Face::parse(face_data, font_index).unwrap();
+                    self.axis_values.apply(&mut face);
                     face.glyph_index(ch).map(|glyph_index| {
                         let mut out_glyph = Glyph::default();
 
                         out_glyph.glyph_id = core::num::NonZeroU16::new(glyph_index.0 as u16);
 
-                        out_glyph.advance = (FontLength::new(
+                        out_glyph.advance = self.glyph_advance(FontLength::new(
                             face.glyph_hor_advance(glyph_index).unwrap_or_default() as _,
-                        )
-                        .cast()
-                            * self.scale)
-                            .cast();
+                        ));
 
                         out_glyph
                     })
@@ -171,32 +476,363 @@ fn descent(&self) -> PhysicalLength {
     }
 }
 
-impl super::GlyphRenderer for VectorFont {
-    fn render_glyph(&self, glyph_id: core::num::NonZeroU16) -> super::RenderableGlyph {
-        GLYPH_CACHE.with(|cache| {
-            let mut cache = cache.borrow_mut();
+/// A segment of the outline of a glyph, in font units.
+enum OutlineSegment {
+    Line(ab_glyph_rasterizer::Point, ab_glyph_rasterizer::Point),
+    Quad(ab_glyph_rasterizer::Point, ab_glyph_rasterizer::Point, ab_glyph_rasterizer::Point),
+    Cubic(
+        ab_glyph_rasterizer::Point,
+        ab_glyph_rasterizer::Point,
+        ab_glyph_rasterizer::Point,
+        ab_glyph_rasterizer::Point,
+    ),
+}
 
-            let cache_key = (self.id, self.pixel_size, glyph_id);
+/// Collects the segments of the outline of a glyph, as the bounding box is needed before
+/// they can be rasterized.
+#[derive(Default)]
+struct GlyphOutline {
+    segments: Vec<OutlineSegment>,
+    start: ab_glyph_rasterizer::Point,
+    current: ab_glyph_rasterizer::Point,
+}
 
-            if let Some(entry) = cache.get(&cache_key) {
-                entry.clone()
-            } else {
-                let (metrics, alpha_map) =
-                    self.fontdue_font.rasterize_indexed(glyph_id.get(), self.pixel_size.get() as _);
+impl rustybuzz::ttf_parser::OutlineBuilder for GlyphOutline {
+    fn move_to(&mut self, x: f32, y: f32) {
+        self.start = ab_glyph_rasterizer::point(x, y);
+        self.current = self.start;
+    }
 
-                let alpha_map: Rc<[u8]> = alpha_map.into();
+    fn line_to(&mut self, x: f32, y: f32) {
+        let to = ab_glyph_rasterizer::point(x, y);
+        self.segments.push(OutlineSegment::Line(self.current, to));
+        self.current = to;
+    }
 
-                let glyph = super::RenderableGlyph {
-                    x: PhysicalLength::new(metrics.xmin.try_into().unwrap()),
-                    y: PhysicalLength::new(metrics.ymin.try_into().unwrap()),
-                    width: PhysicalLength::new(metrics.width.try_into().unwrap()),
-                    height: PhysicalLength::new(metrics.height.try_into().unwrap()),
-                    alpha_map: alpha_map.into(),
-                };
+    fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
+        let to = ab_glyph_rasterizer::point(x, y);
+        self.segments.push(OutlineSegment::Quad(
+            self.current,
+            ab_glyph_rasterizer::point(x1, y1),
+            to,
+        ));
+        self.current = to;
+    }
 
-                cache.put_with_weight(cache_key, glyph.clone()).ok();
-                glyph
+    fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
+        let to = ab_glyph_rasterizer::point(x, y);
+        self.segments.push(OutlineSegment::Cubic(
+            self.current,
+            ab_glyph_rasterizer::point(x1, y1),
+            ab_glyph_rasterizer::point(x2, y2),
+            to,
+        ));
+        self.current = to;
+    }
+
+    fn close(&mut self) {
+        if self.current != self.start {
+            self.segments.push(OutlineSegment::Line(self.current, self.start));
+        }
+        self.current = self.start;
+    }
+}
+
+/// Dilates the coverage of a glyph by `strength` pixels to the right and to the top, to embolden it.
+/// Returns the new coverage with its width and height.
+fn embolden(
+    alpha_map: &[u8],
+    width: usize,
+    height: usize,
+    strength: usize,
+) -> (Vec<u8>, usize, usize) {
+    if width == 0 || height == 0 {
+        return (alpha_map.to_vec(), width, height);
+    }
+    let (new_width, new_height) = (width + strength, height + strength);
+    let mut result = alloc::vec![0; new_width * new_height];
+    for y in 0..new_height {
+        // The rows are ordered top to bottom, so the glyph grows upwards by starting `strength` rows later
+        let source_rows = y.saturating_sub(strength)..(y + 1).min(height);
+        for x in 0..new_width {
+            let source_columns = x.saturating_sub(strength)..(x + 1).min(width);
+            result[y * new_width + x] = source_rows
+                .clone()
+                .flat_map(|source_y| {
+                    alpha_map[source_y * width..][source_columns.clone()].iter().copied()
+                })
+                .max()
+                .unwrap_or(0);
+        }
+    }
+    (result, new_width, new_height)
+}
+
+/// Shears the coverage of a glyph to slant it to the right, around the baseline. `ymin` is the
+/// position of the bottom of the glyph relative to the baseline. Returns the new coverage with
+/// its width, and the horizontal offset of its left edge.
+fn slant(alpha_map: &[u8], width: usize, height: usize, ymin: i32) -> (Vec<u8>, usize, i32) {
+    if width == 0 || height == 0 {
+        return (alpha_map.to_vec(), width, 0);
+    }
+    // The shift of the center of a row, from the top row to the bottom one
+    let shift = |row: usize| (ymin as f32 + (height - row) as f32 - 0.5) * SYNTHETIC_ITALIC_SLANT;
+    let left_shift = shift(height - 1).floor();
+    let new_width = width + (shift(0).ceil() - left_shift) as usize + 1;
+
+    let mut coverage = alloc::vec![0f32; new_width * height];
+    for row in 0..height {
+        let row_shift = shift(row) - left_shift;
+        let (whole, fraction) = (row_shift.floor() as usize, row_shift.fract());
+        for x in 0..width {
+            let value = alpha_map[row * width + x] as f32;
+            coverage[row * new_width + x + whole] += value * (1. - fraction);
+            coverage[row * new_width + x + whole + 1] += value * fraction;
+        }
+    }
+    let result = coverage.into_iter().map(|value| value.round().min(255.) as u8).collect();
+    (result, new_width, left_shift as i32)
+}
+
+/// A font together with the fonts to fall back to, in order, for the characters it has no glyph for.
+/// The metrics are those of the primary font.
+pub struct FontFallbackChain {
+    fonts: Vec<VectorFont>,
+}
+
+impl FontFallbackChain {
+    /// Creates a chain from the primary font followed by its fallback fonts.
+    pub fn new(primary: VectorFont, fallbacks: impl IntoIterator<Item = VectorFont>) -> Self {
+        Self { fonts: core::iter::once(primary).chain(fallbacks).collect() }
+    }
+
+    fn primary(&self) -> &VectorFont {
+        &self.fonts[0]
+    }
+
+    /// Returns the vertical metrics of the primary font, which are used to lay out the lines.
+    pub fn vertical_metrics(&self) -> FontVerticalMetrics<PhysicalLength> {
+        self.primary().vertical_metrics()
+    }
+}
+
+/// Shapes the runs of missing glyphs in `glyphs` again with `font`, and returns the glyphs with
+/// these runs replaced. `glyphs` is the result of shaping `text`.
+fn reshape_missing_glyphs(
+    text: &str,
+    glyphs: Vec<Glyph<PhysicalLength>>,
+    font: &VectorFont,
+    font_index: usize,
+) -> Vec<Glyph<PhysicalLength>> {
+    let mut result = Vec::with_capacity(glyphs.len());
+    let mut remaining = glyphs.as_slice();
+
+    while !remaining.is_empty() {
+        let present_count = remaining.iter().take_while(|glyph| glyph.glyph_id.is_some()).count();
+        result.extend_from_slice(&remaining[..present_count]);
+        remaining = &remaining[present_count..];
+
+        let missing_count = remaining.iter().take_while(|glyph| glyph.glyph_id.is_none()).count();
+        if missing_count == 0 {
+            continue;
+        }
+        let (missing, rest) = remaining.split_at(missing_count);
+        remaining = rest;
+
+        let start = missing.iter().map(|glyph| glyph.text_byte_offset).min().unwrap();
+        let last = missing.iter().map(|glyph| glyph.text_byte_offset).max().unwrap();
+        let end = glyphs
+            .iter()
+            .map(|glyph| glyph.text_byte_offset)
+            .filter(|offset| *offset > last)
+            .min()
+            .unwrap_or(text.len());
+
+        // Keep the missing glyphs if they share a cluster with glyphs that were found, as shaping
+        // the cluster again would duplicate these.
+        if glyphs
+            .iter()
+            .any(|glyph| glyph.glyph_id.is_some() && (start..end).contains(&glyph.text_byte_offset))
+        {
+            result.extend_from_slice(missing);
+            continue;
+        }
+
+        let reshaped_start = result.len();
+        font.shape_text(&text[start..end], &mut result);
+        for glyph in &mut result[reshaped_start..] {
+            glyph.text_byte_offset += start;
+            if glyph.glyph_id.is_some() {
+                glyph.font_index = font_index;
             }
+        }
+    }
+
+    result
+}
+
+impl TextShaper for FontFallbackChain {
+    type LengthPrimitive = i16;
+    type Length = PhysicalLength;
+    fn shape_text<GlyphStorage: core::iter::Extend<Glyph<PhysicalLength>>>(
+        &self,
+        text: &str,
+        glyphs: &mut GlyphStorage,
+    ) {
+        let mut shaped_glyphs = Vec::new();
+        self.primary().shape_text(text, &mut shaped_glyphs);
+
+        for (font_index, fallback) in self.fonts.iter().enumerate().skip(1) {
+            if shaped_glyphs.iter().all(|glyph| glyph.glyph_id.is_some()) {
+                break;
+            }
+            shaped_glyphs = reshape_missing_glyphs(text, shaped_glyphs, fallback, font_index);
+        }
+
+        glyphs.extend(shaped_glyphs);
+    }
+
+    fn glyph_for_char(&self, ch: char) -> Option<Glyph<PhysicalLength>> {
+        self.fonts.iter().enumerate().find_map(|(font_index, font)| {
+            font.glyph_for_char(ch)
+                .filter(|glyph| glyph.glyph_id.is_some())
+                .map(|glyph| Glyph { font_index, ..glyph })
         })
     }
 }
+
+impl crate::textlayout::FontMetrics<PhysicalLength> for FontFallbackChain {
+    fn ascent(&self) -> PhysicalLength {
+        self.primary().ascent()
+    }
+
+    fn height(&self) -> PhysicalLength {
+        self.primary().height()
+    }
+
+    fn descent(&self) -> PhysicalLength {
+        self.primary().descent()
+    }
+}
+
+impl super::GlyphRenderer for FontFallbackChain {
+    fn render_glyph(&self, glyph_id: core::num::NonZeroU16, font_index: usize) -> RenderableGlyph {
+        self.fonts[font_index].render_glyph(glyph_id)
+    }
+}
+
+#[cfg(test)]
+fn load_test_font(data: &'static [u8], features: &[FontFeature]) -> VectorFont {
+    let id = sharedfontdb::FONT_DB
+        .with(|db| db.borrow_mut().load_faces(|db| db.load_font_data(data.to_vec())).1[0]);
+    let fontdue_font =
+        fontdue::Font::from_bytes(data, fontdue::FontSettings::default()).unwrap().into();
+    VectorFont::new(
+        id,
+        fontdue_font,
+        PhysicalLength::new(16),
+        SyntheticStyle::default(),
+        features,
+        &[],
+    )
+}
+
+#[test]
+fn test_fallback_chain_shaping() {
+    let primary = load_test_font(include_bytes!("../../sharedfontdb/Plaster-Regular.ttf"), &[]);
+    let fallback = load_test_font(include_bytes!("../../sharedfontdb/DejaVuSans.ttf"), &[]);
+    assert!(primary.has_glyph('A'));
+    assert!(!primary.has_glyph('Ж'));
+    assert!(fallback.has_glyph('Ж'));
+
+    let chain = FontFallbackChain::new(primary, [fallback]);
+    let mut glyphs = Vec::new();
+    chain.shape_text("AЖB", &mut glyphs);
+
+    assert_eq!(glyphs.len(), 3);
+    assert!(glyphs.iter().all(|glyph| glyph.glyph_id.is_some()));
+    assert_eq!(
+        glyphs.iter().map(|glyph| (glyph.text_byte_offset, glyph.font_index)).collect::<Vec<_>>(),
+        [(0, 0), (1, 1), (3, 0)]
+    );
+
+    assert_eq!(chain.glyph_for_char('Ж').unwrap().font_index, 1);
+    assert_eq!(chain.glyph_for_char('B').unwrap().font_index, 0);
+}
+
+#[test]
+fn test_synthetic_bold_and_italic() {
+    #[rustfmt::skip]
+    let glyph = [
+        0, 255,
+        0, 255,
+    ];
+
+    let (bold, width, height) = embolden(&glyph, 2, 2, 1);
+    assert_eq!((width, height), (3, 3));
+    #[rustfmt::skip]
+    assert_eq!(bold, [
+        0, 255, 255,
+        0, 255, 255,
+        0, 255, 255,
+    ]);
+
+    // The rows are centered 9.5 and 8.5 pixels above the baseline, and shifted by 1.9 and 1.7 pixels
+    let (italic, width, left_shift) = slant(&glyph, 2, 2, 8);
+    assert_eq!((width, left_shift), (4, 1));
+    #[rustfmt::skip]
+    assert_eq!(italic, [
+        0, 26, 230, 0,
+        0, 76, 179, 0,
+    ]);
+}
+
+#[test]
+fn test_font_features() {
+    let shape = |features: &str| {
+        let features = FontFeature::parse_list(features).collect::<Vec<_>>();
+        let font = load_test_font(include_bytes!("../../sharedfontdb/DejaVuSans.ttf"), &features);
+        let mut glyphs = Vec::new();
+        font.shape_text("fi", &mut glyphs);
+        glyphs.len()
+    };
+
+    // DejaVu Sans has a ligature for "fi"
+    assert_eq!(shape(""), 1);
+    assert_eq!(shape("-liga"), 2);
+}
+
+#[test]
+fn test_rasterize_outline() {
+    let font = load_test_font(include_bytes!("../../sharedfontdb/DejaVuSans.ttf"), &[]);
+    // DejaVu Sans isn't a variable font, so the variations are dropped.
+    assert!(font.axis_values.is_empty());
+
+    let glyph_id = font.glyph_for_char('H').unwrap().glyph_id.unwrap();
+    let (metrics, fontdue_alpha_map) = font.fontdue_font.rasterize_indexed(glyph_id.get(), 16.);
+    let (alpha_map, x, y, width, height) = font.rasterize_outline(glyph_id);
+    assert_eq!((x, y, width, height), (metrics.xmin, metrics.ymin, metrics.width, metrics.height));
+    let coverage = |alpha_map: &[u8]| alpha_map.iter().map(|alpha| *alpha as u32).sum::<u32>();
+    assert!(
+        coverage(&alpha_map).abs_diff(coverage(&fontdue_alpha_map)) < coverage(&alpha_map) / 50
+    );
+
+    let space = font.glyph_for_char(' ').unwrap().glyph_id.unwrap();
+    assert_eq!(font.rasterize_outline(space), (Vec::new(), 0, 0, 0, 0));
+}
+
+#[test]
+fn test_vertical_metrics() {
+    let font = load_test_font(include_bytes!("../../sharedfontdb/DejaVuSans.ttf"), &[]);
+    let metrics = font.vertical_metrics();
+    assert!(metrics.ascent.get() > 0 && metrics.descent.get() < 0);
+    assert!(metrics.line_gap.get() >= 0);
+    assert!(metrics.cap_height.get() > 0 && metrics.cap_height <= metrics.ascent);
+    // Each of the metrics is truncated to whole pixels
+    assert!((metrics.ascent - metrics.descent + metrics.line_gap - font.height).get().abs() <= 1);
+
+    // The glyph of the H spans the cap height
+    let glyph_id = font.glyph_for_char('H').unwrap().glyph_id.unwrap();
+    let glyph = font.render_glyph(glyph_id);
+    assert!((glyph.y + glyph.height - metrics.cap_height).get().abs() <= 1);
+}
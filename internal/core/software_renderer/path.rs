@@ -0,0 +1,311 @@
+// Copyright © SixtyFPS GmbH <info@slint-ui.com>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-commercial
+
+/*! Rasterization of the `Path` element into an alpha map.
+
+The flattened path is transformed into a list of edges, in physical pixels, and filled with a
+scanline algorithm. When anti-aliasing is enabled, each line of pixels is sampled with several
+sub-scanlines and the horizontal coverage of the pixels at the ends of each span is computed
+exactly. Otherwise, pixels are filled if their center is inside the path.
+
+Strokes are turned into polygons (one quad per segment and a circle at each join) that all
+have the same orientation, so that filling them with the non-zero rule computes their union.
+*/
+
+use crate::items::FillRule;
+use alloc::vec::Vec;
+use euclid::default::{Point2D, Rect, Vector2D};
+use lyon_path::math::Transform;
+use lyon_path::PathEvent;
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+/// The number of sub-scanlines per line of pixels when anti-aliasing is enabled.
+const SUBSCANLINES: usize = 4;
+
+type Point = Point2D<f32>;
+
+#[derive(Clone, Copy, Debug)]
+struct Edge {
+    from: Point,
+    to: Point,
+}
+
+/// The edges of the polygons to fill, in physical pixels.
+#[derive(Default)]
+pub struct Edges(Vec<Edge>);
+
+impl Edges {
+    /// The edges of the area enclosed by the path. Sub-paths are implicitly closed.
+    ///
+    /// The events must already be flattened, as done by
+    /// [`PathDataIterator::flattened`](crate::graphics::PathDataIterator::flattened).
+    pub fn fill(events: impl Iterator<Item = PathEvent>, transform: &Transform) -> Self {
+        let mut edges = Vec::new();
+        for event in events.map(|e| e.transformed(transform)) {
+            match event {
+                PathEvent::Line { from, to } => edges.push(Edge { from, to }),
+                PathEvent::End { last, first, .. } => edges.push(Edge { from: last, to: first }),
+                _ => {}
+            }
+        }
+        Self(edges)
+    }
+
+    /// The edges of the stroke of the path, with the given width in physical pixels. The ends of
+    /// open sub-paths are butt caps, and the segments are joined with round joins.
+    /// The events must already be flattened, like for [`Self::fill`].
+    pub fn stroke(
+        events: impl Iterator<Item = PathEvent>,
+        transform: &Transform,
+        width: f32,
+    ) -> Self {
+        let mut edges = Self::default();
+        let half_width = width / 2.;
+        if half_width <= 0. {
+            return edges;
+        }
+        let mut polyline: Vec<Point> = Vec::new();
+        for event in events.map(|e| e.transformed(transform)) {
+            match event {
+                PathEvent::Begin { at } => {
+                    polyline.clear();
+                    polyline.push(at);
+                }
+                PathEvent::Line { to, .. } => polyline.push(to),
+                PathEvent::End { first, close, .. } => {
+                    if close {
+                        polyline.push(first);
+                    }
+                    edges.add_polyline(&polyline, close, half_width);
+                }
+                _ => {}
+            }
+        }
+        edges
+    }
+
+    fn add_polyline(&mut self, points: &[Point], closed: bool, half_width: f32) {
+        let mut previous: Option<Point> = None;
+        for window in points.windows(2) {
+            let (a, b) = (window[0], window[1]);
+            let direction = b - a;
+            let length = direction.length();
+            if length <= f32::EPSILON {
+                continue;
+            }
+            let normal = Vector2D::new(-direction.y, direction.x) * (half_width / length);
+            self.add_polygon(&[a + normal, b + normal, b - normal, a - normal]);
+            if previous.is_some() {
+                self.add_circle(a, half_width);
+            }
+            previous = Some(b);
+        }
+        if let (true, Some(last)) = (closed, previous) {
+            self.add_circle(last, half_width);
+        }
+    }
+
+    fn add_circle(&mut self, center: Point, radius: f32) {
+        let segments = (radius * 2.).ceil().clamp(8., 64.) as usize;
+        let points = (0..segments)
+            .map(|i| {
+                let angle = i as f32 * core::f32::consts::TAU / segments as f32;
+                center + Vector2D::new(angle.cos(), angle.sin()) * radius
+            })
+            .collect::<Vec<_>>();
+        self.add_polygon(&points);
+    }
+
+    /// Adds a closed polygon, always with the same orientation.
+    fn add_polygon(&mut self, points: &[Point]) {
+        let area: f32 = points
+            .iter()
+            .zip(points.iter().cycle().skip(1))
+            .map(|(p, q)| p.x * q.y - q.x * p.y)
+            .sum();
+        let edges = points.iter().zip(points.iter().cycle().skip(1));
+        if area >= 0. {
+            self.0.extend(edges.map(|(&from, &to)| Edge { from, to }));
+        } else {
+            self.0.extend(edges.map(|(&from, &to)| Edge { from: to, to: from }));
+        }
+    }
+
+    /// Computes the coverage of the pixels within `clip`. Returns the rectangle covered by the
+    /// path within the clip, and one alpha value per pixel of that rectangle, row by row.
+    pub fn rasterize(
+        &self,
+        fill_rule: FillRule,
+        antialiased: bool,
+        clip: Rect<i32>,
+    ) -> Option<(Rect<i32>, Vec<u8>)> {
+        let (mut min, mut max) = (Point::new(f32::MAX, f32::MAX), Point::new(f32::MIN, f32::MIN));
+        for edge in &self.0 {
+            min = min.min(edge.from).min(edge.to);
+            max = max.max(edge.from).max(edge.to);
+        }
+        let bounds = Rect::new(
+            Point2D::new(min.x.floor() as i32, min.y.floor() as i32),
+            euclid::size2(
+                (max.x.ceil() - min.x.floor()) as i32,
+                (max.y.ceil() - min.y.floor()) as i32,
+            ),
+        )
+        .intersection(&clip)?;
+        if bounds.is_empty() {
+            return None;
+        }
+
+        let width = bounds.width() as usize;
+        let subscanlines = if antialiased { SUBSCANLINES } else { 1 };
+        let weight = 1. / subscanlines as f32;
+        let mut coverage = Vec::with_capacity(width * bounds.height() as usize);
+        let mut row = alloc::vec![0f32; width];
+        let mut crossings: Vec<(f32, i32)> = Vec::new();
+
+        for y in bounds.min_y()..bounds.max_y() {
+            row.fill(0.);
+            for sub in 0..subscanlines {
+                let sample_y = y as f32 + (sub as f32 + 0.5) * weight;
+                crossings.clear();
+                crossings.extend(self.0.iter().filter_map(|edge| {
+                    let (top, bottom, direction) = if edge.from.y < edge.to.y {
+                        (edge.from, edge.to, 1)
+                    } else {
+                        (edge.to, edge.from, -1)
+                    };
+                    if sample_y < top.y || sample_y >= bottom.y {
+                        return None;
+                    }
+                    let t = (sample_y - top.y) / (bottom.y - top.y);
+                    Some((top.x + t * (bottom.x - top.x) - bounds.min_x() as f32, direction))
+                }));
+                crossings.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+                let mut winding = 0;
+                for pair in crossings.windows(2) {
+                    winding += pair[0].1;
+                    let inside = match fill_rule {
+                        FillRule::Nonzero => winding != 0,
+                        FillRule::Evenodd => winding % 2 != 0,
+                    };
+                    if inside {
+                        if antialiased {
+                            add_span(&mut row, pair[0].0, pair[1].0, weight);
+                        } else {
+                            // Only the pixels whose center is in the span
+                            add_span(
+                                &mut row,
+                                (pair[0].0 - 0.5).ceil(),
+                                (pair[1].0 - 0.5).ceil(),
+                                1.,
+                            );
+                        }
+                    }
+                }
+            }
+            coverage.extend(row.iter().map(|c| (c.min(1.) * 255.).round() as u8));
+        }
+        Some((bounds, coverage))
+    }
+}
+
+/// Adds `weight` times the part of each pixel of the row that is between `from` and `to`.
+fn add_span(row: &mut [f32], from: f32, to: f32, weight: f32) {
+    let from = from.clamp(0., row.len() as f32);
+    let to = to.clamp(0., row.len() as f32);
+    if to <= from {
+        return;
+    }
+    let (first, last) = (from.floor() as usize, to.floor() as usize);
+    if first == last {
+        row[first] += (to - from) * weight;
+        return;
+    }
+    row[first] += (first as f32 + 1. - from) * weight;
+    for pixel in &mut row[first + 1..last] {
+        *pixel += weight;
+    }
+    if let Some(pixel) = row.get_mut(last) {
+        *pixel += (to - last as f32) * weight;
+    }
+}
+
+#[test]
+fn test_rasterize_fill() {
+    let square = [
+        PathEvent::Begin { at: Point::new(1., 1.) },
+        PathEvent::Line { from: Point::new(1., 1.), to: Point::new(3., 1.) },
+        PathEvent::Line { from: Point::new(3., 1.), to: Point::new(3., 3.) },
+        PathEvent::Line { from: Point::new(3., 3.), to: Point::new(1., 3.) },
+        PathEvent::End { last: Point::new(1., 3.), first: Point::new(1., 1.), close: true },
+    ];
+    let clip = Rect::new(Point2D::new(0, 0), euclid::size2(100, 100));
+
+    // Scaled to cover the pixels from 2 to 6, shifted by half a pixel
+    let edges = Edges::fill(
+        square.iter().copied(),
+        &Transform::scale(2., 2.).then_translate(Vector2D::new(0.5, 0.)),
+    );
+    let (rect, alpha) = edges.rasterize(FillRule::Nonzero, true, clip).unwrap();
+    assert_eq!(rect, Rect::new(Point2D::new(2, 2), euclid::size2(5, 4)));
+    for row in alpha.chunks(5) {
+        assert_eq!(row, [128, 255, 255, 255, 128]);
+    }
+    let (rect, alpha) = edges.rasterize(FillRule::Nonzero, false, clip).unwrap();
+    assert_eq!(rect, Rect::new(Point2D::new(2, 2), euclid::size2(5, 4)));
+    for row in alpha.chunks(5) {
+        assert_eq!(row, [255, 255, 255, 255, 0]);
+    }
+
+    // The clip limits the rasterized area
+    let clip = Rect::new(Point2D::new(3, 0), euclid::size2(2, 3));
+    let (rect, alpha) = edges.rasterize(FillRule::Nonzero, true, clip).unwrap();
+    assert_eq!(rect, Rect::new(Point2D::new(3, 2), euclid::size2(2, 1)));
+    assert_eq!(alpha, [255, 255]);
+}
+
+#[test]
+fn test_rasterize_fill_rule() {
+    // Two nested squares with the same orientation
+    let square = |min: f32, max: f32| {
+        [
+            PathEvent::Begin { at: Point::new(min, min) },
+            PathEvent::Line { from: Point::new(min, min), to: Point::new(max, min) },
+            PathEvent::Line { from: Point::new(max, min), to: Point::new(max, max) },
+            PathEvent::Line { from: Point::new(max, max), to: Point::new(min, max) },
+            PathEvent::End { last: Point::new(min, max), first: Point::new(min, min), close: true },
+        ]
+    };
+    let events = square(0., 3.).into_iter().chain(square(1., 2.));
+    let edges = Edges::fill(events, &Transform::identity());
+    let clip = Rect::new(Point2D::new(0, 0), euclid::size2(100, 100));
+    let (_, nonzero) = edges.rasterize(FillRule::Nonzero, true, clip).unwrap();
+    assert_eq!(nonzero, [255; 9]);
+    let (_, evenodd) = edges.rasterize(FillRule::Evenodd, true, clip).unwrap();
+    assert_eq!(evenodd, [255, 255, 255, 255, 0, 255, 255, 255, 255]);
+}
+
+#[test]
+fn test_rasterize_stroke() {
+    // A polyline going right then down, stroked with a width of 2
+    let events = [
+        PathEvent::Begin { at: Point::new(1., 1.) },
+        PathEvent::Line { from: Point::new(1., 1.), to: Point::new(5., 1.) },
+        PathEvent::Line { from: Point::new(5., 1.), to: Point::new(5., 5.) },
+        PathEvent::End { last: Point::new(5., 5.), first: Point::new(1., 1.), close: false },
+    ];
+    let edges = Edges::stroke(events.iter().copied(), &Transform::identity(), 2.);
+    let clip = Rect::new(Point2D::new(0, 0), euclid::size2(100, 100));
+    let (rect, alpha) = edges.rasterize(FillRule::Nonzero, true, clip).unwrap();
+    assert_eq!(rect, Rect::new(Point2D::new(1, 0), euclid::size2(5, 5)));
+    // The horizontal segment, with a butt cap on the left and the round join on the right
+    assert_eq!(alpha[..4], [255, 255, 255, 255]);
+    assert!(alpha[4] > 128 && alpha[4] < 255);
+    assert_eq!(alpha[5..10], [255, 255, 255, 255, 255]);
+    // The vertical segment, overlapping the horizontal one without gaps at the join
+    for row in alpha[10..].chunks(5) {
+        assert_eq!(row, [0, 0, 0, 255, 255]);
+    }
+}
@@ -51,9 +51,23 @@
 pub struct TextLayout<'a, Font: AbstractFont> {
     pub font: &'a Font,
     pub letter_spacing: Option<<Font as TextShaper>::Length>,
+    /// The distance between the top of two consecutive lines. When None, the height of the font is used.
+    pub line_height: Option<<Font as TextShaper>::Length>,
 }
 
 impl<'a, Font: AbstractFont> TextLayout<'a, Font> {
+    /// Returns the line height if set, otherwise the height of the font.
+    pub fn effective_line_height(&self) -> Font::Length {
+        self.line_height.unwrap_or_else(|| self.font.height())
+    }
+
+    /// Returns the space between the top of a line and the top of its glyphs, which is half of the
+    /// difference between the line height and the font height, so that the glyphs are centered in the line.
+    fn half_leading(&self) -> Font::Length {
+        let two = Font::LengthPrimitive::one() + Font::LengthPrimitive::one();
+        (self.effective_line_height() - self.font.height()) / two
+    }
+
     // Measures the size of the given text when rendered with the specified font and optionally constrained
     // by the provided `max_width`.
     // Returns a tuple of the width of the longest line as well as height of all lines.
@@ -74,7 +88,7 @@ pub fn text_size(
             line_count += 1;
         }
 
-        (max_line_width, self.font.height() * line_count.into())
+        (max_line_width, self.effective_line_height() * line_count.into())
     }
 }
 
@@ -84,6 +98,7 @@ pub struct PositionedGlyph<Length> {
     pub advance: Length,
     pub glyph_id: core::num::NonZeroU16,
     pub text_byte_offset: usize,
+    pub font_index: usize,
 }
 
 pub struct TextParagraphLayout<'a, Font: AbstractFont> {
@@ -133,10 +148,11 @@ pub fn layout_lines<R>(
 
         let mut text_height = || {
             if self.single_line {
-                self.layout.font.height()
+                self.layout.effective_line_height()
             } else {
                 text_lines = Some(new_line_break_iter().collect::<Vec<_>>());
-                self.layout.font.height() * (text_lines.as_ref().unwrap().len() as i16).into()
+                self.layout.effective_line_height()
+                    * (text_lines.as_ref().unwrap().len() as i16).into()
             }
         };
 
@@ -148,7 +164,7 @@ pub fn layout_lines<R>(
             TextVerticalAlignment::Bottom => self.max_height - text_height(),
         };
 
-        let mut y = baseline_y;
+        let mut y = baseline_y + self.layout.half_leading();
 
         let mut process_line = |line: &TextLine<Font::Length>, glyphs: &[Glyph<Font::Length>]| {
             let x = match self.horizontal_alignment {
@@ -162,24 +178,31 @@ pub fn layout_lines<R>(
                 }
             };
 
-            let mut elide_glyph = elide_glyph.as_ref().clone();
+            // Only the lines that don't fit are elided, like in the other renderers
+            let elide_glyph = elide_glyph.as_ref().filter(|_| line.text_width > self.max_width);
+            let mut elided = false;
 
             let glyph_it = glyphs[line.glyph_range.clone()].iter();
             let mut glyph_x = Font::Length::zero();
             let mut positioned_glyph_it = glyph_it.filter_map(|glyph| {
                 // TODO: cut off at grapheme boundaries
-                if glyph_x > max_width_without_elision {
-                    if let Some(elide_glyph) = elide_glyph.take() {
+                if elided {
+                    return None;
+                }
+                if let Some(elide_glyph) = elide_glyph {
+                    if glyph_x + glyph.advance > max_width_without_elision {
+                        elided = true;
                         return Some(PositionedGlyph {
                             x: glyph_x,
                             y: Font::Length::zero(),
-                            advance: glyph.advance,
+                            advance: elide_glyph.advance,
                             glyph_id: elide_glyph.glyph_id.unwrap(), // checked earlier when initializing elide_glyph
                             text_byte_offset: glyph.text_byte_offset,
+                            font_index: elide_glyph.font_index,
                         });
-                    } else {
-                        return None;
                     }
+                } else if glyph_x > self.max_width {
+                    return None;
                 }
                 let x = glyph_x;
                 glyph_x += glyph.advance;
@@ -190,6 +213,7 @@ pub fn layout_lines<R>(
                     advance: glyph.advance,
                     glyph_id: existing_glyph_id,
                     text_byte_offset: glyph.text_byte_offset,
+                    font_index: glyph.font_index,
                 })
             });
 
@@ -198,7 +222,7 @@ pub fn layout_lines<R>(
             {
                 return core::ops::ControlFlow::Break(break_val);
             }
-            y += self.layout.font.height();
+            y += self.layout.effective_line_height();
 
             core::ops::ControlFlow::Continue(())
         };
@@ -259,7 +283,7 @@ pub fn byte_offset_for_position(&self, (pos_x, pos_y): (Font::Length, Font::Leng
         let two = Font::LengthPrimitive::one() + Font::LengthPrimitive::one();
 
         match self.layout_lines(|glyphs, _, line_y, line| {
-            if pos_y >= line_y + self.layout.font.height() {
+            if pos_y >= line_y + self.layout.font.height() + self.layout.half_leading() {
                 byte_offset = line.byte_range.end;
                 return core::ops::ControlFlow::Continue(());
             }
@@ -318,6 +342,7 @@ fn shape_text<GlyphStorage: std::iter::Extend<Glyph<f32>>>(
                 glyph_id: core::num::NonZeroU16::new(utf16_char_as_glyph_id),
                 advance: 10.,
                 text_byte_offset: byte_offset,
+                font_index: 0,
             }
         });
         glyphs.extend(glyph_iter);
@@ -333,6 +358,7 @@ fn glyph_for_char(&self, ch: char) -> Option<Glyph<f32>> {
             glyph_id: core::num::NonZeroU16::new(utf16_char_as_glyph_id),
             advance: 10.,
             text_byte_offset: 0,
+            font_index: 0,
         }
         .into()
     }
@@ -358,7 +384,7 @@ fn test_elision() {
 
     let paragraph = TextParagraphLayout {
         string: text,
-        layout: TextLayout { font: &font, letter_spacing: None },
+        layout: TextLayout { font: &font, letter_spacing: None, line_height: None },
         max_width: 13. * 10.,
         max_height: 10.,
         horizontal_alignment: TextHorizontalAlignment::Left,
@@ -387,7 +413,7 @@ fn test_elision() {
                 .collect::<Vec<char>>()
         })
         .collect::<String>();
-    debug_assert_eq!(rendered_text, "This is a lon…")
+    debug_assert_eq!(rendered_text, "This is a lo…")
 }
 
 #[test]
@@ -399,7 +425,7 @@ fn test_exact_fit() {
 
     let paragraph = TextParagraphLayout {
         string: text,
-        layout: TextLayout { font: &font, letter_spacing: None },
+        layout: TextLayout { font: &font, letter_spacing: None, line_height: None },
         max_width: 4. * 10.,
         max_height: 10.,
         horizontal_alignment: TextHorizontalAlignment::Left,
@@ -431,6 +457,40 @@ fn test_exact_fit() {
     debug_assert_eq!(rendered_text, "Fits")
 }
 
+#[test]
+fn test_line_height() {
+    let font = FixedTestFont;
+    let text = "Hello\nWorld\n!";
+
+    let layout = TextLayout { font: &font, letter_spacing: None, line_height: Some(20.) };
+    assert_eq!(layout.text_size(text, None), (50., 60.));
+
+    let paragraph = TextParagraphLayout {
+        string: text,
+        layout,
+        max_width: 100.,
+        max_height: 100.,
+        horizontal_alignment: TextHorizontalAlignment::Left,
+        vertical_alignment: TextVerticalAlignment::Top,
+        wrap: TextWrap::NoWrap,
+        overflow: TextOverflow::Clip,
+        single_line: false,
+    };
+    let mut line_ys = Vec::new();
+    paragraph
+        .layout_lines::<()>(|_, _, y, _| {
+            line_ys.push(y);
+            core::ops::ControlFlow::Continue(())
+        })
+        .unwrap();
+    // The glyphs are centered in lines of 20 pixels
+    assert_eq!(line_ys, vec![5., 25., 45.]);
+
+    assert_eq!(paragraph.byte_offset_for_position((0., 19.)), 0);
+    assert_eq!(paragraph.byte_offset_for_position((0., 21.)), 6);
+    assert_eq!(paragraph.cursor_pos_for_byte_offset(6), (0., 25.));
+}
+
 #[test]
 fn test_no_line_separators_characters_rendered() {
     let font = FixedTestFont;
@@ -440,7 +500,7 @@ fn test_no_line_separators_characters_rendered() {
 
     let paragraph = TextParagraphLayout {
         string: text,
-        layout: TextLayout { font: &font, letter_spacing: None },
+        layout: TextLayout { font: &font, letter_spacing: None, line_height: None },
         max_width: 13. * 10.,
         max_height: 10.,
         horizontal_alignment: TextHorizontalAlignment::Left,
@@ -484,7 +544,7 @@ fn test_cursor_position() {
 
     let paragraph = TextParagraphLayout {
         string: text,
-        layout: TextLayout { font: &font, letter_spacing: None },
+        layout: TextLayout { font: &font, letter_spacing: None, line_height: None },
         max_width: 10. * 10.,
         max_height: 10.,
         horizontal_alignment: TextHorizontalAlignment::Left,
@@ -524,7 +584,7 @@ fn test_cursor_position_with_newline() {
 
     let paragraph = TextParagraphLayout {
         string: text,
-        layout: TextLayout { font: &font, letter_spacing: None },
+        layout: TextLayout { font: &font, letter_spacing: None, line_height: None },
         max_width: 100. * 10.,
         max_height: 10.,
         horizontal_alignment: TextHorizontalAlignment::Left,
@@ -544,7 +604,7 @@ fn byte_offset_for_empty_line() {
 
     let paragraph = TextParagraphLayout {
         string: text,
-        layout: TextLayout { font: &font, letter_spacing: None },
+        layout: TextLayout { font: &font, letter_spacing: None, line_height: None },
         max_width: 100. * 10.,
         max_height: 10.,
         horizontal_alignment: TextHorizontalAlignment::Left,
@@ -566,7 +626,7 @@ fn test_byte_offset() {
 
     let paragraph = TextParagraphLayout {
         string: text,
-        layout: TextLayout { font: &font, letter_spacing: None },
+        layout: TextLayout { font: &font, letter_spacing: None, line_height: None },
         max_width: 10. * 10.,
         max_height: 10.,
         horizontal_alignment: TextHorizontalAlignment::Left,
@@ -20,6 +20,9 @@ pub struct Glyph<Length> {
     /// "produced" this glyph. When one character produces multiple glyphs (for example
     /// decomposed ligature), then all glyphs have the same offset.
     pub text_byte_offset: usize,
+    /// The index of the font that provides this glyph in a chain of fallback fonts, or zero
+    /// for the primary font.
+    pub font_index: usize,
 }
 
 /// This trait defines the interface between the text layout and the platform specific
@@ -365,7 +368,7 @@ fn test_letter_spacing() {
             shaped_glyphs.iter().map(|g| g.advance).collect::<Vec<_>>()
         };
 
-        let layout = TextLayout { font: &face, letter_spacing: Some(20.) };
+        let layout = TextLayout { font: &face, letter_spacing: Some(20.), line_height: None };
         let buffer = ShapeBuffer::new(&layout, text);
 
         assert_eq!(buffer.glyphs.len(), advances.len());
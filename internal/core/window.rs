@@ -14,7 +14,7 @@
 use crate::graphics::Point;
 use crate::input::{
     key_codes, ClickState, InternalKeyboardModifierState, KeyEvent, KeyEventType, KeyInputEvent,
-    KeyboardModifiers, MouseEvent, MouseInputState, TextCursorBlinker,
+    KeyboardModifiers, MouseEvent, MouseInputState, TextCursorBlinker, TouchInputState,
 };
 use crate::item_tree::ItemRc;
 use crate::items::{ItemRef, MouseCursor};
@@ -125,6 +125,12 @@ fn apply_geometry_constraint(
     }
 
     /// Set the mouse cursor
+    ///
+    /// This is called by the items while the mouse events are dispatched: a `TouchArea` sets its
+    /// `mouse-cursor` when the pointer moves over it and a `TextInput` sets [`MouseCursor::Text`].
+    /// Since parents see the events before their children, the cursor of the innermost item
+    /// under the pointer wins. These items reset the cursor to [`MouseCursor::Default`] when the
+    /// pointer leaves them.
     // TODO: Make the enum public and make public
     fn set_mouse_cursor(&self, _cursor: MouseCursor) {}
 
@@ -172,6 +178,20 @@ fn set_position(&self, _position: WindowPosition) {}
     // FIXME: before making that public, we need to add a WindowEvent::Resized to avoid the above recursion
     fn set_size(&self, _size: WindowSize) {}
 
+    /// Sets whether the window covers the whole screen that currently contains it.
+    ///
+    /// The default implementation does nothing
+    ///
+    /// Called from [`Window::set_fullscreen()`]
+    fn set_fullscreen(&self, _fullscreen: bool) {}
+
+    /// Sets whether the window is maximized.
+    ///
+    /// The default implementation does nothing
+    ///
+    /// Called from [`Window::set_maximized()`]
+    fn set_maximized(&self, _maximized: bool) {}
+
     /// returns wether a dark theme is used
     fn dark_color_scheme(&self) -> bool {
         false
@@ -277,6 +297,7 @@ pub struct WindowInner {
     window_adapter_weak: Weak<dyn WindowAdapter>,
     component: RefCell<ComponentWeak>,
     mouse_input_state: Cell<MouseInputState>,
+    touch_input_state: Cell<TouchInputState>,
     pub(crate) modifiers: Cell<InternalKeyboardModifierState>,
 
     /// itemRC will retrieve on wasms
@@ -286,7 +307,13 @@ pub struct WindowInner {
     pinned_fields: Pin<Box<WindowPinnedFields>>,
     active_popup: RefCell<Option<PopupWindow>>,
     close_requested: Callback<(), CloseRequestResponse>,
+    scale_factor_changed: Callback<f32>,
+    /// The scale factor that was last passed to the scale_factor_changed callback
+    reported_scale_factor: Cell<Option<f32>>,
     click_state: ClickState,
+    /// The buttons held down according to the pointer events passed to
+    /// [`crate::api::Window::dispatch_event`], reported with the move events.
+    pub(crate) pressed_buttons: Cell<crate::input::PointerEventButtons>,
     /// This is a cache of the size set by the set_inner_size setter.
     /// It should be mapping with the WindowItem::width and height (only in physical)
     pub(crate) inner_size: Cell<PhysicalSize>,
@@ -325,6 +352,7 @@ pub fn new(window_adapter_weak: Weak<dyn WindowAdapter>) -> Self {
             window_adapter_weak,
             component: Default::default(),
             mouse_input_state: Default::default(),
+            touch_input_state: Default::default(),
             modifiers: Default::default(),
             pinned_fields: Box::pin(WindowPinnedFields {
                 redraw_tracker: redraw_tracker,
@@ -340,8 +368,11 @@ pub fn new(window_adapter_weak: Weak<dyn WindowAdapter>) -> Self {
             cursor_blinker: Default::default(),
             active_popup: Default::default(),
             close_requested: Default::default(),
+            scale_factor_changed: Default::default(),
+            reported_scale_factor: Default::default(),
             inner_size: Default::default(),
             click_state: ClickState::default(),
+            pressed_buttons: Default::default(),
         }
     }
 
@@ -351,6 +382,7 @@ pub fn set_component(&self, component: &ComponentRc) {
         self.close_popup();
         self.focus_item.replace(Default::default());
         self.mouse_input_state.replace(Default::default());
+        self.touch_input_state.replace(Default::default());
         self.modifiers.replace(Default::default());
         self.component.replace(ComponentRc::downgrade(component));
         self.pinned_fields.window_properties_tracker.set_dirty(); // component changed, layout constraints for sure must be re-calculated
@@ -386,7 +418,7 @@ pub fn try_component(&self) -> Option<ComponentRc> {
         self.component.borrow().upgrade()
     }
 
-    /// Receive a mouse event and pass it to the items of the component to
+    /// Receive a mouse or touch event and pass it to the items of the component to
     /// change their state.
     ///
     /// Arguments:
@@ -434,6 +466,16 @@ pub fn process_mouse_input(&self, mut event: MouseEvent) {
             return;
         };
 
+        if matches!(event, MouseEvent::Touch { .. }) {
+            self.touch_input_state.set(crate::input::process_touch_input(
+                component,
+                event,
+                &self.window_adapter(),
+                self.touch_input_state.take(),
+            ));
+            return;
+        }
+
         self.mouse_input_state.set(crate::input::process_mouse_input(
             component,
             event,
@@ -461,6 +503,14 @@ pub(crate) fn process_delayed_event(&self) {
     /// Receive a key event and pass it to the items of the component to
     /// change their state.
     ///
+    /// The keyboard modifiers are tracked from the modifier keys in the events, and the
+    /// [`KeyEvent`] passed to the items carries them together with the text of the key
+    /// and whether it was pressed or released. The event is delivered to the focus item
+    /// through the `key_event` entry of the [`ItemVTable`](crate::items::ItemVTable). If
+    /// that item returns [`KeyEventResult::EventIgnored`](crate::input::KeyEventResult::EventIgnored),
+    /// the event goes to its parent items in turn, until one of them accepts it. If no item
+    /// accepts it, Tab and Shift+Tab move the focus to the next or previous item.
+    ///
     /// Arguments:
     /// * `event`: The key event received by the windowing system.
     /// * `component`: The Slint compiled component that provides the tree of items.
@@ -703,6 +753,9 @@ pub fn draw_contents<T>(
     pub fn show(&self) -> Result<(), PlatformError> {
         self.update_window_properties();
         self.window_adapter().show()?;
+        // Let the application know about the scale factor the window was mapped with, unless the
+        // backend already reported it while showing the window
+        self.report_scale_factor(self.scale_factor());
         Ok(())
     }
 
@@ -800,7 +853,17 @@ pub fn scale_factor(&self) -> f32 {
 
     /// Sets the scale factor for the window. This is set by the backend or for testing.
     pub(crate) fn set_scale_factor(&self, factor: f32) {
-        self.pinned_fields.scale_factor.set(factor)
+        if self.pinned_fields.as_ref().project_ref().scale_factor.get_untracked() != factor {
+            self.pinned_fields.scale_factor.set(factor);
+            self.report_scale_factor(factor);
+        }
+    }
+
+    /// Runs the scale_factor_changed callback, if it wasn't already run with this factor.
+    fn report_scale_factor(&self, factor: f32) {
+        if self.reported_scale_factor.replace(Some(factor)) != Some(factor) {
+            self.scale_factor_changed.call(&factor);
+        }
     }
 
     /// Returns the scale factor set on the window, as provided by the windowing system.
@@ -839,6 +902,12 @@ pub fn on_close_requested(&self, mut callback: impl FnMut() -> CloseRequestRespo
         self.close_requested.set_handler(move |()| callback());
     }
 
+    /// Sets the scale_factor_changed callback. The callback will be run when the window is first shown,
+    /// and every time the windowing system reports a new scale factor.
+    pub fn on_scale_factor_changed(&self, mut callback: impl FnMut(f32) + 'static) {
+        self.scale_factor_changed.set_handler(move |factor| callback(*factor));
+    }
+
     /// Runs the close_requested callback.
     /// If the callback returns KeepWindowShown, this function returns false. That should prevent the Window from closing.
     /// Otherwise it returns true, which allows the Window to hide.
@@ -1106,6 +1175,40 @@ fn call(&self) -> CloseRequestResponse {
         window_adapter.window().on_close_requested(move || with_user_data.call());
     }
 
+    /// C binding to the on_scale_factor_changed() API of Window
+    #[no_mangle]
+    pub unsafe extern "C" fn slint_windowrc_on_scale_factor_changed(
+        handle: *const WindowAdapterRcOpaque,
+        callback: extern "C" fn(scale_factor: f32, user_data: *mut c_void),
+        drop_user_data: extern "C" fn(user_data: *mut c_void),
+        user_data: *mut c_void,
+    ) {
+        struct WithUserData {
+            callback: extern "C" fn(scale_factor: f32, user_data: *mut c_void),
+            drop_user_data: extern "C" fn(*mut c_void),
+            user_data: *mut c_void,
+        }
+
+        impl Drop for WithUserData {
+            fn drop(&mut self) {
+                (self.drop_user_data)(self.user_data)
+            }
+        }
+
+        impl WithUserData {
+            fn call(&self, scale_factor: f32) {
+                (self.callback)(scale_factor, self.user_data)
+            }
+        }
+
+        let with_user_data = WithUserData { callback, drop_user_data, user_data };
+
+        let window_adapter = &*(handle as *const Rc<dyn WindowAdapter>);
+        window_adapter
+            .window()
+            .on_scale_factor_changed(move |scale_factor| with_user_data.call(scale_factor));
+    }
+
     /// This function issues a request to the windowing system to redraw the contents of the window.
     #[no_mangle]
     pub unsafe extern "C" fn slint_windowrc_request_redraw(handle: *const WindowAdapterRcOpaque) {
@@ -1178,6 +1281,26 @@ fn call(&self) -> CloseRequestResponse {
         window_adapter.window().set_size(crate::api::LogicalSize::new(size.width, size.height));
     }
 
+    /// Sets whether the window is shown in fullscreen mode.
+    #[no_mangle]
+    pub unsafe extern "C" fn slint_windowrc_set_fullscreen(
+        handle: *const WindowAdapterRcOpaque,
+        fullscreen: bool,
+    ) {
+        let window_adapter = &*(handle as *const Rc<dyn WindowAdapter>);
+        window_adapter.window().set_fullscreen(fullscreen);
+    }
+
+    /// Sets whether the window is maximized.
+    #[no_mangle]
+    pub unsafe extern "C" fn slint_windowrc_set_maximized(
+        handle: *const WindowAdapterRcOpaque,
+        maximized: bool,
+    ) {
+        let window_adapter = &*(handle as *const Rc<dyn WindowAdapter>);
+        window_adapter.window().set_maximized(maximized);
+    }
+
     /// Return wether the style is using a dark theme
     #[no_mangle]
     pub unsafe extern "C" fn slint_windowrc_dark_color_scheme(
@@ -198,6 +198,13 @@ pub fn eval_expression(expression: &Expression, local_context: &mut EvalLocalCon
                 }
                 (Value::Number(n), Type::Color) => Color::from_argb_encoded(n as u32).into(),
                 (Value::Brush(brush), Type::Color) => brush.color().into(),
+                (Value::Model(model), Type::LayoutCache) => {
+                    model.model_tracker().track_row_count_changes();
+                    Value::LayoutCache((0..model.row_count()).map(|row| {
+                        model.model_tracker().track_row_data_changes(row);
+                        model.row_data(row).and_then(|v| f64::try_from(v).ok()).unwrap_or_default() as f32
+                    }).collect())
+                }
                 (v, _) => v,
             }
         }
@@ -377,7 +384,7 @@ pub fn eval_expression(expression: &Expression, local_context: &mut EvalLocalCon
             if let Value::LayoutCache(cache) = cache {
                 if let Some(ri) = repeater_index {
                     let offset : usize = eval_expression(ri, local_context).try_into().unwrap();
-                    Value::Number(cache[(cache[*index] as usize) + offset * 2].into())
+                    Value::Number(cache.get((cache[*index] as usize) + offset * 2).copied().unwrap_or(0.).into())
                 } else {
                     Value::Number(cache[*index].into())
                 }
@@ -106,10 +106,7 @@ fn find_start_item(
 }
 
 fn item_contains(item: &ItemRc, position: &LogicalPoint) -> bool {
-    let offset = item.map_to_window(LogicalPoint::default());
-    let geometry = item.geometry().translate(offset.to_vector());
-
-    geometry.contains(*position)
+    item.geometry_in_window().contains(*position)
 }
 
 pub fn on_element_selected(
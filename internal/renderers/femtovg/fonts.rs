@@ -7,7 +7,9 @@
 use femtovg::TextContext;
 use i_slint_core::graphics::euclid;
 use i_slint_core::graphics::FontRequest;
-use i_slint_core::items::{TextHorizontalAlignment, TextOverflow, TextVerticalAlignment, TextWrap};
+use i_slint_core::items::{
+    FontStyle, TextHorizontalAlignment, TextOverflow, TextVerticalAlignment, TextWrap,
+};
 use i_slint_core::lengths::{LogicalLength, LogicalSize, ScaleFactor, SizeLengths};
 use i_slint_core::sharedfontdb::fontdb;
 use i_slint_core::{sharedfontdb, SharedString, SharedVector};
@@ -23,6 +25,7 @@
 struct FontCacheKey {
     family: SharedString,
     weight: i32,
+    style: FontStyle,
 }
 
 #[derive(Clone)]
@@ -45,9 +48,12 @@ pub fn init_paint(
         paint
     }
 
+    /// Returns the size of the text. The lines are `line_height` apart, or the height of the font
+    /// if it is None.
     pub fn text_size(
         &self,
         letter_spacing: PhysicalLength,
+        line_height: Option<PhysicalLength>,
         text: &str,
         max_width: Option<PhysicalLength>,
     ) -> PhysicalSize {
@@ -80,7 +86,8 @@ pub fn text_size(
                 width = measure.width().max(width);
             }
         }
-        euclid::size2(width, lines as f32 * font_metrics.height())
+        let line_height = line_height.map_or(font_metrics.height(), |height| height.get());
+        euclid::size2(width, lines as f32 * line_height)
     }
 
     pub fn height(&self) -> PhysicalLength {
@@ -101,8 +108,12 @@ pub(crate) fn text_size(
     let font =
         FONT_CACHE.with(|cache| cache.borrow_mut().font(font_request.clone(), scale_factor, text));
     let letter_spacing = font_request.letter_spacing.unwrap_or_default();
-    font.text_size(letter_spacing * scale_factor, text, max_width.map(|x| x * scale_factor))
-        / scale_factor
+    font.text_size(
+        letter_spacing * scale_factor,
+        font_request.line_height.map(|height| height * scale_factor),
+        text,
+        max_width.map(|x| x * scale_factor),
+    ) / scale_factor
 }
 
 #[derive(Copy, Clone)]
@@ -144,16 +155,21 @@ pub struct FontCache {
     available_families: HashSet<SharedString>,
 }
 
+/// Returns the names of the font families in the font database.
+fn available_families() -> HashSet<SharedString> {
+    sharedfontdb::FONT_DB.with(|db| {
+        db.borrow()
+            .faces()
+            .filter_map(|face_info| {
+                face_info.families.first().map(|(family_name, _)| family_name.as_str().into())
+            })
+            .collect()
+    })
+}
+
 impl Default for FontCache {
     fn default() -> Self {
-        let available_families = sharedfontdb::FONT_DB.with(|db| {
-            db.borrow()
-                .faces()
-                .filter_map(|face_info| {
-                    face_info.families.first().map(|(family_name, _)| family_name.as_str().into())
-                })
-                .collect()
-        });
+        let available_families = available_families();
 
         let text_context = TextContext::default();
         text_context.resize_shaped_words_cache(NonZeroUsize::new(10_000_000).unwrap());
@@ -173,9 +189,26 @@ fn default() -> Self {
 }
 
 impl FontCache {
-    fn load_single_font(&mut self, family: Option<&SharedString>, weight: i32) -> LoadedFont {
+    /// Forgets the fonts loaded from the given faces, after these were removed from the font database,
+    /// and the families that are no longer available.
+    ///
+    /// femtovg can't remove fonts from its `TextContext`, so the data of the fonts loaded from these
+    /// faces remains in memory, but they are not used anymore. Registering the same font again loads
+    /// it anew.
+    pub fn evict_faces(&mut self, faces: &[fontdb::ID]) {
+        self.loaded_fonts.retain(|_, loaded_font| !faces.contains(&loaded_font.fontdb_face_id));
+        self.loaded_font_coverage.retain(|id, _| !faces.contains(id));
+        self.available_families = available_families();
+    }
+
+    fn load_single_font(
+        &mut self,
+        family: Option<&SharedString>,
+        weight: i32,
+        style: FontStyle,
+    ) -> LoadedFont {
         let text_context = self.text_context.clone();
-        let cache_key = FontCacheKey { family: family.cloned().unwrap_or_default(), weight };
+        let cache_key = FontCacheKey { family: family.cloned().unwrap_or_default(), weight, style };
 
         if let Some(loaded_font) = self.loaded_fonts.get(&cache_key) {
             return *loaded_font;
@@ -189,6 +222,11 @@ fn load_single_font(&mut self, family: Option<&SharedString>, weight: i32) -> Lo
         let query = fontdb::Query {
             families: &[family],
             weight: fontdb::Weight(weight as u16),
+            style: match style {
+                FontStyle::Normal => fontdb::Style::Normal,
+                FontStyle::Italic => fontdb::Style::Italic,
+                FontStyle::Oblique => fontdb::Style::Oblique,
+            },
             ..Default::default()
         };
 
@@ -254,7 +292,8 @@ pub fn font(
         let pixel_size = font_request.pixel_size.unwrap_or(DEFAULT_FONT_SIZE) * scale_factor;
         let weight = font_request.weight.unwrap_or(DEFAULT_FONT_WEIGHT);
 
-        let primary_font = self.load_single_font(font_request.family.as_ref(), weight);
+        let primary_font =
+            self.load_single_font(font_request.family.as_ref(), weight, font_request.style);
 
         use unicode_script::{Script, UnicodeScript};
         // map from required script to sample character
@@ -301,7 +340,8 @@ pub fn font(
                     return None;
                 }
 
-                let fallback_font = self.load_single_font(Some(fallback_family), weight);
+                let fallback_font =
+                    self.load_single_font(Some(fallback_family), weight, font_request.style);
 
                 coverage_result = self.check_and_update_script_coverage(
                     &mut scripts_required,
@@ -552,10 +592,13 @@ fn check_and_update_script_coverage(
 /// Layout the given string in lines, and call the `layout_line` callback with the line to draw at position y.
 /// The signature of the `layout_line` function is: `(text, pos, start_index, line_metrics)`.
 /// start index is the starting byte of the text in the string.
+/// The lines are `line_height` apart, or the height of the font if it is None, with the glyphs
+/// centered vertically in each line.
 /// Returns the y coordinate of where to place the cursor if it is at the end of the text
 pub(crate) fn layout_text_lines(
     string: &str,
     font: &Font,
+    line_height: Option<PhysicalLength>,
     max_size: PhysicalSize,
     (horizontal_alignment, vertical_alignment): (TextHorizontalAlignment, TextVerticalAlignment),
     wrap: TextWrap,
@@ -573,14 +616,17 @@ pub(crate) fn layout_text_lines(
     let text_context = FONT_CACHE.with(|cache| cache.borrow().text_context.clone());
     let font_metrics = text_context.measure_font(paint).unwrap();
     let font_height = PhysicalLength::new(font_metrics.height());
+    let half_leading = line_height
+        .map_or(PhysicalLength::default(), |line_height| (line_height - font_height) / 2.);
 
     let text_height = || {
         if single_line {
-            font_height
+            line_height.unwrap_or(font_height)
         } else {
             // Note: this is kind of doing twice the layout because text_size also does it
             font.text_size(
                 PhysicalLength::new(paint.letter_spacing()),
+                line_height,
                 string,
                 if wrap { Some(max_width) } else { None },
             )
@@ -599,6 +645,7 @@ pub(crate) fn layout_text_lines(
                     max_width - max_width.min(PhysicalLength::new(line_metrics.width()))
                 }
             };
+            let y = y + half_leading;
             layout_line(text, PhysicalPoint::from_lengths(x, y), start, line_metrics);
         };
 
@@ -607,10 +654,11 @@ pub(crate) fn layout_text_lines(
         TextVerticalAlignment::Center => max_height / 2. - text_height() / 2.,
         TextVerticalAlignment::Bottom => max_height - text_height(),
     };
+    let line_height = line_height.unwrap_or(font_height);
     let mut y = baseline_y;
     let mut start = 0;
-    'lines: while start < string.len() && y + font_height <= max_height {
-        if wrap && (!elide || y + font_height * 2. <= max_height) {
+    'lines: while start < string.len() && y + line_height <= max_height {
+        if wrap && (!elide || y + line_height * 2. <= max_height) {
             let max_line_index = string[start..].find('\n').map_or(string.len(), |i| i + 1 + start);
             let index = text_context
                 .break_text(max_width.get(), &string[start..max_line_index], paint)
@@ -623,7 +671,7 @@ pub(crate) fn layout_text_lines(
             let line = &string[start..index];
             let text_metrics = text_context.measure_text(0., 0., line, paint).unwrap();
             process_line(line, y, start, &text_metrics);
-            y += font_height;
+            y += line_height;
             start = index;
         } else {
             let index = if single_line {
@@ -634,7 +682,7 @@ pub(crate) fn layout_text_lines(
             let line = &string[start..index];
             let text_metrics = text_context.measure_text(0., 0., line, paint).unwrap();
             let elide_last_line =
-                elide && index < string.len() && y + font_height * 2. > max_height;
+                elide && index < string.len() && y + line_height * 2. > max_height;
             if text_metrics.width() > max_width.get() || elide_last_line {
                 let w = max_width
                     - if elide {
@@ -655,7 +703,7 @@ pub(crate) fn layout_text_lines(
                         } else {
                             process_line(txt, y, start, &text_metrics);
                         }
-                        y += font_height;
+                        y += line_height;
                         start = index;
                         continue 'lines;
                     }
@@ -663,13 +711,13 @@ pub(crate) fn layout_text_lines(
                 if elide_last_line {
                     let elided = format!("{}…", line);
                     process_line(&elided, y, start, &text_metrics);
-                    y += font_height;
+                    y += line_height;
                     start = index;
                     continue 'lines;
                 }
             }
             process_line(line, y, start, &text_metrics);
-            y += font_height;
+            y += line_height;
             start = index;
         }
     }
@@ -10,7 +10,7 @@
 use i_slint_core::graphics::euclid::num::Zero;
 use i_slint_core::graphics::euclid::{self};
 use i_slint_core::graphics::rendering_metrics_collector::RenderingMetrics;
-use i_slint_core::graphics::{Image, IntRect, Point, Size};
+use i_slint_core::graphics::{Image, ImageLoadState, IntRect, Point, Size};
 use i_slint_core::item_rendering::{ItemCache, ItemRenderer};
 use i_slint_core::items::{
     self, Clip, FillRule, ImageFit, ImageRendering, Item, ItemRc, Layer, Opacity, RenderingResult,
@@ -319,13 +319,10 @@ fn draw_text(&mut self, text: Pin<&items::Text>, _: &ItemRc, size: LogicalSize)
 
         let string = text.text();
         let string = string.as_str();
-        let font = fonts::FONT_CACHE.with(|cache| {
-            cache.borrow_mut().font(
-                text.font_request(WindowInner::from_pub(self.window)),
-                self.scale_factor,
-                &text.text(),
-            )
-        });
+        let font_request = text.font_request(WindowInner::from_pub(self.window));
+        let line_height = font_request.line_height.map(|height| height * self.scale_factor);
+        let font = fonts::FONT_CACHE
+            .with(|cache| cache.borrow_mut().font(font_request, self.scale_factor, &text.text()));
 
         let paint = match self
             .brush_to_paint(text.color(), &mut rect_to_path((size * self.scale_factor).into()))
@@ -338,6 +335,7 @@ fn draw_text(&mut self, text: Pin<&items::Text>, _: &ItemRc, size: LogicalSize)
         fonts::layout_text_lines(
             string,
             &font,
+            line_height,
             PhysicalSize::from_lengths(max_width, max_height),
             (text.horizontal_alignment(), text.vertical_alignment()),
             text.wrap(),
@@ -406,6 +404,7 @@ fn draw_text_input(
         let next_y = fonts::layout_text_lines(
             text.as_str(),
             &font,
+            None,
             PhysicalSize::from_lengths(width, height),
             (text_input.horizontal_alignment(), text_input.vertical_alignment()),
             text_input.wrap(),
@@ -618,6 +617,28 @@ fn add_point(&mut self, p: Point) {
             }
         }
 
+        let tolerance =
+            i_slint_core::graphics::PathDataIterator::DEFAULT_TOLERANCE / self.scale_factor.get();
+        let mut dashed_stroke_path =
+            path.dashed_stroke_events(&path_events, tolerance).map(|dashes| {
+                let mut stroke_path = femtovg::Path::new();
+                for event in dashes {
+                    match event {
+                        lyon_path::Event::Begin { at } => stroke_path.move_to(
+                            at.x * self.scale_factor.get(),
+                            at.y * self.scale_factor.get(),
+                        ),
+                        lyon_path::Event::Line { from: _, to } => stroke_path.line_to(
+                            to.x * self.scale_factor.get(),
+                            to.y * self.scale_factor.get(),
+                        ),
+                        // The dashes are made of line segments
+                        _ => {}
+                    }
+                }
+                stroke_path
+            });
+
         let fill_paint =
             self.brush_to_paint(path.fill(), &mut femtovg_path).map(|mut fill_paint| {
                 fill_paint.set_fill_rule(match path.fill_rule() {
@@ -639,7 +660,10 @@ fn add_point(&mut self, p: Point) {
                 canvas.fill_path(&mut femtovg_path, fill_paint);
             }
             if let Some(border_paint) = &border_paint {
-                canvas.stroke_path(&mut femtovg_path, border_paint);
+                canvas.stroke_path(
+                    dashed_stroke_path.as_mut().unwrap_or(&mut femtovg_path),
+                    border_paint,
+                );
             }
         })
     }
@@ -1237,6 +1261,36 @@ fn colorize_image(
         }
     }
 
+    /// Draws the placeholder for images that failed to load: a gray frame on a light background.
+    fn draw_broken_image(&mut self, size: LogicalSize) {
+        let mut geometry = PhysicalRect::from(size * self.scale_factor);
+        if self.global_alpha_transparent() {
+            return;
+        }
+
+        let mut border_width = LogicalLength::new(1.) * self.scale_factor;
+        let mut background_path = rect_to_path(geometry);
+        adjust_rect_and_border_for_inner_drawing(&mut geometry, &mut border_width);
+        let mut border_path = rect_to_path(geometry);
+
+        let fill_paint =
+            self.brush_to_paint(Color::from_rgb_u8(0xf0, 0xf0, 0xf0).into(), &mut background_path);
+        let border_paint = self
+            .brush_to_paint(Color::from_rgb_u8(0xa0, 0xa0, 0xa0).into(), &mut border_path)
+            .map(|mut paint| {
+                paint.set_line_width(border_width.get());
+                paint
+            });
+
+        let mut canvas = self.canvas.borrow_mut();
+        if let Some(paint) = fill_paint {
+            canvas.fill_path(&mut background_path, &paint);
+        }
+        if let Some(paint) = border_paint {
+            canvas.stroke_path(&mut border_path, &paint);
+        }
+    }
+
     fn draw_image_impl(
         &mut self,
         item_rc: &ItemRc,
@@ -1255,6 +1309,14 @@ fn draw_image_impl(
             return;
         }
 
+        if source_property.get().load_state() == ImageLoadState::Error {
+            self.draw_broken_image(LogicalSize::from_lengths(
+                target_width.get(),
+                target_height.get(),
+            ));
+            return;
+        }
+
         let cached_image = loop {
             let image_cache_entry = self.graphics_cache.get_or_update_cache_entry(item_rc, || {
                 let image = source_property.get();
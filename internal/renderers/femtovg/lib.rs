@@ -156,6 +156,17 @@ pub fn render(
         &self,
         size: PhysicalWindowSize,
     ) -> Result<(), i_slint_core::platform::PlatformError> {
+        self.render_scene(size)?;
+
+        if let Some(callback) = self.rendering_notifier.borrow_mut().as_mut() {
+            self.with_graphics_api(|api| callback.notify(RenderingState::AfterRendering, &api))?;
+        }
+
+        self.opengl_context.swap_buffers()
+    }
+
+    /// Render the scene into the back buffer, without presenting it.
+    fn render_scene(&self, size: PhysicalWindowSize) -> Result<(), PlatformError> {
         self.opengl_context.ensure_current()?;
 
         let width = size.width;
@@ -246,13 +257,7 @@ pub fn render(
             self.texture_cache.borrow_mut().drain();
             drop(item_renderer);
             Ok(())
-        })?;
-
-        if let Some(callback) = self.rendering_notifier.borrow_mut().as_mut() {
-            self.with_graphics_api(|api| callback.notify(RenderingState::AfterRendering, &api))?;
-        }
-
-        self.opengl_context.swap_buffers()
+        })
     }
 
     /// Inform the renderer about the new size of the underlying window.
@@ -338,6 +343,7 @@ fn text_input_byte_offset_for_position(
         crate::fonts::layout_text_lines(
             &visual_representation.text,
             &font,
+            None,
             PhysicalSize::from_lengths(width, height),
             (text_input.horizontal_alignment(), text_input.vertical_alignment()),
             text_input.wrap(),
@@ -391,6 +397,7 @@ fn text_input_cursor_rect_for_byte_offset(
         fonts::layout_text_lines(
             text.as_str(),
             &font,
+            None,
             PhysicalSize::from_lengths(width, height),
             (text_input.horizontal_alignment(), text_input.vertical_alignment()),
             text_input.wrap(),
@@ -426,20 +433,51 @@ fn register_font_from_memory(
         &self,
         data: &'static [u8],
     ) -> Result<(), Box<dyn std::error::Error>> {
-        sharedfontdb::register_font_from_memory(data)
+        sharedfontdb::register_font_from_memory(data).map(|_| ())
     }
 
     fn register_font_from_path(
         &self,
         path: &std::path::Path,
     ) -> Result<(), Box<dyn std::error::Error>> {
-        sharedfontdb::register_font_from_path(path)
+        sharedfontdb::register_font_from_path(path).map(|_| ())
+    }
+
+    fn reset_registered_fonts(&self) -> Result<(), Box<dyn std::error::Error>> {
+        let removed_faces = sharedfontdb::reset_registered_fonts();
+        fonts::FONT_CACHE.with(|cache| cache.borrow_mut().evict_faces(&removed_faces));
+        Ok(())
     }
 
     fn default_font_size(&self) -> LogicalLength {
         self::fonts::DEFAULT_FONT_SIZE
     }
 
+    fn grab_frame(
+        &self,
+    ) -> Result<
+        i_slint_core::graphics::SharedPixelBuffer<i_slint_core::graphics::Rgba8Pixel>,
+        PlatformError,
+    > {
+        let window_adapter = self
+            .window_adapter_weak
+            .upgrade()
+            .ok_or("grab_frame() called on a destroyed Window")?;
+        let size = window_adapter.window().size();
+
+        // Render into the back buffer and read it back before it gets presented or cleared.
+        self.render_scene(size)?;
+        let screenshot = self.canvas.borrow_mut().screenshot().map_err(|e| {
+            PlatformError::Other(format!("FemtoVG: Error reading back the window contents: {e}"))
+        })?;
+
+        let mut buffer = i_slint_core::graphics::SharedPixelBuffer::new(size.width, size.height);
+        for (dst, src) in buffer.make_mut_slice().iter_mut().zip(screenshot.pixels()) {
+            *dst = i_slint_core::graphics::Rgba8Pixel::new(src.r, src.g, src.b, src.a);
+        }
+        Ok(buffer)
+    }
+
     fn set_rendering_notifier(
         &self,
         callback: Box<dyn i_slint_core::api::RenderingNotifier>,
@@ -74,6 +74,13 @@ pub(crate) fn as_skia_image(
             )
         }
         ImageInner::StaticTextures(_) => todo!(),
+        ImageInner::RemoteImage(remote) => as_skia_image(
+            remote.loaded_image()?.into(),
+            target_width,
+            target_height,
+            image_fit,
+            scale_factor,
+        ),
         ImageInner::BackendStorage(x) => {
             vtable::VRc::borrow(x).downcast::<SkiaCachedImage>().map(|x| x.image.clone())
         }
@@ -7,6 +7,7 @@
 use i_slint_core::graphics::boxshadowcache::BoxShadowCache;
 use i_slint_core::graphics::euclid::num::Zero;
 use i_slint_core::graphics::euclid::{self, Vector2D};
+use i_slint_core::graphics::ImageLoadState;
 use i_slint_core::item_rendering::{ItemCache, ItemRenderer};
 use i_slint_core::items::{ImageFit, ImageRendering, ItemRc, Layer, Opacity, RenderingResult};
 use i_slint_core::lengths::{
@@ -144,6 +145,30 @@ fn colorize_image(
         Some(surface.image_snapshot())
     }
 
+    /// Draws the placeholder for images that failed to load: a gray frame on a light background.
+    fn draw_broken_image(&mut self, mut geometry: PhysicalRect) {
+        if let Some(mut fill_paint) = self.brush_to_paint(
+            Color::from_rgb_u8(0xf0, 0xf0, 0xf0).into(),
+            geometry.width_length(),
+            geometry.height_length(),
+        ) {
+            fill_paint.set_style(skia_safe::PaintStyle::Fill);
+            self.canvas.draw_rect(to_skia_rect(&geometry), &fill_paint);
+        }
+
+        let mut border_width = LogicalLength::new(1.) * self.scale_factor;
+        adjust_rect_and_border_for_inner_drawing(&mut geometry, &mut border_width);
+        if let Some(mut border_paint) = self.brush_to_paint(
+            Color::from_rgb_u8(0xa0, 0xa0, 0xa0).into(),
+            geometry.width_length(),
+            geometry.height_length(),
+        ) {
+            border_paint.set_style(skia_safe::PaintStyle::Stroke);
+            border_paint.set_stroke_width(border_width.get());
+            self.canvas.draw_rect(to_skia_rect(&geometry), &border_paint);
+        }
+    }
+
     fn draw_image_impl(
         &mut self,
         item_rc: &ItemRc,
@@ -156,6 +181,11 @@ fn draw_image_impl(
         rendering: ImageRendering,
         colorize_property: Pin<&Property<Brush>>,
     ) {
+        if source_property.get().load_state() == ImageLoadState::Error {
+            self.draw_broken_image(dest_rect);
+            return;
+        }
+
         // TODO: avoid doing creating an SkImage multiple times when the same source is used in multiple image elements
         let skia_image = self.image_cache.get_or_update_cache_entry(item_rc, || {
             let image = source_property.get();
@@ -639,6 +669,18 @@ fn draw_path(
             border_paint.set_anti_alias(true);
             border_paint.set_stroke_width((path.stroke_width() * self.scale_factor).get());
             border_paint.set_stroke(true);
+            if let Some(mut dash_pattern) = path.stroke_dash_pattern() {
+                // Skia needs an even number of intervals, SVG repeats odd dash arrays
+                if dash_pattern.len() % 2 == 1 {
+                    dash_pattern = dash_pattern.repeat(2);
+                }
+                let intervals = dash_pattern
+                    .iter()
+                    .map(|length| length * self.scale_factor.get())
+                    .collect::<Vec<_>>();
+                let phase = (path.stroke_dash_offset() * self.scale_factor).get();
+                border_paint.set_path_effect(skia_safe::PathEffect::dash(&intervals, phase));
+            }
             self.canvas.draw_path(&skpath, &border_paint);
         }
     }
@@ -115,76 +115,94 @@ pub fn render(
         &self,
         size: PhysicalWindowSize,
     ) -> Result<(), i_slint_core::platform::PlatformError> {
-        let window_adapter = self.window_adapter_weak.upgrade().unwrap();
-        let window_inner = WindowInner::from_pub(window_adapter.window());
-
         self.surface.render(size, |skia_canvas, gr_context| {
-            window_inner.draw_contents(|components| {
-                let window_background_brush =
-                    window_inner.window_item().map(|w| w.as_pin_ref().background());
-
-                // Clear with window background if it is a solid color otherwise it will drawn as gradient
-                if let Some(Brush::SolidColor(clear_color)) = window_background_brush {
-                    skia_canvas.clear(itemrenderer::to_skia_color(&clear_color));
-                }
-
-                if let Some(callback) = self.rendering_notifier.borrow_mut().as_mut() {
-                    // For the BeforeRendering rendering notifier callback it's important that this happens *after* clearing
-                    // the back buffer, in order to allow the callback to provide its own rendering of the background.
-                    // Skia's clear() will merely schedule a clear call, so flush right away to make it immediate.
-                    gr_context.flush(None);
-
-                    self.surface.with_graphics_api(|api| {
-                        callback.notify(RenderingState::BeforeRendering, &api)
-                    })
-                }
+            self.render_to_canvas(
+                skia_canvas,
+                size,
+                || {
+                    if let Some(callback) = self.rendering_notifier.borrow_mut().as_mut() {
+                        // For the BeforeRendering rendering notifier callback it's important that this happens *after* clearing
+                        // the back buffer, in order to allow the callback to provide its own rendering of the background.
+                        // Skia's clear() will merely schedule a clear call, so flush right away to make it immediate.
+                        gr_context.flush(None);
+
+                        self.surface.with_graphics_api(|api| {
+                            callback.notify(RenderingState::BeforeRendering, &api)
+                        })
+                    }
+                },
+                |item_renderer| {
+                    if let Some(collector) = &self.rendering_metrics_collector.borrow_mut().as_ref()
+                    {
+                        collector.measure_frame_rendered(item_renderer);
+                    }
+                },
+            );
+            gr_context.flush(None);
 
-                let mut box_shadow_cache = Default::default();
+            if let Some(callback) = self.rendering_notifier.borrow_mut().as_mut() {
+                self.surface
+                    .with_graphics_api(|api| callback.notify(RenderingState::AfterRendering, &api))
+            }
+        })
+    }
 
-                let window_adapter = self.window_adapter_weak.upgrade().unwrap();
+    /// Draws the window background and the items of the window into the canvas. `before_rendering`
+    /// is called after the background was cleared and `after_rendering` after all items were drawn.
+    fn render_to_canvas(
+        &self,
+        skia_canvas: &mut skia_safe::Canvas,
+        size: PhysicalWindowSize,
+        before_rendering: impl FnOnce(),
+        after_rendering: impl FnOnce(&mut itemrenderer::SkiaRenderer),
+    ) {
+        let window_adapter = self.window_adapter_weak.upgrade().unwrap();
+        let window_inner = WindowInner::from_pub(window_adapter.window());
 
-                let mut item_renderer = itemrenderer::SkiaRenderer::new(
-                    skia_canvas,
-                    window_adapter.window(),
-                    &self.image_cache,
-                    &self.path_cache,
-                    &mut box_shadow_cache,
-                );
+        window_inner.draw_contents(|components| {
+            let window_background_brush =
+                window_inner.window_item().map(|w| w.as_pin_ref().background());
 
-                // Draws the window background as gradient
-                match window_background_brush {
-                    Some(Brush::SolidColor(..)) | None => {}
-                    Some(brush @ _) => {
-                        item_renderer.draw_rect(
-                            i_slint_core::lengths::logical_size_from_api(
-                                size.to_logical(window_inner.scale_factor()),
-                            ),
-                            brush,
-                        );
-                    }
-                }
+            // Clear with window background if it is a solid color otherwise it will drawn as gradient
+            if let Some(Brush::SolidColor(clear_color)) = window_background_brush {
+                skia_canvas.clear(itemrenderer::to_skia_color(&clear_color));
+            }
 
-                for (component, origin) in components {
-                    i_slint_core::item_rendering::render_component_items(
-                        component,
-                        &mut item_renderer,
-                        *origin,
+            before_rendering();
+
+            let mut box_shadow_cache = Default::default();
+
+            let mut item_renderer = itemrenderer::SkiaRenderer::new(
+                skia_canvas,
+                window_adapter.window(),
+                &self.image_cache,
+                &self.path_cache,
+                &mut box_shadow_cache,
+            );
+
+            // Draws the window background as gradient
+            match window_background_brush {
+                Some(Brush::SolidColor(..)) | None => {}
+                Some(brush @ _) => {
+                    item_renderer.draw_rect(
+                        i_slint_core::lengths::logical_size_from_api(
+                            size.to_logical(window_inner.scale_factor()),
+                        ),
+                        brush,
                     );
                 }
+            }
 
-                if let Some(collector) = &self.rendering_metrics_collector.borrow_mut().as_ref() {
-                    collector.measure_frame_rendered(&mut item_renderer);
-                }
-
-                drop(item_renderer);
-                gr_context.flush(None);
-            });
-
-            if let Some(callback) = self.rendering_notifier.borrow_mut().as_mut() {
-                self.surface
-                    .with_graphics_api(|api| callback.notify(RenderingState::AfterRendering, &api))
+            for (component, origin) in components {
+                i_slint_core::item_rendering::render_component_items(
+                    component,
+                    &mut item_renderer,
+                    *origin,
+                );
             }
-        })
+
+            after_rendering(&mut item_renderer);
+        });
     }
 
     /// Call this when you receive a notification from the windowing system that the size of the window has changed.
@@ -341,6 +359,45 @@ fn default_font_size(&self) -> LogicalLength {
         self::textlayout::DEFAULT_FONT_SIZE
     }
 
+    fn grab_frame(
+        &self,
+    ) -> Result<
+        i_slint_core::graphics::SharedPixelBuffer<i_slint_core::graphics::Rgba8Pixel>,
+        PlatformError,
+    > {
+        let window_adapter = self
+            .window_adapter_weak
+            .upgrade()
+            .ok_or("grab_frame() called on a destroyed Window")?;
+        let size = window_adapter.window().size();
+
+        // Render into a separate surface in memory, so that the window surface is not affected.
+        // Raster surfaces must use premultiplied alpha, read_pixels() converts when reading back.
+        let dimensions = (size.width as i32, size.height as i32);
+        let surface_info = skia_safe::ImageInfo::new(
+            dimensions,
+            skia_safe::ColorType::RGBA8888,
+            skia_safe::AlphaType::Premul,
+            None,
+        );
+        let mut surface = skia_safe::Surface::new_raster(&surface_info, None, None)
+            .ok_or("Skia: Error creating a surface to grab the window contents")?;
+        self.render_to_canvas(surface.canvas(), size, || {}, |_| {});
+
+        let image_info = surface_info.with_alpha_type(skia_safe::AlphaType::Unpremul);
+
+        let mut buffer = i_slint_core::graphics::SharedPixelBuffer::new(size.width, size.height);
+        if !surface.read_pixels(
+            &image_info,
+            buffer.make_mut_bytes(),
+            image_info.min_row_bytes(),
+            (0, 0),
+        ) {
+            return Err("Skia: Error reading back the window contents".into());
+        }
+        Ok(buffer)
+    }
+
     fn free_graphics_resources(
         &self,
         component: i_slint_core::component::ComponentRef,
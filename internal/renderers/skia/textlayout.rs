@@ -74,11 +74,26 @@ pub fn create_layout(
         text_style.set_letter_spacing((letter_spacing * scale_factor).get());
     }
     text_style.set_font_size(pixel_size.get());
+    if let Some(line_height) = font_request.line_height.filter(|_| pixel_size.get() > 0.) {
+        // Skia's height is a multiple of the font size. Half leading centers the glyphs in the line.
+        text_style.set_height_override(true);
+        text_style.set_height((line_height * scale_factor).get() / pixel_size.get());
+        text_style.set_half_leading(true);
+    }
     text_style.set_font_style(skia_safe::FontStyle::new(
         font_request.weight.map_or(skia_safe::font_style::Weight::NORMAL, |w| w.into()),
         skia_safe::font_style::Width::NORMAL,
-        skia_safe::font_style::Slant::Upright,
+        match font_request.style {
+            items::FontStyle::Normal => skia_safe::font_style::Slant::Upright,
+            items::FontStyle::Italic => skia_safe::font_style::Slant::Italic,
+            items::FontStyle::Oblique => skia_safe::font_style::Slant::Oblique,
+        },
     ));
+    for feature in &font_request.features {
+        if let Ok(tag) = std::str::from_utf8(&feature.tag) {
+            text_style.add_font_feature(tag, feature.value as i32);
+        }
+    }
 
     let mut style = skia_safe::textlayout::ParagraphStyle::new();
 
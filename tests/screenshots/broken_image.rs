@@ -0,0 +1,47 @@
+// Copyright © SixtyFPS GmbH <info@slint-ui.com>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-commercial
+
+// Images that failed to load are drawn as a placeholder. Such images can't be created from a
+// .slint file alone, so this test sets the image from Rust.
+
+use crate::testing;
+
+slint::slint! {
+    export component TestCase inherits Window {
+        width: 64px;
+        height: 64px;
+
+        background: black;
+
+        in property <image> source;
+
+        Image { x: 4px; y: 4px; width: 56px; height: 24px; source: root.source; }
+        // Also for a clipped image
+        Image { x: 4px; y: 36px; width: 24px; height: 24px; source: root.source; source-clip-x: 1; source-clip-width: 2; }
+    }
+}
+
+#[test]
+fn broken_image() -> Result<(), Box<dyn std::error::Error>> {
+    let window = testing::init_swr();
+    window.set_size(slint::PhysicalSize::new(64, 64));
+    let screenshot =
+        concat!(env!("CARGO_MANIFEST_DIR"), "/references/software/basic/broken-image.png");
+
+    // Dropping the completion without data makes the fetch fail
+    slint::set_image_url_fetcher(|_, completion| drop(completion));
+    let source = slint::Image::load_from_url("https://example.com/missing.png");
+    assert_eq!(source.load_state(), slint::ImageLoadState::Error);
+
+    let instance = TestCase::new().unwrap();
+    instance.set_source(source);
+    instance.show().unwrap();
+
+    testing::assert_with_render(screenshot, window.clone());
+
+    testing::assert_with_render_by_line(screenshot, window.clone());
+
+    testing::assert_with_grab_frame(screenshot, window.clone());
+
+    Ok(())
+}
@@ -104,6 +104,8 @@ fn main() -> std::io::Result<()> {
 
     testing::assert_with_render_by_line(screenshot, window.clone());
 
+    testing::assert_with_grab_frame(screenshot, window.clone());
+
     Ok(())
     }}",
             i,
@@ -5,6 +5,9 @@
 
 pub mod testing;
 
+#[cfg(test)]
+mod broken_image;
+
 #[cfg(test)]
 include!(concat!(env!("OUT_DIR"), "/generated.rs"));
 
@@ -196,6 +196,17 @@ pub fn assert_with_render(path: &str, window: Rc<MinimalSoftwareWindow>) {
     }
 }
 
+pub fn assert_with_grab_frame(path: &str, window: Rc<MinimalSoftwareWindow>) {
+    let frame = window.grab_frame().expect("the software renderer can grab frames");
+    let mut rendering = SharedPixelBuffer::<Rgb8Pixel>::new(frame.width(), frame.height());
+    for (dst, src) in rendering.make_mut_slice().iter_mut().zip(frame.as_slice()) {
+        *dst = Rgb8Pixel::new(src.r, src.g, src.b);
+    }
+    if let Err(reason) = compare_images(path, &rendering) {
+        panic!("Image comparison failure for the grabbed frame for {path}: {reason}");
+    }
+}
+
 pub fn assert_with_render_by_line(path: &str, window: Rc<MinimalSoftwareWindow>) {
     let s = window.size();
     let mut rendering = SharedPixelBuffer::<Rgb8Pixel>::new(s.width, s.height);